@@ -12,4 +12,13 @@ pub mod platform {
     pub mod v0 {
         include!("platform/proto/org.dash.platform.dapi.v0.rs");
     }
+
+    #[cfg(feature = "platform_v0")]
+    pub mod metadata;
+
+    #[cfg(all(feature = "platform_v0", feature = "client"))]
+    pub mod broadcast;
+
+    #[cfg(all(feature = "platform_v0", feature = "client"))]
+    pub mod wait;
 }
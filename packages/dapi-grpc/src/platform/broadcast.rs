@@ -0,0 +1,157 @@
+//! Broadcasting dependent state transitions one after another
+
+use std::fmt;
+use std::time::Duration;
+
+use tonic::transport::Channel;
+
+use super::v0::platform_client::PlatformClient;
+use super::v0::{
+    BroadcastStateTransitionRequest, Proof, ResponseMetadata, StateTransitionBroadcastError,
+};
+use super::wait::{
+    wait_for_state_transition_result, StateTransitionResult, WaitForStateTransitionError,
+};
+
+/// A serialized state transition to broadcast
+#[derive(Debug, Clone, PartialEq)]
+pub struct SerializedStateTransition {
+    /// The serialized state transition, signature included
+    pub bytes: Vec<u8>,
+    /// The hash the node keys the state transition by, the SHA256 of `bytes`
+    pub hash: Vec<u8>,
+}
+
+/// A committed state transition of a broadcast sequence
+#[derive(Debug, Clone, PartialEq)]
+pub struct BroadcastResult {
+    /// The proof of the result of the transition, if one was requested
+    pub proof: Option<Proof>,
+    /// The metadata of the response
+    pub metadata: Option<ResponseMetadata>,
+}
+
+/// Why a state transition of a broadcast sequence did not commit
+#[derive(Debug)]
+pub enum BroadcastFailure {
+    /// The node did not accept the state transition
+    Broadcast(tonic::Status),
+    /// The state transition was rejected
+    Rejected(StateTransitionBroadcastError),
+    /// Waiting for the state transition result failed
+    Wait(WaitForStateTransitionError),
+}
+
+/// A broadcast sequence was aborted at a state transition that did not commit
+#[derive(Debug)]
+pub struct BroadcastSequenceError {
+    /// The index of the state transition that did not commit
+    pub index: usize,
+    /// The results of the state transitions committed before it
+    pub committed: Vec<BroadcastResult>,
+    /// Why the state transition did not commit
+    pub failure: BroadcastFailure,
+}
+
+impl fmt::Display for BroadcastSequenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "state transition {} of the sequence ", self.index)?;
+        match &self.failure {
+            BroadcastFailure::Broadcast(status) => {
+                write!(f, "could not be broadcast: {}", status)
+            }
+            BroadcastFailure::Rejected(error) => write!(
+                f,
+                "was rejected with code {}: {}",
+                error.code, error.message
+            ),
+            BroadcastFailure::Wait(error) => write!(f, "did not commit: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for BroadcastSequenceError {}
+
+/// Broadcasts state transitions in order, each one once the previous one is committed.
+///
+/// Every state transition is broadcast, then waited for with
+/// `wait_for_state_transition_result` and the given timeout, so a transition depending on a
+/// previous one, like a document of a newly created identity, never races it. The sequence is
+/// aborted at the first state transition that is not committed, the remaining ones are not
+/// broadcast.
+///
+/// ```ignore
+/// match broadcast_sequence(&mut client, vec![identity_create, document_batch], timeout, false).await {
+///     Ok(results) => assert_eq!(results.len(), 2),
+///     Err(error) => report(error.index, error.failure),
+/// }
+/// ```
+pub async fn broadcast_sequence(
+    client: &mut PlatformClient<Channel>,
+    state_transitions: Vec<SerializedStateTransition>,
+    timeout: Duration,
+    prove: bool,
+) -> Result<Vec<BroadcastResult>, BroadcastSequenceError> {
+    let mut committed = Vec::with_capacity(state_transitions.len());
+    for (index, state_transition) in state_transitions.into_iter().enumerate() {
+        match broadcast_and_wait(client, state_transition, timeout, prove).await {
+            Ok(result) => committed.push(result),
+            Err(failure) => {
+                return Err(BroadcastSequenceError {
+                    index,
+                    committed,
+                    failure,
+                })
+            }
+        }
+    }
+    Ok(committed)
+}
+
+async fn broadcast_and_wait(
+    client: &mut PlatformClient<Channel>,
+    state_transition: SerializedStateTransition,
+    timeout: Duration,
+    prove: bool,
+) -> Result<BroadcastResult, BroadcastFailure> {
+    client
+        .broadcast_state_transition(BroadcastStateTransitionRequest {
+            state_transition: state_transition.bytes,
+        })
+        .await
+        .map_err(BroadcastFailure::Broadcast)?;
+    match wait_for_state_transition_result(client, state_transition.hash, timeout, prove)
+        .await
+        .map_err(BroadcastFailure::Wait)?
+    {
+        StateTransitionResult::Committed { proof, metadata } => {
+            Ok(BroadcastResult { proof, metadata })
+        }
+        StateTransitionResult::Rejected { error, .. } => Err(BroadcastFailure::Rejected(error)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_report_which_state_transition_failed() {
+        let error = BroadcastSequenceError {
+            index: 1,
+            committed: vec![BroadcastResult {
+                proof: None,
+                metadata: None,
+            }],
+            failure: BroadcastFailure::Rejected(StateTransitionBroadcastError {
+                code: 4001,
+                message: "insufficient balance".to_string(),
+                data: vec![],
+            }),
+        };
+        assert_eq!(
+            error.to_string(),
+            "state transition 1 of the sequence was rejected with code 4001: insufficient balance"
+        );
+    }
+}
@@ -0,0 +1,145 @@
+//! Helpers for reasoning about the response metadata attached to proved responses
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use super::v0::ResponseMetadata;
+
+/// How the blocks two proved responses were taken from relate to each other
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProofHeightRelation {
+    /// Both responses were proved at the same block
+    Same,
+    /// The responses were proved at two consecutive blocks
+    AdjacentBlocks,
+    /// The responses were proved at blocks this many heights apart, more than one
+    Gap(u64),
+    /// The metadata can not describe a single chain history: the chain ids differ, the same
+    /// height reports a different block, or the later block goes back in time or in core height
+    Reorged,
+}
+
+/// Compares the metadata of two proved responses so a client reconciling them can decide
+/// whether they are consistent or whether it should re-fetch one of them.
+///
+/// The comparison is symmetric, the order of `a` and `b` does not matter.
+pub fn compare_proof_heights(a: &ResponseMetadata, b: &ResponseMetadata) -> ProofHeightRelation {
+    if a.chain_id != b.chain_id {
+        return ProofHeightRelation::Reorged;
+    }
+    let (earlier, later) = match a.height.cmp(&b.height) {
+        Ordering::Equal => {
+            return if a.time_ms == b.time_ms
+                && a.core_chain_locked_height == b.core_chain_locked_height
+            {
+                ProofHeightRelation::Same
+            } else {
+                ProofHeightRelation::Reorged
+            };
+        }
+        Ordering::Less => (a, b),
+        Ordering::Greater => (b, a),
+    };
+    if later.time_ms < earlier.time_ms
+        || later.core_chain_locked_height < earlier.core_chain_locked_height
+    {
+        return ProofHeightRelation::Reorged;
+    }
+    match later.height - earlier.height {
+        1 => ProofHeightRelation::AdjacentBlocks,
+        gap => ProofHeightRelation::Gap(gap),
+    }
+}
+
+/// The node answered with a protocol version newer than the client can parse
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProtocolVersionMismatch {
+    /// The protocol version reported by the node
+    pub server: u32,
+    /// The highest protocol version the client supports
+    pub client_max: u32,
+}
+
+impl fmt::Display for ProtocolVersionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the node runs protocol version {} but this client only supports up to version {}, please upgrade your client",
+            self.server, self.client_max
+        )
+    }
+}
+
+impl std::error::Error for ProtocolVersionMismatch {}
+
+/// Checks the protocol version a node reported in the response metadata against the highest
+/// version the client supports, so a newer node is reported before the response is decoded.
+pub fn check_protocol_version(
+    metadata: &ResponseMetadata,
+    client_max: u32,
+) -> Result<(), ProtocolVersionMismatch> {
+    if metadata.protocol_version > client_max {
+        Err(ProtocolVersionMismatch {
+            server: metadata.protocol_version,
+            client_max,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata(height: u64, time_ms: u64) -> ResponseMetadata {
+        ResponseMetadata {
+            height,
+            core_chain_locked_height: 10,
+            time_ms,
+            protocol_version: 1,
+            chain_id: "dash-testnet".to_string(),
+        }
+    }
+
+    #[test]
+    fn should_compare_proof_heights() {
+        assert_eq!(
+            compare_proof_heights(&metadata(5, 500), &metadata(5, 500)),
+            ProofHeightRelation::Same
+        );
+        assert_eq!(
+            compare_proof_heights(&metadata(6, 600), &metadata(5, 500)),
+            ProofHeightRelation::AdjacentBlocks
+        );
+        assert_eq!(
+            compare_proof_heights(&metadata(5, 500), &metadata(9, 900)),
+            ProofHeightRelation::Gap(4)
+        );
+        assert_eq!(
+            compare_proof_heights(&metadata(5, 500), &metadata(5, 501)),
+            ProofHeightRelation::Reorged
+        );
+        assert_eq!(
+            compare_proof_heights(&metadata(5, 500), &metadata(6, 400)),
+            ProofHeightRelation::Reorged
+        );
+    }
+
+    #[test]
+    fn should_check_protocol_version() {
+        let metadata = ResponseMetadata {
+            protocol_version: 2,
+            ..metadata(5, 500)
+        };
+        assert_eq!(check_protocol_version(&metadata, 2), Ok(()));
+        assert_eq!(check_protocol_version(&metadata, 3), Ok(()));
+        assert_eq!(
+            check_protocol_version(&metadata, 1),
+            Err(ProtocolVersionMismatch {
+                server: 2,
+                client_max: 1
+            })
+        );
+    }
+}
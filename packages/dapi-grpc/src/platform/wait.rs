@@ -0,0 +1,159 @@
+//! Waiting for a broadcast state transition to be committed or rejected
+
+use std::fmt;
+use std::time::Duration;
+
+use tonic::transport::Channel;
+use tonic::Code;
+
+use super::v0::platform_client::PlatformClient;
+use super::v0::wait_for_state_transition_result_response::Result as WaitResult;
+use super::v0::{
+    Proof, ResponseMetadata, StateTransitionBroadcastError, WaitForStateTransitionResultRequest,
+    WaitForStateTransitionResultResponse,
+};
+
+/// What became of a broadcast state transition
+#[derive(Debug, Clone, PartialEq)]
+pub enum StateTransitionResult {
+    /// The transition was committed, with the proof of its result if one was requested
+    Committed {
+        /// The proof of the result of the transition
+        proof: Option<Proof>,
+        /// The metadata of the response
+        metadata: Option<ResponseMetadata>,
+    },
+    /// The transition was rejected
+    Rejected {
+        /// Why the transition was rejected
+        error: StateTransitionBroadcastError,
+        /// The metadata of the response
+        metadata: Option<ResponseMetadata>,
+    },
+}
+
+impl From<WaitForStateTransitionResultResponse> for StateTransitionResult {
+    fn from(response: WaitForStateTransitionResultResponse) -> Self {
+        let metadata = response.metadata;
+        match response.result {
+            Some(WaitResult::Error(error)) => StateTransitionResult::Rejected { error, metadata },
+            Some(WaitResult::Proof(proof)) => StateTransitionResult::Committed {
+                proof: Some(proof),
+                metadata,
+            },
+            None => StateTransitionResult::Committed {
+                proof: None,
+                metadata,
+            },
+        }
+    }
+}
+
+/// Waiting for a state transition result failed
+#[derive(Debug)]
+pub enum WaitForStateTransitionError {
+    /// The transition was neither committed nor rejected within the timeout
+    Timeout(Duration),
+    /// The node could not be asked for the result
+    Status(tonic::Status),
+}
+
+impl fmt::Display for WaitForStateTransitionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WaitForStateTransitionError::Timeout(timeout) => write!(
+                f,
+                "the state transition was not committed or rejected within {:?}",
+                timeout
+            ),
+            WaitForStateTransitionError::Status(status) => write!(
+                f,
+                "waiting for the state transition result failed: {}",
+                status
+            ),
+        }
+    }
+}
+
+impl std::error::Error for WaitForStateTransitionError {}
+
+/// Waits until the state transition with the given hash is committed or rejected.
+///
+/// The node holds the request until the transition is included in a block or rejected, so no
+/// polling is needed. The timeout is sent to the node as the deadline of the request, a node
+/// giving up at that deadline is reported as `WaitForStateTransitionError::Timeout`.
+///
+/// ```ignore
+/// match wait_for_state_transition_result(&mut client, hash, Duration::from_secs(60), true).await? {
+///     StateTransitionResult::Committed { proof, .. } => verify(proof),
+///     StateTransitionResult::Rejected { error, .. } => report(error),
+/// }
+/// ```
+pub async fn wait_for_state_transition_result(
+    client: &mut PlatformClient<Channel>,
+    state_transition_hash: Vec<u8>,
+    timeout: Duration,
+    prove: bool,
+) -> Result<StateTransitionResult, WaitForStateTransitionError> {
+    let mut request = tonic::Request::new(WaitForStateTransitionResultRequest {
+        state_transition_hash,
+        prove,
+    });
+    request.set_timeout(timeout);
+    match client.wait_for_state_transition_result(request).await {
+        Ok(response) => Ok(response.into_inner().into()),
+        Err(status) if status.code() == Code::DeadlineExceeded => {
+            Err(WaitForStateTransitionError::Timeout(timeout))
+        }
+        Err(status) => Err(WaitForStateTransitionError::Status(status)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_read_the_state_transition_result() {
+        let committed = WaitForStateTransitionResultResponse {
+            metadata: None,
+            result: Some(WaitResult::Proof(Proof {
+                grovedb_proof: vec![1, 2, 3],
+                ..Default::default()
+            })),
+        };
+        assert!(matches!(
+            StateTransitionResult::from(committed),
+            StateTransitionResult::Committed { proof: Some(_), .. }
+        ));
+
+        let committed_without_proof = WaitForStateTransitionResultResponse {
+            metadata: None,
+            result: None,
+        };
+        assert_eq!(
+            StateTransitionResult::from(committed_without_proof),
+            StateTransitionResult::Committed {
+                proof: None,
+                metadata: None
+            }
+        );
+
+        let error = StateTransitionBroadcastError {
+            code: 4001,
+            message: "insufficient balance".to_string(),
+            data: vec![],
+        };
+        let rejected = WaitForStateTransitionResultResponse {
+            metadata: None,
+            result: Some(WaitResult::Error(error.clone())),
+        };
+        assert_eq!(
+            StateTransitionResult::from(rejected),
+            StateTransitionResult::Rejected {
+                error,
+                metadata: None
+            }
+        );
+    }
+}
@@ -13,3 +13,27 @@ pub const OWNER_ID_BYTES: [u8; 32] = [
 pub fn load_documents_schemas() -> Result<Value, Error> {
     serde_json::from_str(include_str!("../schema/dpns-contract-documents.json"))
 }
+
+/// Normalizes a domain label the way the platform validates `normalizedLabel`, so ids and
+/// uniqueness checks computed by clients match the stored documents, e.g. `Bob` becomes `bob`.
+pub fn normalize_label(label: &str) -> String {
+    label.to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_normalize_labels() {
+        for (label, normalized_label) in [
+            ("bob", "bob"),
+            ("Bob", "bob"),
+            ("ALICE-42", "alice-42"),
+            ("o0O-lL1", "o0o-ll1"),
+            ("dash", "dash"),
+        ] {
+            assert_eq!(normalize_label(label), normalized_label);
+        }
+    }
+}
@@ -230,6 +230,18 @@ struct DataContractInner {
     pub defs: Option<BTreeMap<DefinitionName, Value>>,
 }
 
+/// The leading fields of a serialized contract, up to its owner, decoding them does not
+/// require decoding the document schemas that follow
+#[derive(Decode)]
+struct DataContractOwnerPrefix {
+    _protocol_version: u32,
+    _id: Identifier,
+    _config: ContractConfig,
+    _schema: String,
+    _version: u32,
+    owner_id: Identifier,
+}
+
 impl From<DataContract> for DataContractInner {
     fn from(value: DataContract) -> Self {
         let DataContract {
@@ -616,6 +628,29 @@ impl DataContract {
     pub fn has_document_type_for_name(&self, document_type_name: &str) -> bool {
         self.document_types.get(document_type_name).is_some()
     }
+
+    /// Reads the owner id of a serialized contract without deserializing its document types
+    pub fn owner_id_from_serialized(bytes: &[u8]) -> Result<Identifier, ProtocolError> {
+        let config = config::standard().with_big_endian().with_no_limit();
+        let (prefix, _): (DataContractOwnerPrefix, usize) =
+            bincode::decode_from_slice(bytes, config).map_err(|e| {
+                ProtocolError::PlatformDeserializationError(format!(
+                    "unable to deserialize DataContract owner id: {}",
+                    e
+                ))
+            })?;
+        Ok(prefix.owner_id)
+    }
+
+    /// Whether the contract keeps the history of its own revisions or of the documents of
+    /// any of its document types
+    pub fn keeps_any_history(&self) -> bool {
+        self.config.keeps_history
+            || self
+                .document_types
+                .values()
+                .any(|document_type| document_type.keeps_history())
+    }
 }
 
 impl TryFrom<JsonValue> for DataContract {
@@ -1049,4 +1084,34 @@ mod test {
 
         assert_eq!(hex::encode(data_contract_cbor), hex::encode(serialized));
     }
+
+    #[test]
+    fn should_tell_if_any_history_is_kept() {
+        let mut data_contract = get_data_contract_fixture(None).data_contract;
+        assert!(!data_contract.keeps_any_history());
+
+        data_contract
+            .document_types
+            .get_mut("niceDocument")
+            .expect("expected a document type")
+            .documents_keep_history = true;
+        assert!(data_contract.keeps_any_history());
+        assert!(data_contract
+            .document_type_for_name("niceDocument")
+            .expect("expected a document type")
+            .keeps_history());
+    }
+
+    #[test]
+    fn should_read_owner_id_from_serialized_contract() {
+        let data_contract = get_data_contract_fixture(None).data_contract;
+        let serialized_contract =
+            PlatformSerializable::serialize(&data_contract).expect("expected to serialize");
+
+        let owner_id = DataContract::owner_id_from_serialized(&serialized_contract)
+            .expect("expected to read the owner id");
+        assert_eq!(owner_id, data_contract.owner_id);
+
+        assert!(DataContract::owner_id_from_serialized(&serialized_contract[..10]).is_err());
+    }
 }
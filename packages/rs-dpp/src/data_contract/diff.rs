@@ -0,0 +1,212 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::data_contract::document_type::DocumentType;
+
+use super::DataContract;
+
+/// The changes of the document types of a data contract from one version to another
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ContractDiff {
+    /// The names of the document types only in the new version
+    pub added_document_types: BTreeSet<String>,
+    /// The names of the document types only in the old version
+    pub removed_document_types: BTreeSet<String>,
+    /// The changes of the document types in both versions, by document type name
+    pub changed_document_types: BTreeMap<String, DocumentTypeDiff>,
+}
+
+impl ContractDiff {
+    /// Whether the document types of both versions are the same
+    pub fn is_empty(&self) -> bool {
+        self.added_document_types.is_empty()
+            && self.removed_document_types.is_empty()
+            && self.changed_document_types.is_empty()
+    }
+}
+
+/// The changes of a document type from one version of its data contract to another
+///
+/// Properties are named by their path, nested properties included. An index whose
+/// definition changed is both removed and added.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DocumentTypeDiff {
+    /// The properties only in the new version
+    pub added_properties: BTreeSet<String>,
+    /// The properties only in the old version
+    pub removed_properties: BTreeSet<String>,
+    /// The properties in both versions whose definition changed
+    pub changed_properties: BTreeSet<String>,
+    /// The names of the indices only in the new version
+    pub added_indices: BTreeSet<String>,
+    /// The names of the indices only in the old version
+    pub removed_indices: BTreeSet<String>,
+    /// The fields only required in the new version
+    pub added_required_fields: BTreeSet<String>,
+    /// The fields only required in the old version
+    pub removed_required_fields: BTreeSet<String>,
+}
+
+impl DocumentTypeDiff {
+    /// Whether both versions of the document type are the same
+    pub fn is_empty(&self) -> bool {
+        self.added_properties.is_empty()
+            && self.removed_properties.is_empty()
+            && self.changed_properties.is_empty()
+            && self.added_indices.is_empty()
+            && self.removed_indices.is_empty()
+            && self.added_required_fields.is_empty()
+            && self.removed_required_fields.is_empty()
+    }
+
+    fn new(old: &DocumentType, new: &DocumentType) -> Self {
+        let old_properties = &old.flattened_properties;
+        let new_properties = &new.flattened_properties;
+        DocumentTypeDiff {
+            added_properties: new_properties
+                .keys()
+                .filter(|name| !old_properties.contains_key(*name))
+                .cloned()
+                .collect(),
+            removed_properties: old_properties
+                .keys()
+                .filter(|name| !new_properties.contains_key(*name))
+                .cloned()
+                .collect(),
+            changed_properties: old_properties
+                .iter()
+                .filter(|(name, field)| {
+                    new_properties
+                        .get(*name)
+                        .map_or(false, |new_field| new_field != *field)
+                })
+                .map(|(name, _)| name.clone())
+                .collect(),
+            added_indices: new
+                .indices
+                .iter()
+                .filter(|index| !old.indices.contains(index))
+                .map(|index| index.name.clone())
+                .collect(),
+            removed_indices: old
+                .indices
+                .iter()
+                .filter(|index| !new.indices.contains(index))
+                .map(|index| index.name.clone())
+                .collect(),
+            added_required_fields: new
+                .required_fields
+                .difference(&old.required_fields)
+                .cloned()
+                .collect(),
+            removed_required_fields: old
+                .required_fields
+                .difference(&new.required_fields)
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+impl DataContract {
+    /// Lists the changes of the document types from this version of the contract to `other`,
+    /// usually the next one
+    pub fn diff(&self, other: &DataContract) -> ContractDiff {
+        ContractDiff {
+            added_document_types: other
+                .document_types
+                .keys()
+                .filter(|name| !self.document_types.contains_key(*name))
+                .cloned()
+                .collect(),
+            removed_document_types: self
+                .document_types
+                .keys()
+                .filter(|name| !other.document_types.contains_key(*name))
+                .cloned()
+                .collect(),
+            changed_document_types: self
+                .document_types
+                .iter()
+                .filter_map(|(name, document_type)| {
+                    let other_document_type = other.document_types.get(name)?;
+                    let document_type_diff =
+                        DocumentTypeDiff::new(document_type, other_document_type);
+                    (!document_type_diff.is_empty()).then(|| (name.clone(), document_type_diff))
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::data_contract::document_type::{Index, IndexProperty};
+    use crate::tests::fixtures::get_data_contract_fixture;
+
+    #[test]
+    fn should_diff_document_types() {
+        let data_contract = get_data_contract_fixture(None).data_contract;
+        assert!(data_contract.diff(&data_contract).is_empty());
+
+        let mut updated_data_contract = data_contract.clone();
+        let removed_document_type = updated_data_contract
+            .document_types
+            .remove("prettyDocument")
+            .expect("expected a document type");
+        updated_data_contract.document_types.insert(
+            "newDocument".to_string(),
+            DocumentType {
+                name: "newDocument".to_string(),
+                ..removed_document_type
+            },
+        );
+
+        let nice_document = updated_data_contract
+            .document_types
+            .get_mut("niceDocument")
+            .expect("expected a document type");
+        let field = nice_document
+            .flattened_properties
+            .remove("name")
+            .expect("expected a property");
+        nice_document
+            .flattened_properties
+            .insert("newProperty".to_string(), field);
+        nice_document
+            .required_fields
+            .insert("newProperty".to_string());
+        nice_document.indices.push(Index {
+            name: "newIndex".to_string(),
+            properties: vec![IndexProperty {
+                name: "newProperty".to_string(),
+                ascending: true,
+            }],
+            unique: false,
+        });
+
+        let diff = data_contract.diff(&updated_data_contract);
+
+        assert_eq!(
+            diff.added_document_types,
+            BTreeSet::from(["newDocument".to_string()])
+        );
+        assert_eq!(
+            diff.removed_document_types,
+            BTreeSet::from(["prettyDocument".to_string()])
+        );
+        assert_eq!(
+            diff.changed_document_types,
+            BTreeMap::from([(
+                "niceDocument".to_string(),
+                DocumentTypeDiff {
+                    added_properties: BTreeSet::from(["newProperty".to_string()]),
+                    removed_properties: BTreeSet::from(["name".to_string()]),
+                    added_indices: BTreeSet::from(["newIndex".to_string()]),
+                    added_required_fields: BTreeSet::from(["newProperty".to_string()]),
+                    ..Default::default()
+                }
+            )])
+        );
+    }
+}
@@ -1,8 +1,11 @@
 use super::*;
 use crate::ProtocolError;
-use integer_encoding::VarInt;
+use byteorder::{BigEndian, ReadBytesExt};
+use integer_encoding::{VarInt, VarIntReader};
 use platform_value::Value;
 use serde::{Deserialize, Serialize};
+use std::convert::TryInto;
+use std::io::{BufReader, Read};
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
 pub enum ArrayFieldType {
@@ -118,6 +121,69 @@ impl ArrayFieldType {
             }
         };
     }
+
+    fn read_varint_value(buf: &mut BufReader<&[u8]>) -> Result<Vec<u8>, ProtocolError> {
+        let bytes: usize = buf.read_varint().map_err(|_| {
+            ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+                "error reading varint length of array item from serialized document",
+            ))
+        })?;
+        let mut value: Vec<u8> = vec![0u8; bytes];
+        buf.read_exact(&mut value).map_err(|_| {
+            ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+                "error reading array item from serialized document",
+            ))
+        })?;
+        Ok(value)
+    }
+
+    /// Reads an array item that was encoded with `encode_value_with_size`
+    pub fn read_from(&self, buf: &mut BufReader<&[u8]>) -> Result<Value, ProtocolError> {
+        match self {
+            ArrayFieldType::String(_, _) => {
+                let bytes = Self::read_varint_value(buf)?;
+                let string = String::from_utf8(bytes).map_err(|_| {
+                    ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+                        "error reading string array item from serialized document",
+                    ))
+                })?;
+                Ok(Value::Text(string))
+            }
+            ArrayFieldType::Date | ArrayFieldType::Number => {
+                let float = buf.read_f64::<BigEndian>().map_err(|_| {
+                    ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+                        "error reading date/number array item from serialized document",
+                    ))
+                })?;
+                Ok(Value::Float(float))
+            }
+            ArrayFieldType::Integer => {
+                let integer = buf.read_i64::<BigEndian>().map_err(|_| {
+                    ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+                        "error reading integer array item from serialized document",
+                    ))
+                })?;
+                Ok(Value::I64(integer))
+            }
+            ArrayFieldType::ByteArray(_, _) => Ok(Value::Bytes(Self::read_varint_value(buf)?)),
+            ArrayFieldType::Identifier => {
+                let id: [u8; 32] = Self::read_varint_value(buf)?.try_into().map_err(|_| {
+                    ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+                        "identifier array item is not 32 bytes",
+                    ))
+                })?;
+                Ok(Value::Identifier(id))
+            }
+            ArrayFieldType::Boolean => {
+                let value = buf.read_u8().map_err(|_| {
+                    ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+                        "error reading bool array item from serialized document",
+                    ))
+                })?;
+                Ok(Value::Bool(value != 0))
+            }
+        }
+    }
 }
 
 fn get_field_type_matching_error() -> ProtocolError {
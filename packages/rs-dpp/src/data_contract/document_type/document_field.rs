@@ -21,6 +21,166 @@ use super::array_field::ArrayFieldType;
 pub struct DocumentField {
     pub document_type: DocumentFieldType,
     pub required: bool,
+    /// The values allowed by the `enum` keyword of string and integer fields
+    #[serde(default)]
+    pub allowed_values: Option<Vec<Value>>,
+    /// The `title` annotation of the field schema
+    #[serde(default)]
+    pub title: Option<String>,
+    /// The `description` annotation of the field schema
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The `$comment` annotation of the field schema
+    #[serde(default)]
+    pub comment: Option<String>,
+}
+
+impl DocumentField {
+    /// A field without allowed values or annotations
+    pub fn new(document_type: DocumentFieldType, required: bool) -> Self {
+        DocumentField {
+            document_type,
+            required,
+            allowed_values: None,
+            title: None,
+            description: None,
+            comment: None,
+        }
+    }
+
+    /// A human readable label for the field, from the `title` of its schema
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Help text for the field, from the `description` of its schema
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// A note for contract developers, from the `$comment` of its schema
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    /// Checks that a document value conforms to this field, `field_path` is the dotted path of
+    /// the field and is used to name it in the error
+    pub fn validate_value(
+        &self,
+        field_path: &str,
+        value: Option<&Value>,
+    ) -> Result<(), ProtocolError> {
+        let invalid = |reason: &'static str| {
+            ProtocolError::DataContractError(DataContractError::InvalidDocumentProperty {
+                field: field_path.to_string(),
+                reason,
+            })
+        };
+        let value = match value {
+            Some(value) if !value.is_null() => value,
+            _ if self.required => return Err(invalid("required field is missing")),
+            _ => return Ok(()),
+        };
+        match &self.document_type {
+            DocumentFieldType::Integer => {
+                if !value.is_integer() {
+                    return Err(invalid("expected an integer"));
+                }
+            }
+            DocumentFieldType::Number | DocumentFieldType::Date => {
+                if !value.is_integer() && !value.is_float() {
+                    return Err(invalid("expected a number"));
+                }
+            }
+            DocumentFieldType::String(min_length, max_length) => {
+                let text = value
+                    .as_text()
+                    .ok_or_else(|| invalid("expected a string"))?;
+                let length = text.chars().count();
+                if min_length.map_or(false, |min_length| length < min_length as usize) {
+                    return Err(invalid("string is shorter than minLength"));
+                }
+                if max_length.map_or(false, |max_length| length > max_length as usize) {
+                    return Err(invalid("string is longer than maxLength"));
+                }
+            }
+            DocumentFieldType::ByteArray(min_size, max_size) => {
+                let bytes = value
+                    .to_binary_bytes()
+                    .map_err(|_| invalid("expected a byte array"))?;
+                if min_size.map_or(false, |min_size| bytes.len() < min_size as usize) {
+                    return Err(invalid("byte array is shorter than minItems"));
+                }
+                if max_size.map_or(false, |max_size| bytes.len() > max_size as usize) {
+                    return Err(invalid("byte array is longer than maxItems"));
+                }
+            }
+            DocumentFieldType::Identifier => {
+                value
+                    .to_identifier_bytes()
+                    .map_err(|_| invalid("expected an identifier"))?;
+            }
+            DocumentFieldType::Boolean => {
+                if !value.is_bool() {
+                    return Err(invalid("expected a boolean"));
+                }
+            }
+            DocumentFieldType::Object(inner_fields) => {
+                let value_map = value
+                    .as_map()
+                    .ok_or_else(|| invalid("expected an object"))
+                    .and_then(|value_map| {
+                        Value::map_ref_into_btree_string_map(value_map)
+                            .map_err(|_| invalid("expected an object with string keys"))
+                    })?;
+                for (key, inner_field) in inner_fields {
+                    inner_field.validate_value(
+                        format!("{}.{}", field_path, key).as_str(),
+                        value_map.get(key).copied(),
+                    )?;
+                }
+            }
+            DocumentFieldType::Array(array_field_type) => {
+                let Value::Array(array) = value else {
+                    return Err(invalid("expected an array"));
+                };
+                for item in array {
+                    array_field_type
+                        .encode_value_ref_with_size(item)
+                        .map_err(|_| invalid("array item does not match the item type"))?;
+                }
+            }
+            DocumentFieldType::VariableTypeArray(array_field_types) => {
+                let Value::Array(array) = value else {
+                    return Err(invalid("expected an array"));
+                };
+                if array.len() > array_field_types.len() {
+                    return Err(invalid("array has more items than item types"));
+                }
+                for (item, array_field_type) in array.iter().zip(array_field_types) {
+                    array_field_type
+                        .encode_value_ref_with_size(item)
+                        .map_err(|_| invalid("array item does not match the item type"))?;
+                }
+            }
+        }
+        if let Some(allowed_values) = &self.allowed_values {
+            let is_allowed = match &self.document_type {
+                // integers are compared by value as they can be held by any integer variant
+                DocumentFieldType::Integer => {
+                    let integer = value.to_integer::<i128>().ok();
+                    allowed_values
+                        .iter()
+                        .any(|allowed_value| allowed_value.to_integer::<i128>().ok() == integer)
+                }
+                _ => allowed_values.contains(value),
+            };
+            if !is_allowed {
+                return Err(invalid("value is not one of the enum values"));
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
@@ -410,9 +570,26 @@ impl DocumentFieldType {
             DocumentFieldType::Array(_array_field_type) => Err(ProtocolError::DataContractError(
                 DataContractError::Unsupported("serialization of arrays not yet supported"),
             )),
-            DocumentFieldType::VariableTypeArray(_) => Err(ProtocolError::DataContractError(
-                DataContractError::Unsupported("serialization of arrays not yet supported"),
-            )),
+            DocumentFieldType::VariableTypeArray(array_field_types) => {
+                let len: usize = buf.read_varint().map_err(|_| {
+                    ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+                        "error reading array length from serialized document",
+                    ))
+                })?;
+                if len > array_field_types.len() {
+                    return Err(ProtocolError::DataContractError(
+                        DataContractError::CorruptedSerialization(
+                            "array has more items than item types",
+                        ),
+                    ));
+                }
+                let values = array_field_types
+                    .iter()
+                    .take(len)
+                    .map(|array_field_type| array_field_type.read_from(buf))
+                    .collect::<Result<Vec<Value>, ProtocolError>>()?;
+                Ok(Some(Value::Array(values)))
+            }
         }
     }
 
@@ -538,11 +715,26 @@ impl DocumentFieldType {
                     Err(get_field_type_matching_error())
                 }
             }
-            DocumentFieldType::VariableTypeArray(_) => Err(ProtocolError::DataContractError(
-                DataContractError::Unsupported(
-                    "serialization of variable type arrays not yet supported",
-                ),
-            )),
+            DocumentFieldType::VariableTypeArray(array_field_types) => {
+                if let Value::Array(array) = value {
+                    if array.len() > array_field_types.len() {
+                        return Err(get_field_type_matching_error());
+                    }
+                    let mut r_vec = array.len().encode_var_vec();
+
+                    array.into_iter().zip(array_field_types).try_for_each(
+                        |(value, array_field_type)| {
+                            let mut serialized_value =
+                                array_field_type.encode_value_with_size(value)?;
+                            r_vec.append(&mut serialized_value);
+                            Ok::<(), ProtocolError>(())
+                        },
+                    )?;
+                    Ok(r_vec)
+                } else {
+                    Err(get_field_type_matching_error())
+                }
+            }
         }
     }
 
@@ -648,9 +840,26 @@ impl DocumentFieldType {
                 }
             }
 
-            DocumentFieldType::VariableTypeArray(_) => Err(ProtocolError::DataContractError(
-                DataContractError::Unsupported("serialization of arrays not yet supported"),
-            )),
+            DocumentFieldType::VariableTypeArray(array_field_types) => {
+                if let Value::Array(array) = value {
+                    if array.len() > array_field_types.len() {
+                        return Err(get_field_type_matching_error());
+                    }
+                    let mut r_vec = array.len().encode_var_vec();
+
+                    array.iter().zip(array_field_types).try_for_each(
+                        |(value, array_field_type)| {
+                            let mut serialized_value =
+                                array_field_type.encode_value_ref_with_size(value)?;
+                            r_vec.append(&mut serialized_value);
+                            Ok::<(), ProtocolError>(())
+                        },
+                    )?;
+                    Ok(r_vec)
+                } else {
+                    Err(get_field_type_matching_error())
+                }
+            }
         };
     }
 
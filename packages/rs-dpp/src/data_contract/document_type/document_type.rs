@@ -54,6 +54,19 @@ pub struct DocumentType {
     pub data_contract_id: Identifier,
 }
 
+/// A field that is referenced by at least one index of a document type
+#[derive(Debug, PartialEq, Clone)]
+pub struct IndexableField {
+    /// The name of the field, system fields are prefixed with `$`
+    pub name: String,
+    /// The type of the field, including its size constraints
+    pub field_type: DocumentFieldType,
+    /// If the field is part of at least one unique index
+    pub in_unique_index: bool,
+    /// The names of the indices that reference the field
+    pub indices: Vec<String>,
+}
+
 #[derive(Debug, PartialEq, Default, Clone)]
 pub struct IndexLevel {
     /// the lower index levels from this level
@@ -233,36 +246,55 @@ impl DocumentType {
         let mut flattened_document_properties: BTreeMap<String, DocumentField> = BTreeMap::new();
         let mut document_properties: BTreeMap<String, DocumentField> = BTreeMap::new();
 
+        // JSON pointer to this document type's schema, used to locate schema errors
+        let schema_path = format!("/documents/{}", escape_json_pointer_token(name));
+        let keyword_path = |keyword: &str| format!("{}/{}", schema_path, keyword);
+
         // Do documents of this type keep history? (Overrides contract value)
-        let documents_keep_history: bool =
-            Value::inner_optional_bool_value(document_type_value_map, "documentsKeepHistory")
-                .map_err(ProtocolError::ValueError)?
-                .unwrap_or(default_keeps_history);
+        let documents_keep_history: bool = Value::inner_optional_bool_value(
+            document_type_value_map,
+            property_names::DOCUMENTS_KEEP_HISTORY,
+        )
+        .map_err(at_schema_path(&keyword_path(
+            property_names::DOCUMENTS_KEEP_HISTORY,
+        )))?
+        .unwrap_or(default_keeps_history);
 
         // Are documents of this type mutable? (Overrides contract value)
-        let documents_mutable: bool =
-            Value::inner_optional_bool_value(document_type_value_map, "documentsMutable")
-                .map_err(ProtocolError::ValueError)?
-                .unwrap_or(default_mutability);
+        let documents_mutable: bool = Value::inner_optional_bool_value(
+            document_type_value_map,
+            property_names::DOCUMENTS_MUTABLE,
+        )
+        .map_err(at_schema_path(&keyword_path(
+            property_names::DOCUMENTS_MUTABLE,
+        )))?
+        .unwrap_or(default_mutability);
 
+        let indices_path = keyword_path(property_names::INDICES);
         let index_values = Value::inner_optional_array_slice_value(
             document_type_value_map,
             property_names::INDICES,
-        )?;
+        )
+        .map_err(at_schema_path(&indices_path))?;
         let indices: Vec<Index> = index_values
             .map(|index_values| {
                 index_values
                     .iter()
-                    .map(|index_value| {
+                    .enumerate()
+                    .map(|(position, index_value)| {
+                        let index_path = format!("{}/{}", indices_path, position);
                         index_value
                             .as_map()
-                            .ok_or(ProtocolError::DataContractError(
-                                DataContractError::InvalidContractStructure(
-                                    "table document is not a map as expected",
-                                ),
-                            ))?
+                            .ok_or_else(|| {
+                                at_schema_path(&index_path)(ProtocolError::DataContractError(
+                                    DataContractError::InvalidContractStructure(
+                                        "table document is not a map as expected",
+                                    ),
+                                ))
+                            })?
                             .as_slice()
                             .try_into()
+                            .map_err(at_schema_path::<ProtocolError>(&index_path))
                     })
                     .collect::<Result<Vec<Index>, ProtocolError>>()
             })
@@ -271,7 +303,8 @@ impl DocumentType {
 
         // Extract the properties
         let property_values =
-            Value::inner_optional_btree_map(document_type_value_map, property_names::PROPERTIES)?
+            Value::inner_optional_btree_map(document_type_value_map, property_names::PROPERTIES)
+                .map_err(at_schema_path(&keyword_path(property_names::PROPERTIES)))?
                 .unwrap_or_default();
 
         let required_fields = Value::inner_recursive_optional_array_of_strings(
@@ -289,6 +322,7 @@ impl DocumentType {
                 property_key.clone(),
                 property_value,
                 definition_references,
+                &schema_path,
             )?;
 
             insert_values_nested(
@@ -297,40 +331,29 @@ impl DocumentType {
                 property_key,
                 property_value,
                 definition_references,
+                &schema_path,
             )?;
         }
         // Add system properties
         if required_fields.contains(property_names::CREATED_AT) {
             flattened_document_properties.insert(
                 String::from(property_names::CREATED_AT),
-                DocumentField {
-                    document_type: DocumentFieldType::Date,
-                    required: true,
-                },
+                DocumentField::new(DocumentFieldType::Date, true),
             );
             document_properties.insert(
                 String::from(property_names::CREATED_AT),
-                DocumentField {
-                    document_type: DocumentFieldType::Date,
-                    required: true,
-                },
+                DocumentField::new(DocumentFieldType::Date, true),
             );
         }
 
         if required_fields.contains(property_names::UPDATED_AT) {
             flattened_document_properties.insert(
                 String::from(property_names::UPDATED_AT),
-                DocumentField {
-                    document_type: DocumentFieldType::Date,
-                    required: true,
-                },
+                DocumentField::new(DocumentFieldType::Date, true),
             );
             document_properties.insert(
                 String::from(property_names::UPDATED_AT),
-                DocumentField {
-                    document_type: DocumentFieldType::Date,
-                    required: true,
-                },
+                DocumentField::new(DocumentFieldType::Date, true),
             );
         }
 
@@ -390,6 +413,33 @@ impl DocumentType {
         index_properties
     }
 
+    /// The fields referenced by the indices of the document type, in the order they first
+    /// appear in the indices
+    pub fn indexable_fields(&self) -> Vec<IndexableField> {
+        let mut indexable_fields: Vec<IndexableField> = vec![];
+        for index in &self.indices {
+            for property in &index.properties {
+                if let Some(indexable_field) = indexable_fields
+                    .iter_mut()
+                    .find(|indexable_field| indexable_field.name == property.name)
+                {
+                    indexable_field.in_unique_index |= index.unique;
+                    indexable_field.indices.push(index.name.clone());
+                } else if let Some(field_type) =
+                    self.document_field_type_for_property(property.name.as_str())
+                {
+                    indexable_fields.push(IndexableField {
+                        name: property.name.clone(),
+                        field_type,
+                        in_unique_index: index.unique,
+                        indices: vec![index.name.clone()],
+                    });
+                }
+            }
+        }
+        indexable_fields
+    }
+
     pub fn document_field_for_property(&self, property: &str) -> Option<DocumentField> {
         self.flattened_properties.get(property).cloned()
     }
@@ -428,6 +478,67 @@ impl DocumentType {
         self.documents_mutable
     }
 
+    /// Whether every revision of the documents of this type is kept
+    pub fn keeps_history(&self) -> bool {
+        self.documents_keep_history
+    }
+
+    /// Checks that document properties conform to the properties defined in this document type
+    ///
+    /// The `$createdAt` and `$updatedAt` system properties are not checked, see
+    /// `validate_document`.
+    pub fn validate_properties(
+        &self,
+        properties: &BTreeMap<String, Value>,
+    ) -> Result<(), ProtocolError> {
+        if let Some(unknown_field) = properties
+            .keys()
+            .find(|field| !self.properties.contains_key(*field))
+        {
+            return Err(ProtocolError::DataContractError(
+                DataContractError::InvalidDocumentProperty {
+                    field: unknown_field.clone(),
+                    reason: "field is not defined in the document type",
+                },
+            ));
+        }
+        self.properties
+            .iter()
+            .filter(|(field_name, _)| !Self::is_timestamp_field(field_name))
+            .try_for_each(|(field_name, field)| {
+                field.validate_value(field_name, properties.get(field_name))
+            })
+    }
+
+    /// Checks that a document conforms to this document type
+    ///
+    /// The `$createdAt` and `$updatedAt` system properties are held by the document itself and
+    /// not by its properties, so when required they are checked on the document.
+    pub fn validate_document(&self, document: &Document) -> Result<(), ProtocolError> {
+        self.validate_properties(&document.properties)?;
+        [
+            (property_names::CREATED_AT, document.created_at),
+            (property_names::UPDATED_AT, document.updated_at),
+        ]
+        .into_iter()
+        .try_for_each(|(field_name, timestamp)| {
+            if timestamp.is_none() && self.required_fields.contains(field_name) {
+                Err(ProtocolError::DataContractError(
+                    DataContractError::InvalidDocumentProperty {
+                        field: field_name.to_string(),
+                        reason: "required field is missing",
+                    },
+                ))
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    fn is_timestamp_field(field_name: &str) -> bool {
+        field_name == property_names::CREATED_AT || field_name == property_names::UPDATED_AT
+    }
+
     pub(crate) fn find_identifier_and_binary_paths(
         properties: &BTreeMap<String, DocumentField>,
     ) -> (BTreeSet<String>, BTreeSet<String>) {
@@ -505,33 +616,191 @@ pub fn string_to_field_type(field_type_name: &str) -> Option<DocumentFieldType>
     }
 }
 
+/// Escapes a property name so it can be used as a JSON pointer reference token (RFC 6901)
+fn escape_json_pointer_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+/// Builds the field of a property, reading the `title`, `description` and `$comment`
+/// annotations of its schema, annotations that are not strings are not kept
+fn document_field_from_schema(
+    document_type: DocumentFieldType,
+    required: bool,
+    allowed_values: Option<Vec<Value>>,
+    inner_properties: &BTreeMap<String, &Value>,
+) -> DocumentField {
+    let annotation = |keyword: &str| {
+        inner_properties
+            .get(keyword)
+            .and_then(|annotation| annotation.as_text())
+            .map(str::to_string)
+    };
+    DocumentField {
+        document_type,
+        required,
+        allowed_values,
+        title: annotation(property_names::TITLE),
+        description: annotation(property_names::DESCRIPTION),
+        comment: annotation(property_names::COMMENT),
+    }
+}
+
+/// Reads the values allowed by the `enum` keyword of a string or integer property
+fn enum_allowed_values(
+    field_type: &DocumentFieldType,
+    inner_properties: &BTreeMap<String, &Value>,
+    enum_path: &str,
+) -> Result<Option<Vec<Value>>, ProtocolError> {
+    if !matches!(
+        field_type,
+        DocumentFieldType::Integer | DocumentFieldType::String(..)
+    ) {
+        return Ok(None);
+    }
+    inner_properties
+        .get(property_names::ENUM)
+        .map(|enum_value| {
+            enum_value.as_array().cloned().ok_or_else(|| {
+                at_schema_path(enum_path)(DataContractError::InvalidContractStructure(
+                    "enum must be an array",
+                ))
+            })
+        })
+        .transpose()
+}
+
+/// Attaches the JSON pointer of the failing schema node to an error.
+/// Errors that already carry a schema path are left untouched so that the
+/// innermost (most precise) location is the one reported.
+fn at_schema_path<E: Into<ProtocolError>>(path: &str) -> impl FnOnce(E) -> ProtocolError + '_ {
+    move |error| match error.into() {
+        error @ ProtocolError::DataContractError(DataContractError::InvalidSchemaAtPath {
+            ..
+        }) => error,
+        error => ProtocolError::DataContractError(DataContractError::InvalidSchemaAtPath {
+            path: path.to_string(),
+            error: Box::new(error),
+        }),
+    }
+}
+
+/// Reads the item types of a variable type array from an `items` list, where each entry is
+/// the schema of the array item at that position
+///
+/// Returns `None` if an item is of a type array items can not have yet, like an object or a
+/// nested array that is not a byte array.
+fn variable_type_array_field_types(
+    items: &[Value],
+    items_path: &str,
+) -> Result<Option<Vec<ArrayFieldType>>, ProtocolError> {
+    items
+        .iter()
+        .enumerate()
+        .map(|(position, item)| {
+            let item_path = format!("{}/{}", items_path, position);
+            let keyword_path = |keyword: &str| format!("{}/{}", item_path, keyword);
+            let item_properties = item
+                .to_btree_ref_string_map()
+                .map_err(at_schema_path(&item_path))?;
+            let type_path = keyword_path(property_names::TYPE);
+            let item_type = item_properties
+                .get_str(property_names::TYPE)
+                .map_err(at_schema_path(&type_path))?;
+            match item_type {
+                "integer" => Ok(Some(ArrayFieldType::Integer)),
+                "number" => Ok(Some(ArrayFieldType::Number)),
+                "boolean" => Ok(Some(ArrayFieldType::Boolean)),
+                "date" => Ok(Some(ArrayFieldType::Date)),
+                "object" => Ok(None),
+                "string" => Ok(Some(ArrayFieldType::String(
+                    item_properties
+                        .get_optional_integer(property_names::MIN_LENGTH)
+                        .map_err(at_schema_path(&keyword_path(property_names::MIN_LENGTH)))?,
+                    item_properties
+                        .get_optional_integer(property_names::MAX_LENGTH)
+                        .map_err(at_schema_path(&keyword_path(property_names::MAX_LENGTH)))?,
+                ))),
+                "array" => {
+                    let is_byte_array = item_properties
+                        .get_optional_bool(property_names::BYTE_ARRAY)
+                        .map_err(at_schema_path(&keyword_path(property_names::BYTE_ARRAY)))?;
+                    if is_byte_array != Some(true) {
+                        return Ok(None);
+                    }
+                    match item_properties
+                        .get_optional_str(property_names::CONTENT_MEDIA_TYPE)
+                        .map_err(at_schema_path(&keyword_path(
+                            property_names::CONTENT_MEDIA_TYPE,
+                        )))? {
+                        Some(content_media_type)
+                            if content_media_type == "application/x.dash.dpp.identifier" =>
+                        {
+                            Ok(Some(ArrayFieldType::Identifier))
+                        }
+                        Some(_) | None => Ok(Some(ArrayFieldType::ByteArray(
+                            item_properties
+                                .get_optional_integer(property_names::MIN_ITEMS)
+                                .map_err(at_schema_path(&keyword_path(
+                                    property_names::MIN_ITEMS,
+                                )))?,
+                            item_properties
+                                .get_optional_integer(property_names::MAX_ITEMS)
+                                .map_err(at_schema_path(&keyword_path(
+                                    property_names::MAX_ITEMS,
+                                )))?,
+                        ))),
+                    }
+                }
+                _ => Err(at_schema_path(&type_path)(
+                    DataContractError::ValueWrongType("invalid variable type array item type"),
+                )),
+            }
+        })
+        .collect::<Result<Vec<_>, ProtocolError>>()
+        .map(|item_types| item_types.into_iter().collect())
+}
+
 fn insert_values_nested(
     document_properties: &mut BTreeMap<String, DocumentField>,
     known_required: &BTreeSet<String>,
     property_key: String,
     property_value: &Value,
     definition_references: &BTreeMap<String, &Value>,
+    schema_path: &str,
 ) -> Result<(), ProtocolError> {
-    let mut inner_properties = property_value.to_btree_ref_string_map()?;
+    let property_path = format!(
+        "{}/{}/{}",
+        schema_path,
+        property_names::PROPERTIES,
+        escape_json_pointer_token(&property_key)
+    );
+    let keyword_path = |keyword: &str| format!("{}/{}", property_path, keyword);
+
+    let mut inner_properties = property_value
+        .to_btree_ref_string_map()
+        .map_err(at_schema_path(&property_path))?;
 
     let type_value = inner_properties
         .remove_optional_string(property_names::TYPE)
-        .map_err(ProtocolError::ValueError)?;
+        .map_err(at_schema_path(&keyword_path(property_names::TYPE)))?;
     let type_value = match type_value {
         None => {
+            let ref_path = keyword_path(property_names::REF);
             let ref_value = inner_properties
                 .get_str(property_names::REF)
-                .map_err(ProtocolError::ValueError)?;
+                .map_err(at_schema_path(&ref_path))?;
             let Some(ref_value) = ref_value.strip_prefix("#/$defs/") else {
-                return Err(ProtocolError::DataContractError(
+                return Err(at_schema_path(&ref_path)(
                     DataContractError::InvalidContractStructure("malformed reference"),
                 ));
             };
             inner_properties = definition_references
                 .get_inner_borrowed_str_value_map(ref_value)
-                .map_err(ProtocolError::ValueError)?;
+                .map_err(at_schema_path(&ref_path))?;
 
-            inner_properties.get_string(property_names::TYPE)?
+            inner_properties
+                .get_string(property_names::TYPE)
+                .map_err(at_schema_path(&ref_path))?
         }
         Some(type_value) => type_value,
     };
@@ -547,53 +816,84 @@ fn insert_values_nested(
         }
         "string" => {
             field_type = DocumentFieldType::String(
-                inner_properties.get_optional_integer(property_names::MIN_LENGTH)?,
-                inner_properties.get_optional_integer(property_names::MAX_LENGTH)?,
+                inner_properties
+                    .get_optional_integer(property_names::MIN_LENGTH)
+                    .map_err(at_schema_path(&keyword_path(property_names::MIN_LENGTH)))?,
+                inner_properties
+                    .get_optional_integer(property_names::MAX_LENGTH)
+                    .map_err(at_schema_path(&keyword_path(property_names::MAX_LENGTH)))?,
             );
         }
         "array" => {
             // Only handling bytearrays for v1
             // Return an error if it is not a byte array
-            field_type = match inner_properties.get_optional_bool(property_names::BYTE_ARRAY)? {
+            let byte_array_path = keyword_path(property_names::BYTE_ARRAY);
+            field_type = match inner_properties
+                .get_optional_bool(property_names::BYTE_ARRAY)
+                .map_err(at_schema_path(&byte_array_path))?
+            {
                 Some(inner_bool) => {
                     if inner_bool {
                         match inner_properties
-                            .get_optional_str(property_names::CONTENT_MEDIA_TYPE)?
-                        {
+                            .get_optional_str(property_names::CONTENT_MEDIA_TYPE)
+                            .map_err(at_schema_path(&keyword_path(
+                                property_names::CONTENT_MEDIA_TYPE,
+                            )))? {
                             Some(content_media_type)
                                 if content_media_type == "application/x.dash.dpp.identifier" =>
                             {
                                 DocumentFieldType::Identifier
                             }
                             Some(_) | None => DocumentFieldType::ByteArray(
-                                inner_properties.get_optional_integer(property_names::MIN_ITEMS)?,
-                                inner_properties.get_optional_integer(property_names::MAX_ITEMS)?,
+                                inner_properties
+                                    .get_optional_integer(property_names::MIN_ITEMS)
+                                    .map_err(at_schema_path(&keyword_path(
+                                        property_names::MIN_ITEMS,
+                                    )))?,
+                                inner_properties
+                                    .get_optional_integer(property_names::MAX_ITEMS)
+                                    .map_err(at_schema_path(&keyword_path(
+                                        property_names::MAX_ITEMS,
+                                    )))?,
                             ),
                         }
                     } else {
-                        return Err(ProtocolError::DataContractError(
+                        return Err(at_schema_path(&byte_array_path)(
                             DataContractError::InvalidContractStructure(
                                 "byteArray should always be true if defined",
                             ),
                         ));
                     }
                 }
-                // TODO: Contract indices and new encoding format don't support arrays
-                //   but we still can use them as document fields with current cbor encoding
-                //   This is a temporary workaround to bring back v0.22 behavior and should be
-                //   replaced with a proper array support in future versions
-                None => DocumentFieldType::Array(ArrayFieldType::Boolean),
+                None => match inner_properties
+                    .get(property_names::ITEMS)
+                    .and_then(|items| items.as_array())
+                    .map(|items| {
+                        variable_type_array_field_types(items, &keyword_path(property_names::ITEMS))
+                    })
+                    .transpose()?
+                    .flatten()
+                {
+                    // A list of item schemas describes an array where every position has
+                    // its own type
+                    Some(item_types) => DocumentFieldType::VariableTypeArray(item_types),
+                    // TODO: Contract indices and new encoding format don't support arrays
+                    //   but we still can use them as document fields with current cbor encoding
+                    //   This is a temporary workaround to bring back v0.22 behavior and should be
+                    //   replaced with a proper array support in future versions
+                    None => DocumentFieldType::Array(ArrayFieldType::Boolean),
+                },
             };
         }
         "object" => {
             let mut nested_properties = BTreeMap::new();
             if let Some(properties_as_value) = inner_properties.get(property_names::PROPERTIES) {
-                let properties =
-                    properties_as_value
-                        .as_map()
-                        .ok_or(ProtocolError::StructureError(
-                            StructureError::ValueWrongType("properties must be a map"),
-                        ))?;
+                let properties_path = keyword_path(property_names::PROPERTIES);
+                let properties = properties_as_value.as_map().ok_or_else(|| {
+                    at_schema_path(&properties_path)(ProtocolError::StructureError(
+                        StructureError::ValueWrongType("properties must be a map"),
+                    ))
+                })?;
 
                 // Create a new set with the prefix removed from the keys
                 let stripped_required: BTreeSet<String> = known_required
@@ -622,9 +922,11 @@ fn insert_values_nested(
                 for (object_property_key, object_property_value) in properties.iter() {
                     let object_property_string = object_property_key
                         .as_text()
-                        .ok_or(ProtocolError::StructureError(StructureError::KeyWrongType(
-                            "property key must be a string",
-                        )))?
+                        .ok_or_else(|| {
+                            at_schema_path(&properties_path)(ProtocolError::StructureError(
+                                StructureError::KeyWrongType("property key must be a string"),
+                            ))
+                        })?
                         .to_string();
 
                     insert_values_nested(
@@ -633,31 +935,34 @@ fn insert_values_nested(
                         object_property_string,
                         object_property_value,
                         &inner_definition_references,
+                        &property_path,
                     )?;
                 }
             }
             field_type = DocumentFieldType::Object(nested_properties);
             document_properties.insert(
                 property_key,
-                DocumentField {
-                    document_type: field_type,
-                    required: is_required,
-                },
+                document_field_from_schema(field_type, is_required, None, &inner_properties),
             );
             return Ok(());
         }
         _ => {
-            field_type = string_to_field_type(type_value.as_str())
-                .ok_or(DataContractError::ValueWrongType("invalid type"))?;
+            field_type = string_to_field_type(type_value.as_str()).ok_or_else(|| {
+                at_schema_path(&keyword_path(property_names::TYPE))(
+                    DataContractError::ValueWrongType("invalid type"),
+                )
+            })?;
         }
     }
 
+    let allowed_values = enum_allowed_values(
+        &field_type,
+        &inner_properties,
+        &keyword_path(property_names::ENUM),
+    )?;
     document_properties.insert(
         property_key,
-        DocumentField {
-            document_type: field_type,
-            required: is_required,
-        },
+        document_field_from_schema(field_type, is_required, allowed_values, &inner_properties),
     );
 
     Ok(())
@@ -670,34 +975,51 @@ fn insert_values(
     property_key: String,
     property_value: &Value,
     definition_references: &BTreeMap<String, &Value>,
+    schema_path: &str,
 ) -> Result<(), ProtocolError> {
-    let mut to_visit: Vec<(Option<String>, String, &Value)> =
-        vec![(prefix, property_key, property_value)];
+    let mut to_visit: Vec<(Option<String>, String, &Value, String)> = vec![(
+        prefix,
+        property_key,
+        property_value,
+        schema_path.to_string(),
+    )];
 
-    while let Some((prefix, property_key, property_value)) = to_visit.pop() {
+    while let Some((prefix, property_key, property_value, parent_path)) = to_visit.pop() {
+        let property_path = format!(
+            "{}/{}/{}",
+            parent_path,
+            property_names::PROPERTIES,
+            escape_json_pointer_token(&property_key)
+        );
+        let keyword_path = |keyword: &str| format!("{}/{}", property_path, keyword);
         let prefixed_property_key = match prefix {
             None => property_key,
             Some(prefix) => [prefix, property_key].join(".").to_owned(),
         };
-        let mut inner_properties = property_value.to_btree_ref_string_map()?;
+        let mut inner_properties = property_value
+            .to_btree_ref_string_map()
+            .map_err(at_schema_path(&property_path))?;
         let type_value = inner_properties
             .remove_optional_string(property_names::TYPE)
-            .map_err(ProtocolError::ValueError)?;
+            .map_err(at_schema_path(&keyword_path(property_names::TYPE)))?;
         let type_value = match type_value {
             None => {
+                let ref_path = keyword_path(property_names::REF);
                 let ref_value = inner_properties
                     .get_str(property_names::REF)
-                    .map_err(ProtocolError::ValueError)?;
+                    .map_err(at_schema_path(&ref_path))?;
                 let Some(ref_value) = ref_value.strip_prefix("#/$defs/") else {
-                    return Err(ProtocolError::DataContractError(
+                    return Err(at_schema_path(&ref_path)(
                         DataContractError::InvalidContractStructure("malformed reference"),
                     ));
                 };
                 inner_properties = definition_references
                     .get_inner_borrowed_str_value_map(ref_value)
-                    .map_err(ProtocolError::ValueError)?;
+                    .map_err(at_schema_path(&ref_path))?;
 
-                inner_properties.get_string(property_names::TYPE)?
+                inner_properties
+                    .get_string(property_names::TYPE)
+                    .map_err(at_schema_path(&ref_path))?
             }
             Some(type_value) => type_value,
         };
@@ -708,12 +1030,18 @@ fn insert_values(
             "array" => {
                 // Only handling bytearrays for v1
                 // Return an error if it is not a byte array
-                field_type = match inner_properties.get_optional_bool(property_names::BYTE_ARRAY)? {
+                let byte_array_path = keyword_path(property_names::BYTE_ARRAY);
+                field_type = match inner_properties
+                    .get_optional_bool(property_names::BYTE_ARRAY)
+                    .map_err(at_schema_path(&byte_array_path))?
+                {
                     Some(inner_bool) => {
                         if inner_bool {
                             match inner_properties
-                                .get_optional_str(property_names::CONTENT_MEDIA_TYPE)?
-                            {
+                                .get_optional_str(property_names::CONTENT_MEDIA_TYPE)
+                                .map_err(at_schema_path(&keyword_path(
+                                    property_names::CONTENT_MEDIA_TYPE,
+                                )))? {
                                 Some(content_media_type)
                                     if content_media_type
                                         == "application/x.dash.dpp.identifier" =>
@@ -722,55 +1050,77 @@ fn insert_values(
                                 }
                                 Some(_) | None => DocumentFieldType::ByteArray(
                                     inner_properties
-                                        .get_optional_integer(property_names::MIN_ITEMS)?,
+                                        .get_optional_integer(property_names::MIN_ITEMS)
+                                        .map_err(at_schema_path(&keyword_path(
+                                            property_names::MIN_ITEMS,
+                                        )))?,
                                     inner_properties
-                                        .get_optional_integer(property_names::MAX_ITEMS)?,
+                                        .get_optional_integer(property_names::MAX_ITEMS)
+                                        .map_err(at_schema_path(&keyword_path(
+                                            property_names::MAX_ITEMS,
+                                        )))?,
                                 ),
                             }
                         } else {
-                            return Err(ProtocolError::DataContractError(
+                            return Err(at_schema_path(&byte_array_path)(
                                 DataContractError::InvalidContractStructure(
                                     "byteArray should always be true if defined",
                                 ),
                             ));
                         }
                     }
-                    // TODO: Contract indices and new encoding format don't support arrays
-                    //   but we still can use them as document fields with current cbor encoding
-                    //   This is a temporary workaround to bring back v0.22 behavior and should be
-                    //   replaced with a proper array support in future versions
-                    None => DocumentFieldType::Array(ArrayFieldType::Boolean),
+                    None => match inner_properties
+                        .get(property_names::ITEMS)
+                        .and_then(|items| items.as_array())
+                        .map(|items| {
+                            variable_type_array_field_types(
+                                items,
+                                &keyword_path(property_names::ITEMS),
+                            )
+                        })
+                        .transpose()?
+                        .flatten()
+                    {
+                        // A list of item schemas describes an array where every position has
+                        // its own type
+                        Some(item_types) => DocumentFieldType::VariableTypeArray(item_types),
+                        // TODO: Contract indices and new encoding format don't support arrays
+                        //   but we still can use them as document fields with current cbor encoding
+                        //   This is a temporary workaround to bring back v0.22 behavior and should be
+                        //   replaced with a proper array support in future versions
+                        None => DocumentFieldType::Array(ArrayFieldType::Boolean),
+                    },
                 };
 
                 document_properties.insert(
                     prefixed_property_key,
-                    DocumentField {
-                        document_type: field_type,
-                        required: is_required,
-                    },
+                    document_field_from_schema(field_type, is_required, None, &inner_properties),
                 );
             }
             "object" => {
                 if let Some(properties_as_value) = inner_properties.get(property_names::PROPERTIES)
                 {
-                    let properties =
-                        properties_as_value
-                            .as_map()
-                            .ok_or(ProtocolError::StructureError(
-                                StructureError::ValueWrongType("properties must be a map"),
-                            ))?;
+                    let properties_path = keyword_path(property_names::PROPERTIES);
+                    let properties = properties_as_value.as_map().ok_or_else(|| {
+                        at_schema_path(&properties_path)(ProtocolError::StructureError(
+                            StructureError::ValueWrongType("properties must be a map"),
+                        ))
+                    })?;
 
                     for (object_property_key, object_property_value) in properties.iter() {
                         let object_property_string = object_property_key
                             .as_text()
-                            .ok_or(ProtocolError::StructureError(StructureError::KeyWrongType(
-                                "property key must be a string",
-                            )))?
+                            .ok_or_else(|| {
+                                at_schema_path(&properties_path)(ProtocolError::StructureError(
+                                    StructureError::KeyWrongType("property key must be a string"),
+                                ))
+                            })?
                             .to_string();
                         to_visit.push((
                             Some(prefixed_property_key.clone()),
                             object_property_string,
                             object_property_value,
+                            property_path.clone(),
                         ));
                     }
                 }
@@ -778,27 +1128,48 @@ fn insert_values(
 
             "string" => {
                 field_type = DocumentFieldType::String(
-                    inner_properties.get_optional_integer(property_names::MIN_LENGTH)?,
-                    inner_properties.get_optional_integer(property_names::MAX_LENGTH)?,
+                    inner_properties
+                        .get_optional_integer(property_names::MIN_LENGTH)
+                        .map_err(at_schema_path(&keyword_path(property_names::MIN_LENGTH)))?,
+                    inner_properties
+                        .get_optional_integer(property_names::MAX_LENGTH)
+                        .map_err(at_schema_path(&keyword_path(property_names::MAX_LENGTH)))?,
                 );
+                let allowed_values = enum_allowed_values(
+                    &field_type,
+                    &inner_properties,
+                    &keyword_path(property_names::ENUM),
+                )?;
                 document_properties.insert(
                     prefixed_property_key,
-                    DocumentField {
-                        document_type: field_type,
-                        required: is_required,
-                    },
+                    document_field_from_schema(
+                        field_type,
+                        is_required,
+                        allowed_values,
+                        &inner_properties,
+                    ),
                 );
             }
 
             _ => {
-                field_type = string_to_field_type(type_value.as_str())
-                    .ok_or(DataContractError::ValueWrongType("invalid type"))?;
+                field_type = string_to_field_type(type_value.as_str()).ok_or_else(|| {
+                    at_schema_path(&keyword_path(property_names::TYPE))(
+                        DataContractError::ValueWrongType("invalid type"),
+                    )
+                })?;
+                let allowed_values = enum_allowed_values(
+                    &field_type,
+                    &inner_properties,
+                    &keyword_path(property_names::ENUM),
+                )?;
                 document_properties.insert(
                     prefixed_property_key,
-                    DocumentField {
-                        document_type: field_type,
-                        required: is_required,
-                    },
+                    document_field_from_schema(
+                        field_type,
+                        is_required,
+                        allowed_values,
+                        &inner_properties,
+                    ),
                 );
             }
         }
@@ -806,3 +1177,521 @@ fn insert_values(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use platform_value::platform_value;
+
+    fn schema_error_path(document_type_value: Value) -> String {
+        let error = DocumentType::from_platform_value(
+            Default::default(),
+            "profile",
+            document_type_value.to_map().expect("expected a map"),
+            &BTreeMap::new(),
+            false,
+            false,
+        )
+        .expect_err("expected the schema to be rejected");
+
+        match error {
+            ProtocolError::DataContractError(DataContractError::InvalidSchemaAtPath {
+                path,
+                ..
+            }) => path,
+            error => panic!("expected an error with a schema path, got {error:?}"),
+        }
+    }
+
+    #[test]
+    fn should_report_the_path_of_an_invalid_keyword() {
+        let document_type_value = platform_value!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "maxLength": "very long",
+                },
+            },
+        });
+
+        assert_eq!(
+            schema_error_path(document_type_value),
+            "/documents/profile/properties/name/maxLength"
+        );
+    }
+
+    #[test]
+    fn should_report_the_path_of_an_invalid_nested_property() {
+        let document_type_value = platform_value!({
+            "type": "object",
+            "properties": {
+                "address": {
+                    "type": "object",
+                    "properties": {
+                        "city": {
+                            "type": "unknown",
+                        },
+                    },
+                },
+            },
+        });
+
+        assert_eq!(
+            schema_error_path(document_type_value),
+            "/documents/profile/properties/address/properties/city/type"
+        );
+    }
+
+    #[test]
+    fn should_escape_json_pointer_tokens() {
+        assert_eq!(escape_json_pointer_token("a/b~c"), "a~1b~0c");
+    }
+
+    fn invalid_property_field(properties: Value) -> String {
+        let document_type_value = platform_value!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "maxLength": 5,
+                },
+                "address": {
+                    "type": "object",
+                    "properties": {
+                        "city": {
+                            "type": "string",
+                        },
+                    },
+                    "required": ["city"],
+                },
+            },
+            "required": ["name"],
+        });
+        let document_type = DocumentType::from_platform_value(
+            Default::default(),
+            "profile",
+            document_type_value.to_map().expect("expected a map"),
+            &BTreeMap::new(),
+            false,
+            false,
+        )
+        .expect("expected a valid document type");
+
+        let error = document_type
+            .validate_properties(&properties.into_btree_string_map().expect("expected a map"))
+            .expect_err("expected the properties to be rejected");
+
+        match error {
+            ProtocolError::DataContractError(DataContractError::InvalidDocumentProperty {
+                field,
+                ..
+            }) => field,
+            error => panic!("expected an invalid document property error, got {error:?}"),
+        }
+    }
+
+    #[test]
+    fn should_fall_back_to_boolean_arrays_for_unsupported_item_types() {
+        let document_type_value = platform_value!({
+            "type": "object",
+            "properties": {
+                "arrayOfObjects": {
+                    "type": "array",
+                    "items": [
+                        {
+                            "type": "object",
+                            "properties": {
+                                "simple": {
+                                    "type": "string",
+                                },
+                            },
+                        },
+                        {
+                            "type": "string",
+                        },
+                        {
+                            "type": "array",
+                            "items": [
+                                {
+                                    "type": "string",
+                                },
+                            ],
+                        },
+                    ],
+                },
+            },
+        });
+        let document_type = DocumentType::from_platform_value(
+            Default::default(),
+            "profile",
+            document_type_value.to_map().expect("expected a map"),
+            &BTreeMap::new(),
+            false,
+            false,
+        )
+        .expect("expected a valid document type");
+
+        assert_eq!(
+            document_type
+                .properties
+                .get("arrayOfObjects")
+                .expect("expected the arrayOfObjects field")
+                .document_type,
+            DocumentFieldType::Array(ArrayFieldType::Boolean)
+        );
+
+        assert_eq!(
+            schema_error_path(platform_value!({
+                "type": "object",
+                "properties": {
+                    "entry": {
+                        "type": "array",
+                        "items": [
+                            {
+                                "type": "object",
+                            },
+                            {
+                                "maxLength": 10,
+                            },
+                        ],
+                    },
+                },
+            })),
+            "/documents/profile/properties/entry/items/1/type"
+        );
+    }
+
+    #[test]
+    fn should_validate_required_timestamps_on_the_document() {
+        let document_type_value = platform_value!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                },
+            },
+            "required": ["name", "$createdAt", "$updatedAt"],
+        });
+        let document_type = DocumentType::from_platform_value(
+            Default::default(),
+            "profile",
+            document_type_value.to_map().expect("expected a map"),
+            &BTreeMap::new(),
+            false,
+            false,
+        )
+        .expect("expected a valid document type");
+
+        let mut document = Document {
+            properties: BTreeMap::from([("name".to_string(), Value::Text("alice".to_string()))]),
+            created_at: Some(1_000),
+            updated_at: Some(2_000),
+            ..Default::default()
+        };
+        document_type
+            .validate_document(&document)
+            .expect("expected the document to be valid");
+
+        document.updated_at = None;
+        let error = document_type
+            .validate_document(&document)
+            .expect_err("expected the document to be rejected");
+        match error {
+            ProtocolError::DataContractError(DataContractError::InvalidDocumentProperty {
+                field,
+                ..
+            }) => assert_eq!(field, "$updatedAt"),
+            error => panic!("expected an invalid document property error, got {error:?}"),
+        }
+    }
+
+    #[test]
+    fn should_list_indexable_fields() {
+        let document_type_value = platform_value!({
+            "type": "object",
+            "indices": [
+                {
+                    "name": "ownerName",
+                    "properties": [{"$ownerId": "asc"}, {"name": "asc"}],
+                    "unique": true,
+                },
+                {
+                    "name": "name",
+                    "properties": [{"name": "asc"}],
+                },
+            ],
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "maxLength": 63,
+                },
+                "bio": {
+                    "type": "string",
+                },
+            },
+        });
+        let document_type = DocumentType::from_platform_value(
+            Default::default(),
+            "profile",
+            document_type_value.to_map().expect("expected a map"),
+            &BTreeMap::new(),
+            false,
+            false,
+        )
+        .expect("expected a valid document type");
+
+        let indexable_fields = document_type.indexable_fields();
+
+        assert_eq!(
+            indexable_fields,
+            vec![
+                IndexableField {
+                    name: "$ownerId".to_string(),
+                    field_type: DocumentFieldType::ByteArray(Some(32), Some(32)),
+                    in_unique_index: true,
+                    indices: vec!["ownerName".to_string()],
+                },
+                IndexableField {
+                    name: "name".to_string(),
+                    field_type: DocumentFieldType::String(None, Some(63)),
+                    in_unique_index: true,
+                    indices: vec!["ownerName".to_string(), "name".to_string()],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_name_the_invalid_document_property() {
+        assert_eq!(
+            invalid_property_field(platform_value!({ "name": "much too long" })),
+            "name"
+        );
+        assert_eq!(invalid_property_field(platform_value!({})), "name");
+        assert_eq!(
+            invalid_property_field(platform_value!({ "name": "sam", "address": {} })),
+            "address.city"
+        );
+        assert_eq!(
+            invalid_property_field(platform_value!({ "name": "sam", "age": 3 })),
+            "age"
+        );
+    }
+
+    #[test]
+    fn should_parse_and_serialize_variable_type_arrays() {
+        let document_type_value = platform_value!({
+            "type": "object",
+            "properties": {
+                "entry": {
+                    "type": "array",
+                    "items": [
+                        {
+                            "type": "string",
+                            "maxLength": 10,
+                        },
+                        {
+                            "type": "integer",
+                        },
+                        {
+                            "type": "array",
+                            "byteArray": true,
+                            "contentMediaType": "application/x.dash.dpp.identifier",
+                        },
+                    ],
+                },
+            },
+        });
+        let document_type = DocumentType::from_platform_value(
+            Default::default(),
+            "profile",
+            document_type_value.to_map().expect("expected a map"),
+            &BTreeMap::new(),
+            false,
+            false,
+        )
+        .expect("expected a valid document type");
+
+        let field = document_type
+            .properties
+            .get("entry")
+            .expect("expected the entry field");
+        assert_eq!(
+            field.document_type,
+            DocumentFieldType::VariableTypeArray(vec![
+                ArrayFieldType::String(None, Some(10)),
+                ArrayFieldType::Integer,
+                ArrayFieldType::Identifier,
+            ])
+        );
+
+        let value = Value::Array(vec![
+            Value::Text("dash".to_string()),
+            Value::I64(7),
+            Value::Identifier([3; 32]),
+        ]);
+        field
+            .validate_value("entry", Some(&value))
+            .expect("expected the value to be valid");
+        assert!(field
+            .validate_value("entry", Some(&Value::Array(vec![Value::I64(7)])))
+            .is_err());
+
+        let serialized = field
+            .document_type
+            .encode_value_ref_with_size(&value, field.required)
+            .expect("expected to serialize the array");
+        let mut buf = std::io::BufReader::new(serialized.as_slice());
+        let deserialized = field
+            .document_type
+            .read_from(&mut buf, true)
+            .expect("expected to deserialize the array");
+        assert_eq!(deserialized, Some(value));
+    }
+
+    #[test]
+    fn should_parse_and_enforce_enum_values() {
+        let document_type_value = platform_value!({
+            "type": "object",
+            "properties": {
+                "color": {
+                    "type": "string",
+                    "enum": ["red", "green"],
+                },
+                "level": {
+                    "type": "integer",
+                    "enum": [1, 2, 3],
+                },
+            },
+        });
+        let document_type = DocumentType::from_platform_value(
+            Default::default(),
+            "profile",
+            document_type_value.to_map().expect("expected a map"),
+            &BTreeMap::new(),
+            false,
+            false,
+        )
+        .expect("expected a valid document type");
+
+        let color = document_type
+            .properties
+            .get("color")
+            .expect("expected the color field");
+        assert_eq!(
+            color.allowed_values,
+            Some(vec![
+                Value::Text("red".to_string()),
+                Value::Text("green".to_string())
+            ])
+        );
+        color
+            .validate_value("color", Some(&Value::Text("green".to_string())))
+            .expect("expected an allowed color to be valid");
+        assert!(matches!(
+            color.validate_value("color", Some(&Value::Text("blue".to_string()))),
+            Err(ProtocolError::DataContractError(
+                DataContractError::InvalidDocumentProperty {
+                    reason: "value is not one of the enum values",
+                    ..
+                }
+            ))
+        ));
+
+        let level = document_type
+            .properties
+            .get("level")
+            .expect("expected the level field");
+        level
+            .validate_value("level", Some(&Value::U64(2)))
+            .expect("expected an allowed level to be valid");
+        assert!(level.validate_value("level", Some(&Value::I64(4))).is_err());
+    }
+
+    #[test]
+    fn should_reject_an_enum_that_is_not_an_array() {
+        let document_type_value = platform_value!({
+            "type": "object",
+            "properties": {
+                "color": {
+                    "type": "string",
+                    "enum": "red",
+                },
+            },
+        });
+
+        assert_eq!(
+            schema_error_path(document_type_value),
+            "/documents/profile/properties/color/enum"
+        );
+    }
+
+    #[test]
+    fn should_enforce_the_enums_of_the_withdrawals_contract() {
+        let data_contract = crate::system_data_contracts::load_system_data_contract(
+            crate::system_data_contracts::SystemDataContract::Withdrawals,
+        )
+        .expect("expected to load the withdrawals contract");
+        let status = data_contract
+            .document_type_for_name("withdrawal")
+            .expect("expected the withdrawal document type")
+            .properties
+            .get("status")
+            .expect("expected the status field");
+
+        assert_eq!(status.allowed_values.as_ref().map(Vec::len), Some(5));
+        status
+            .validate_value("status", Some(&Value::U8(4)))
+            .expect("expected a known status to be valid");
+        assert!(status
+            .validate_value("status", Some(&Value::U8(5)))
+            .is_err());
+    }
+
+    #[test]
+    fn should_keep_the_title_description_and_comment_of_properties() {
+        let document_type_value = platform_value!({
+            "type": "object",
+            "properties": {
+                "displayName": {
+                    "type": "string",
+                    "title": "Display name",
+                    "description": "The name shown to other users",
+                    "$comment": "Not unique",
+                },
+                "bio": {
+                    "type": "string",
+                },
+            },
+        });
+        let document_type = DocumentType::from_platform_value(
+            Default::default(),
+            "profile",
+            document_type_value.to_map().expect("expected a map"),
+            &BTreeMap::new(),
+            false,
+            false,
+        )
+        .expect("expected a valid document type");
+
+        let display_name = document_type
+            .properties
+            .get("displayName")
+            .expect("expected the displayName field");
+        assert_eq!(display_name.title(), Some("Display name"));
+        assert_eq!(
+            display_name.description(),
+            Some("The name shown to other users")
+        );
+        assert_eq!(display_name.comment(), Some("Not unique"));
+
+        let bio = document_type
+            .properties
+            .get("bio")
+            .expect("expected the bio field");
+        assert_eq!(bio.title(), None);
+        assert_eq!(bio.description(), None);
+        assert_eq!(bio.comment(), None);
+    }
+}
@@ -15,7 +15,7 @@ pub use {
         encode_date_timestamp, encode_float, encode_signed_integer, encode_unsigned_integer,
         DocumentField, DocumentFieldType,
     },
-    document_type::{DocumentType, IndexLevel},
+    document_type::{DocumentType, IndexLevel, IndexableField},
     index::{Index, IndexProperty},
 };
 
@@ -31,8 +31,13 @@ pub(self) mod property_names {
     pub const UPDATED_AT: &str = "$updatedAt";
     pub const MIN_ITEMS: &str = "minItems";
     pub const MAX_ITEMS: &str = "maxItems";
+    pub const ITEMS: &str = "items";
     pub const MIN_LENGTH: &str = "minLength";
     pub const MAX_LENGTH: &str = "maxLength";
     pub const BYTE_ARRAY: &str = "byteArray";
     pub const CONTENT_MEDIA_TYPE: &str = "contentMediaType";
+    pub const ENUM: &str = "enum";
+    pub const TITLE: &str = "title";
+    pub const DESCRIPTION: &str = "description";
+    pub const COMMENT: &str = "$comment";
 }
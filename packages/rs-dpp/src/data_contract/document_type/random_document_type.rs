@@ -183,10 +183,7 @@ impl DocumentType {
                 DocumentFieldType::ByteArray(min_len, max_len)
             };
 
-            DocumentField {
-                document_type,
-                required,
-            }
+            DocumentField::new(document_type, required)
         };
 
         let optional_field_count =
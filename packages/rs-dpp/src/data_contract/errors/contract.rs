@@ -3,6 +3,7 @@ use thiserror::Error;
 
 use crate::data_contract::DataContract;
 use crate::errors::consensus::ConsensusError;
+use crate::ProtocolError;
 
 #[derive(Error, Debug)]
 pub enum DataContractError {
@@ -62,4 +63,13 @@ pub enum DataContractError {
 
     #[error("Corrupted Code Execution: {0}")]
     CorruptedCodeExecution(&'static str),
+
+    #[error("invalid schema at {path}: {error}")]
+    InvalidSchemaAtPath {
+        path: String,
+        error: Box<ProtocolError>,
+    },
+
+    #[error("invalid document property {field}: {reason}")]
+    InvalidDocumentProperty { field: String, reason: &'static str },
 }
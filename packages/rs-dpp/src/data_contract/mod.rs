@@ -7,6 +7,7 @@ pub mod errors;
 pub mod extra;
 
 mod data_contract_facade;
+mod diff;
 
 pub mod contract_config;
 mod data_contract_factory;
@@ -32,3 +33,4 @@ pub mod property_names {
 }
 
 pub use data_contract_facade::DataContractFacade;
+pub use diff::{ContractDiff, DocumentTypeDiff};
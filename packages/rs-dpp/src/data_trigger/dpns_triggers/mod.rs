@@ -2,6 +2,7 @@ use std::convert::TryInto;
 
 use anyhow::Context;
 use anyhow::{anyhow, bail};
+use data_contracts::dpns_contract::normalize_label;
 use platform_value::btreemap_extensions::BTreeValueMapHelper;
 use platform_value::btreemap_extensions::BTreeValueMapPathHelper;
 use platform_value::platform_value;
@@ -93,7 +94,7 @@ where
             result.add_error(err.into())
         }
 
-        if normalized_label != label.to_lowercase() {
+        if normalized_label != normalize_label(&label) {
             let err = create_error(
                 context,
                 dt_create.base.id,
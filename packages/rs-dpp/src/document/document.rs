@@ -44,19 +44,20 @@ use serde_json::{json, Value as JsonValue};
 use crate::data_contract::DataContract;
 use platform_value::btreemap_extensions::BTreeValueMapPathHelper;
 use platform_value::btreemap_extensions::BTreeValueRemoveFromMapHelper;
-use platform_value::Value;
+use platform_value::{ReplacementType, Value};
 use serde::{Deserialize, Serialize};
 
 use crate::data_contract::document_type::{encode_date_timestamp, DocumentType};
 use crate::data_contract::errors::DataContractError;
 
 use crate::document::errors::DocumentError;
+use crate::document::extended_document;
 
 use crate::identity::TimestampMillis;
 use crate::prelude::Identifier;
 use crate::prelude::Revision;
 
-use crate::util::hash::hash_to_vec;
+use crate::util::hash::{hash, hash_to_vec};
 use crate::util::json_value::JsonValueExt;
 use crate::ProtocolError;
 
@@ -222,6 +223,18 @@ impl Document {
         Ok(hash_to_vec(buf))
     }
 
+    /// The hash of the document an off-chain signature can commit to, the same as `hash` but
+    /// taking the contract id from the document type.
+    ///
+    /// It is the double sha256 of the contract id, the document type name and the serialized
+    /// document, which only depends on the document and its document type.
+    pub fn signable_hash(&self, document_type: &DocumentType) -> Result<[u8; 32], ProtocolError> {
+        let mut buf = document_type.data_contract_id.to_vec();
+        buf.extend(document_type.name.as_bytes());
+        buf.extend(self.serialize(document_type)?);
+        Ok(hash(buf))
+    }
+
     pub fn increment_revision(&mut self) -> Result<(), ProtocolError> {
         let Some(revision) = self.revision else {
             return Err(ProtocolError::Document(Box::new(DocumentError::DocumentNoRevisionError {
@@ -356,6 +369,41 @@ impl Document {
             .map(|v| v.try_into().map_err(ProtocolError::ValueError))?
     }
 
+    /// Converts the document to the JSON a JS client gets for it, with identifiers as base58
+    /// strings, byte arrays as base64 strings and timestamps as numbers, so documents verified
+    /// in Rust can be compared and hashed with the ones of other SDKs.
+    ///
+    /// The properties are typed against the document type, so identifiers and byte arrays
+    /// are encoded the same whether the document was deserialized or built from JSON. The
+    /// document type name and contract id are included as `$type` and `$dataContractId`.
+    pub fn to_canonical_json(
+        &self,
+        document_type: &DocumentType,
+    ) -> Result<JsonValue, ProtocolError> {
+        let mut value = self.to_object()?;
+        value.replace_at_paths(
+            document_type.identifier_paths.iter().map(|s| s.as_str()),
+            ReplacementType::Identifier,
+        )?;
+        value.replace_at_paths(
+            document_type.binary_paths.iter().map(|s| s.as_str()),
+            ReplacementType::BinaryBytes,
+        )?;
+        let mut json: JsonValue = value.try_into().map_err(ProtocolError::ValueError)?;
+        let json_mut = json.as_object_mut().unwrap();
+        json_mut.insert(
+            extended_document::property_names::DOCUMENT_TYPE.to_string(),
+            JsonValue::String(document_type.name.clone()),
+        );
+        json_mut.insert(
+            property_names::DATA_CONTRACT_ID.to_string(),
+            JsonValue::String(
+                bs58::encode(document_type.data_contract_id.to_buffer()).into_string(),
+            ),
+        );
+        Ok(json)
+    }
+
     pub fn from_json_value<S>(mut document_value: JsonValue) -> Result<Self, ProtocolError>
     where
         for<'de> S: Deserialize<'de> + TryInto<Identifier, Error = ProtocolError>,
@@ -511,4 +559,92 @@ mod tests {
         let re = Regex::new(pattern).unwrap();
         assert!(re.is_match(document_string.as_str()));
     }
+
+    #[test]
+    fn test_to_canonical_json() {
+        let contract = json_document_to_contract(
+            "../rs-dpp/src/tests/payloads/contract/dashpay-contract.json",
+        )
+        .expect("expected to get contract");
+
+        let document_type = contract
+            .document_type_for_name("contactRequest")
+            .expect("expected to get contact request document type");
+        let mut document = document_type.random_document(Some(3333));
+        // documents built from JSON hold identifiers as plain bytes
+        let to_user_id = Identifier::new([7; 32]);
+        document.set("toUserId", Value::Bytes(to_user_id.to_vec()));
+        document.set("encryptedPublicKey", Value::Bytes(vec![1, 2, 3]));
+
+        let json = document
+            .to_canonical_json(document_type)
+            .expect("expected to convert the document to canonical json");
+
+        assert_eq!(
+            json.get("$id"),
+            Some(&JsonValue::String(
+                bs58::encode(document.id.to_buffer()).into_string()
+            ))
+        );
+        assert_eq!(
+            json.get("toUserId"),
+            Some(&JsonValue::String(
+                bs58::encode(to_user_id.to_buffer()).into_string()
+            ))
+        );
+        assert_eq!(
+            json.get("encryptedPublicKey"),
+            Some(&JsonValue::String(base64::encode([1, 2, 3])))
+        );
+        assert_eq!(
+            json.get("$createdAt"),
+            document
+                .created_at
+                .map(|time| JsonValue::Number(time.into()))
+                .as_ref()
+        );
+        assert_eq!(
+            json.get("$type"),
+            Some(&JsonValue::String("contactRequest".to_string()))
+        );
+        assert_eq!(
+            json.get("$dataContractId"),
+            Some(&JsonValue::String(
+                bs58::encode(contract.id.to_buffer()).into_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_signable_hash() {
+        let contract = json_document_to_contract(
+            "../rs-dpp/src/tests/payloads/contract/dashpay-contract.json",
+        )
+        .expect("expected to get contract");
+
+        let document_type = contract
+            .document_type_for_name("profile")
+            .expect("expected to get profile document type");
+        let document = document_type.random_document(Some(3333));
+
+        let signable_hash = document
+            .signable_hash(document_type)
+            .expect("expected to hash the document");
+
+        assert_eq!(
+            signable_hash.to_vec(),
+            document
+                .hash(&contract, document_type)
+                .expect("expected to hash the document")
+        );
+
+        let mut other_document = document.clone();
+        other_document.set("displayName", Value::Text("other".to_string()));
+        assert_ne!(
+            other_document
+                .signable_hash(document_type)
+                .expect("expected to hash the document"),
+            signable_hash
+        );
+    }
 }
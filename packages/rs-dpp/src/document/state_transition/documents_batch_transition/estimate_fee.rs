@@ -0,0 +1,161 @@
+use crate::block::epoch::Epoch;
+use crate::data_contract::DataContract;
+use crate::document::document_transition::DocumentTransition;
+use crate::document::{Document, DocumentsBatchTransition};
+use crate::prelude::Identifier;
+use crate::state_transition::fee::constants::{
+    DELETE_BASE_PROCESSING_COST, PROCESSING_CREDIT_PER_BYTE, STORAGE_CREDIT_PER_BYTE,
+    WRITE_BASE_PROCESSING_COST,
+};
+use crate::state_transition::fee::Credits;
+use crate::version::PlatformVersion;
+use crate::ProtocolError;
+
+impl DocumentsBatchTransition {
+    /// Estimates the fee of applying the batch, summing the cost of every document transition.
+    ///
+    /// Creates and replaces pay storage for the serialized document, twice when the document
+    /// type keeps history as every revision is stored along the latest one, and processing for
+    /// the write. Deletes only pay processing. Refunds for replaced or deleted documents are not
+    /// known without the stored documents and are not subtracted.
+    ///
+    /// The epoch is the one the batch is expected to be applied in, storage costs do not vary
+    /// by epoch yet.
+    pub fn estimate_fee(
+        &self,
+        contract: &DataContract,
+        _epoch: &Epoch,
+        platform_version: &PlatformVersion,
+    ) -> Result<Credits, ProtocolError> {
+        match platform_version.costs.default_current_version {
+            0 => self.estimate_fee_v0(contract),
+            version => Err(ProtocolError::UnknownProtocolVersionError(format!(
+                "unknown costs version {version} for documents batch fee estimation"
+            ))),
+        }
+    }
+
+    fn estimate_fee_v0(&self, contract: &DataContract) -> Result<Credits, ProtocolError> {
+        self.transitions
+            .iter()
+            .try_fold(0 as Credits, |total, transition| {
+                total
+                    .checked_add(Self::estimate_document_transition_fee_v0(
+                        transition,
+                        contract,
+                        self.owner_id,
+                    )?)
+                    .ok_or(ProtocolError::Overflow(
+                        "documents batch fee estimation overflow",
+                    ))
+            })
+    }
+
+    fn estimate_document_transition_fee_v0(
+        transition: &DocumentTransition,
+        contract: &DataContract,
+        owner_id: Identifier,
+    ) -> Result<Credits, ProtocolError> {
+        let document_type =
+            contract.document_type_for_name(transition.base().document_type_name.as_str())?;
+        let document = match transition {
+            DocumentTransition::Create(create_transition) => Document {
+                id: create_transition.base.id,
+                owner_id,
+                properties: create_transition.data.clone().unwrap_or_default(),
+                revision: document_type.initial_revision(),
+                created_at: create_transition.created_at,
+                updated_at: create_transition.updated_at,
+            },
+            DocumentTransition::Replace(replace_transition) => Document {
+                id: replace_transition.base.id,
+                owner_id,
+                properties: replace_transition.data.clone().unwrap_or_default(),
+                revision: Some(replace_transition.revision),
+                created_at: None,
+                updated_at: replace_transition.updated_at,
+            },
+            DocumentTransition::Delete(_) => return Ok(DELETE_BASE_PROCESSING_COST),
+        };
+        let serialized_size = document.serialize(document_type)?.len() as Credits;
+        let stored_copies = if document_type.documents_keep_history {
+            2
+        } else {
+            1
+        };
+        let storage_fee = serialized_size
+            .checked_mul(STORAGE_CREDIT_PER_BYTE * stored_copies)
+            .ok_or(ProtocolError::Overflow("document storage fee is too big"))?;
+        let processing_fee = serialized_size
+            .checked_mul(PROCESSING_CREDIT_PER_BYTE)
+            .and_then(|byte_processing_fee| {
+                byte_processing_fee.checked_add(WRITE_BASE_PROCESSING_COST)
+            })
+            .ok_or(ProtocolError::Overflow(
+                "document processing fee is too big",
+            ))?;
+        storage_fee
+            .checked_add(processing_fee)
+            .ok_or(ProtocolError::Overflow("document fee is too big"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::document::document_transition::Action;
+    use crate::tests::fixtures::{
+        get_data_contract_fixture, get_document_transitions_fixture,
+        get_documents_fixture_with_owner_id_from_contract,
+    };
+
+    #[test]
+    fn should_estimate_fee_per_document() {
+        let platform_version = PlatformVersion::get(0).expect("expected platform version 0");
+        let epoch = Epoch::new(0).expect("expected epoch 0");
+        let mut data_contract = get_data_contract_fixture(None).data_contract;
+        let documents =
+            get_documents_fixture_with_owner_id_from_contract(data_contract.clone()).unwrap();
+        let (delete_transitions, create_transitions): (Vec<_>, Vec<_>) =
+            get_document_transitions_fixture([
+                (Action::Create, documents.clone()),
+                (Action::Delete, documents),
+            ])
+            .into_iter()
+            .partition(|transition| matches!(transition, DocumentTransition::Delete(_)));
+
+        let create_batch = DocumentsBatchTransition {
+            owner_id: data_contract.owner_id,
+            transitions: create_transitions,
+            ..Default::default()
+        };
+        let create_fee = create_batch
+            .estimate_fee(&data_contract, &epoch, &platform_version)
+            .expect("expected to estimate the fee");
+        assert!(
+            create_fee > create_batch.transitions.len() as Credits * WRITE_BASE_PROCESSING_COST
+        );
+
+        let delete_batch = DocumentsBatchTransition {
+            owner_id: data_contract.owner_id,
+            transitions: delete_transitions,
+            ..Default::default()
+        };
+        let delete_fee = delete_batch
+            .estimate_fee(&data_contract, &epoch, &platform_version)
+            .expect("expected to estimate the fee");
+        assert_eq!(
+            delete_fee,
+            delete_batch.transitions.len() as Credits * DELETE_BASE_PROCESSING_COST
+        );
+
+        data_contract
+            .document_types
+            .values_mut()
+            .for_each(|document_type| document_type.documents_keep_history = true);
+        let create_fee_with_history = create_batch
+            .estimate_fee(&data_contract, &epoch, &platform_version)
+            .expect("expected to estimate the fee");
+        assert!(create_fee_with_history > create_fee);
+    }
+}
@@ -42,6 +42,7 @@ use platform_serialization::{PlatformDeserialize, PlatformSerialize};
 mod action;
 pub mod apply_documents_batch_transition_factory;
 pub mod document_transition;
+mod estimate_fee;
 pub mod validation;
 
 pub use action::{DocumentsBatchTransitionAction, DOCUMENTS_BATCH_TRANSITION_ACTION_VERSION};
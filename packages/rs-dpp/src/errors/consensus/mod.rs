@@ -4,6 +4,7 @@ pub mod basic;
 pub mod codes;
 pub mod consensus_error;
 pub mod fee;
+pub mod retry_hint;
 pub mod signature;
 pub mod state;
 #[cfg(test)]
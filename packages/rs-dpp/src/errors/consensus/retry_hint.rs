@@ -0,0 +1,119 @@
+use crate::consensus::basic::BasicError;
+use crate::consensus::signature::SignatureError;
+use crate::consensus::state::state_error::StateError;
+use crate::errors::consensus::{fee::fee_error::FeeError, ConsensusError};
+
+/// What a client should do after a state transition was rejected with a consensus error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryHint {
+    /// The transition was built on an outdated revision, fetch the current revision, rebuild
+    /// the transition with it and broadcast again
+    RefetchRevisionAndRetry,
+    /// The identity can not pay for the transition, top it up and broadcast again
+    TopUpAndRetry,
+    /// Something the transition depends on is not yet known to platform, for example an
+    /// identity or contract created by a transition that is not committed yet, broadcast the
+    /// same transition again later
+    RetryLater,
+    /// The transition is invalid and will be rejected again
+    Abort,
+}
+
+impl ConsensusError {
+    /// Returns what a client should do after a state transition was rejected with this error
+    pub fn retry_hint(&self) -> RetryHint {
+        match self {
+            Self::BasicError(e) => e.retry_hint(),
+            Self::SignatureError(e) => e.retry_hint(),
+            Self::StateError(e) => e.retry_hint(),
+            Self::FeeError(e) => e.retry_hint(),
+
+            #[cfg(test)]
+            ConsensusError::TestConsensusError(_) => RetryHint::Abort,
+            ConsensusError::DefaultError => RetryHint::Abort,
+        }
+    }
+}
+
+impl BasicError {
+    fn retry_hint(&self) -> RetryHint {
+        match self {
+            Self::DataContractNotPresentError { .. }
+            | Self::IdentityAssetLockTransactionIsNotFoundError(_)
+            | Self::InvalidAssetLockProofCoreChainHeightError(_) => RetryHint::RetryLater,
+            _ => RetryHint::Abort,
+        }
+    }
+}
+
+impl SignatureError {
+    fn retry_hint(&self) -> RetryHint {
+        match self {
+            Self::IdentityNotFoundError { .. } => RetryHint::RetryLater,
+            _ => RetryHint::Abort,
+        }
+    }
+}
+
+impl FeeError {
+    fn retry_hint(&self) -> RetryHint {
+        match self {
+            Self::BalanceIsNotEnoughError { .. } => RetryHint::TopUpAndRetry,
+        }
+    }
+}
+
+impl StateError {
+    fn retry_hint(&self) -> RetryHint {
+        match self {
+            Self::InvalidIdentityRevisionError { .. }
+            | Self::InvalidDocumentRevisionError { .. } => RetryHint::RefetchRevisionAndRetry,
+            Self::IdentityInsufficientBalanceError(_) => RetryHint::TopUpAndRetry,
+            _ => RetryHint::Abort,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::consensus::fee::balance_is_not_enough_error::BalanceIsNotEnoughError;
+    use crate::consensus::state::document::document_not_found_error::DocumentNotFoundError;
+    use crate::consensus::state::identity::invalid_identity_revision_error::InvalidIdentityRevisionError;
+    use crate::consensus::state::identity::IdentityInsufficientBalanceError;
+    use crate::consensus::test_consensus_error::TestConsensusError;
+    use crate::prelude::Identifier;
+
+    #[test]
+    fn should_hint_how_to_retry_rejected_transitions() {
+        let identity_id = Identifier::new([1; 32]);
+
+        let outdated_revision = ConsensusError::from(StateError::InvalidIdentityRevisionError(
+            InvalidIdentityRevisionError::new(identity_id, 2),
+        ));
+        assert_eq!(
+            outdated_revision.retry_hint(),
+            RetryHint::RefetchRevisionAndRetry
+        );
+
+        let insufficient_balance =
+            ConsensusError::from(StateError::IdentityInsufficientBalanceError(
+                IdentityInsufficientBalanceError::new(identity_id, 0),
+            ));
+        assert_eq!(insufficient_balance.retry_hint(), RetryHint::TopUpAndRetry);
+
+        let balance_not_enough = ConsensusError::from(FeeError::BalanceIsNotEnoughError(
+            BalanceIsNotEnoughError::new(0, 1),
+        ));
+        assert_eq!(balance_not_enough.retry_hint(), RetryHint::TopUpAndRetry);
+
+        // the document was deleted or never existed, retrying the same transition fails again
+        let document_not_found = ConsensusError::from(StateError::DocumentNotFoundError(
+            DocumentNotFoundError::new(identity_id),
+        ));
+        assert_eq!(document_not_found.retry_hint(), RetryHint::Abort);
+
+        let test_error = ConsensusError::from(TestConsensusError::new("test"));
+        assert_eq!(test_error.retry_hint(), RetryHint::Abort);
+    }
+}
@@ -89,6 +89,16 @@ pub struct PartialIdentity {
     pub not_found_public_keys: BTreeSet<KeyID>,
 }
 
+/// A compact view of an identity, for displaying it without its keys.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentitySummary {
+    pub id: Identifier,
+    pub balance: u64,
+    pub public_key_count: usize,
+    pub revision: Revision,
+}
+
 mod public_key_serialization {
     use crate::identity::{IdentityPublicKey, KeyID};
     use serde::ser::SerializeSeq;
@@ -426,6 +436,16 @@ impl Identity {
             not_found_public_keys: Default::default(),
         }
     }
+
+    /// Returns the id, balance, number of keys and revision of the identity
+    pub fn summary(&self) -> IdentitySummary {
+        IdentitySummary {
+            id: self.id,
+            balance: self.balance,
+            public_key_count: self.public_keys.len(),
+            revision: self.revision,
+        }
+    }
 }
 
 impl TryFrom<Value> for Identity {
@@ -143,7 +143,20 @@ impl IdentityPublicKey {
     }
 
     /// Get the original public key hash
+    ///
+    /// This is an alias of [`IdentityPublicKey::public_key_hash`].
     pub fn hash(&self) -> Result<[u8; 20], ProtocolError> {
+        self.public_key_hash()
+    }
+
+    /// Computes the 20 byte public key hash used to look up identities by key.
+    ///
+    /// The derivation depends on the key type:
+    /// - `ECDSA_SECP256K1`: `RIPEMD160(SHA256(data))` of the 33 (or 65) byte serialized key
+    /// - `BLS12_381`: `RIPEMD160(SHA256(data))` of the 48 byte serialized key
+    /// - `ECDSA_HASH160`, `BIP13_SCRIPT_HASH`, `EDDSA_25519_HASH160`: the data already is
+    ///   the 20 byte hash and is returned as is
+    pub fn public_key_hash(&self) -> Result<[u8; 20], ProtocolError> {
         if self.data.is_empty() {
             return Err(ProtocolError::EmptyPublicKeyDataError);
         }
@@ -304,6 +317,75 @@ mod tests {
     use crate::identity::IdentityPublicKey;
     use crate::serialization_traits::{PlatformDeserializable, PlatformSerializable};
 
+    mod public_key_hash {
+        use crate::identity::{IdentityPublicKey, KeyType, Purpose, SecurityLevel};
+        use platform_value::BinaryData;
+
+        fn key_with_data(key_type: KeyType, data: Vec<u8>) -> IdentityPublicKey {
+            IdentityPublicKey {
+                id: 0,
+                purpose: Purpose::AUTHENTICATION,
+                security_level: SecurityLevel::MASTER,
+                key_type,
+                read_only: false,
+                data: BinaryData::new(data),
+                disabled_at: None,
+            }
+        }
+
+        #[test]
+        fn should_hash_ecdsa_secp256k1_key() {
+            let key = key_with_data(
+                KeyType::ECDSA_SECP256K1,
+                hex::decode("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798")
+                    .unwrap(),
+            );
+            assert_eq!(
+                hex::encode(key.public_key_hash().expect("expected a hash")),
+                "751e76e8199196d454941c45d1b3a323f1433bd6"
+            );
+        }
+
+        #[test]
+        fn should_hash_bls12_381_key() {
+            let key = key_with_data(
+                KeyType::BLS12_381,
+                hex::decode("97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb")
+                    .unwrap(),
+            );
+            assert_eq!(
+                hex::encode(key.public_key_hash().expect("expected a hash")),
+                "dae0a116606633f7c446688c85031be774ff9f65"
+            );
+        }
+
+        #[test]
+        fn should_return_data_for_hash160_key_types() {
+            let data = [7u8; 20];
+            for key_type in [
+                KeyType::ECDSA_HASH160,
+                KeyType::BIP13_SCRIPT_HASH,
+                KeyType::EDDSA_25519_HASH160,
+            ] {
+                let key = key_with_data(key_type, data.to_vec());
+                assert_eq!(key.public_key_hash().expect("expected a hash"), data);
+            }
+        }
+
+        #[test]
+        fn should_fail_on_invalid_key_sizes() {
+            assert!(key_with_data(KeyType::ECDSA_SECP256K1, vec![2; 20])
+                .public_key_hash()
+                .is_err());
+            assert!(key_with_data(KeyType::BLS12_381, vec![1; 33])
+                .public_key_hash()
+                .is_err());
+            assert!(key_with_data(KeyType::ECDSA_HASH160, vec![])
+                .public_key_hash()
+                .is_err());
+        }
+    }
+
     #[test]
     fn test_identity_key_serialization_deserialization() {
         let key = IdentityPublicKey::random_key(1, Some(500));
@@ -1,3 +1,4 @@
+use std::convert::TryInto;
 use std::sync::Arc;
 
 use dashcore::consensus;
@@ -9,9 +10,9 @@ use crate::consensus::basic::identity::{
     InvalidAssetLockTransactionOutputReturnSizeError, InvalidIdentityAssetLockTransactionError,
     InvalidIdentityAssetLockTransactionOutputError,
 };
+use crate::consensus::ConsensusError;
 use crate::state_repository::StateRepositoryLike;
 use crate::state_transition::state_transition_execution_context::StateTransitionExecutionContext;
-use crate::util::vec::vec_to_array;
 use crate::validation::ConsensusValidationResult;
 use crate::NonConsensusError;
 
@@ -62,64 +63,43 @@ where
 
         match consensus::deserialize::<Transaction>(raw_tx) {
             Ok(transaction) => {
-                let output = transaction.output.get(output_index);
-
-                if let Some(output) = output {
-                    if !output.script_pubkey.is_op_return() {
-                        result.add_error(InvalidIdentityAssetLockTransactionOutputError::new(
-                            output_index,
-                        ));
-                        return Ok(result);
-                    }
-
-                    // Slicing from 1 bytes, which is OP_RETURN, to the end of the script
-                    let public_key_hash = &output.script_pubkey.as_bytes()[2..];
-                    // 20 bytes is the size of ripemd160, which should be stored after the OP_RETURN
-                    if public_key_hash.len() != 20 {
-                        result.add_error(InvalidAssetLockTransactionOutputReturnSizeError::new(
-                            output_index,
-                        ));
-                        return Ok(result);
-                    }
-
-                    let out_point = OutPoint::new(transaction.txid(), output_index as u32);
-                    let mut out_point_buf = consensus::serialize(&out_point);
-                    let (tx_id, _) = out_point_buf.split_at_mut(32);
-                    tx_id.reverse();
-
-                    let is_out_point_used = self
-                        .state_repository
-                        .is_asset_lock_transaction_out_point_already_used(
-                            &out_point_buf,
-                            Some(execution_context),
-                        )
-                        .await
-                        .map_err(|err| {
-                            NonConsensusError::StateRepositoryFetchError(err.to_string())
-                        })?;
-
-                    if is_out_point_used {
-                        result.add_error(
-                            IdentityAssetLockTransactionOutPointAlreadyExistsError::new(
-                                transaction.txid(),
-                                output_index,
-                            ),
-                        );
-                        return Ok(result);
-                    }
-
-                    result.set_data(AssetLockTransactionResultData {
-                        public_key_hash: vec_to_array(&output.script_pubkey.as_bytes()[2..22])?,
-                        transaction,
-                    });
-
-                    Ok(result)
-                } else {
-                    result.add_error(IdentityAssetLockTransactionOutputNotFoundError::new(
+                let public_key_hash =
+                    match validate_asset_lock_transaction_output(&transaction, output_index) {
+                        Ok(public_key_hash) => public_key_hash,
+                        Err(error) => {
+                            result.add_error(error);
+                            return Ok(result);
+                        }
+                    };
+
+                let out_point = OutPoint::new(transaction.txid(), output_index as u32);
+                let mut out_point_buf = consensus::serialize(&out_point);
+                let (tx_id, _) = out_point_buf.split_at_mut(32);
+                tx_id.reverse();
+
+                let is_out_point_used = self
+                    .state_repository
+                    .is_asset_lock_transaction_out_point_already_used(
+                        &out_point_buf,
+                        Some(execution_context),
+                    )
+                    .await
+                    .map_err(|err| NonConsensusError::StateRepositoryFetchError(err.to_string()))?;
+
+                if is_out_point_used {
+                    result.add_error(IdentityAssetLockTransactionOutPointAlreadyExistsError::new(
+                        transaction.txid(),
                         output_index,
                     ));
-                    Ok(result)
+                    return Ok(result);
                 }
+
+                result.set_data(AssetLockTransactionResultData {
+                    public_key_hash,
+                    transaction,
+                });
+
+                Ok(result)
             }
             Err(err) => {
                 let error = InvalidIdentityAssetLockTransactionError::new(err.to_string());
@@ -131,3 +111,27 @@ where
         }
     }
 }
+
+/// Checks that the output of an asset lock transaction at `output_index` is an OP_RETURN
+/// burning a public key hash, and returns that hash
+pub fn validate_asset_lock_transaction_output(
+    transaction: &Transaction,
+    output_index: usize,
+) -> Result<[u8; 20], ConsensusError> {
+    let Some(output) = transaction.output.get(output_index) else {
+        return Err(IdentityAssetLockTransactionOutputNotFoundError::new(output_index).into());
+    };
+
+    if !output.script_pubkey.is_op_return() {
+        return Err(InvalidIdentityAssetLockTransactionOutputError::new(output_index).into());
+    }
+
+    // Slicing from 2 bytes, which are OP_RETURN and the push opcode, to the end of the script.
+    // 20 bytes is the size of ripemd160, which should be stored after the OP_RETURN
+    output
+        .script_pubkey
+        .as_bytes()
+        .get(2..)
+        .and_then(|public_key_hash| public_key_hash.try_into().ok())
+        .ok_or_else(|| InvalidAssetLockTransactionOutputReturnSizeError::new(output_index).into())
+}
@@ -0,0 +1,141 @@
+use crate::consensus::basic::identity::IdentityAssetLockProofLockedTransactionMismatchError;
+use crate::consensus::ConsensusError;
+use crate::identity::state_transition::asset_lock_proof::{
+    validate_asset_lock_transaction_output, AssetLockProof, InstantAssetLockProof,
+};
+use crate::identity::state_transition::identity_create_transition::IdentityCreateTransition;
+use crate::identity::state_transition::identity_public_key_transitions::IdentityPublicKeyInCreation;
+use crate::version::LATEST_VERSION;
+use crate::ProtocolError;
+
+/// Builds an identity create transition from the asset lock proof funding it.
+///
+/// The built transition still has to be signed: unique key types need their own signature
+/// over the signable bytes, and the transition itself is signed with the one time private key
+/// of the asset lock using `sign_by_private_key`.
+#[derive(Debug, Clone)]
+pub struct IdentityCreateTransitionBuilder {
+    asset_lock_proof: AssetLockProof,
+    public_keys: Vec<IdentityPublicKeyInCreation>,
+    protocol_version: u32,
+}
+
+impl IdentityCreateTransition {
+    /// Starts building an identity create transition funded by the given asset lock proof
+    pub fn builder(asset_lock_proof: AssetLockProof) -> IdentityCreateTransitionBuilder {
+        IdentityCreateTransitionBuilder {
+            asset_lock_proof,
+            public_keys: vec![],
+            protocol_version: LATEST_VERSION,
+        }
+    }
+}
+
+impl IdentityCreateTransitionBuilder {
+    /// Adds a public key to the identity that will be created
+    pub fn add_public_key(mut self, public_key: IdentityPublicKeyInCreation) -> Self {
+        self.public_keys.push(public_key);
+        self
+    }
+
+    /// Overrides the protocol version, the latest version is used by default
+    pub fn set_protocol_version(mut self, protocol_version: u32) -> Self {
+        self.protocol_version = protocol_version;
+        self
+    }
+
+    /// Checks the format of the asset lock proof and builds the transition, the identity id is
+    /// derived from the outpoint of the asset lock
+    pub fn build(self) -> Result<IdentityCreateTransition, ProtocolError> {
+        if let AssetLockProof::Instant(instant_proof) = &self.asset_lock_proof {
+            validate_instant_asset_lock_proof_format(instant_proof)?;
+        }
+
+        let mut identity_create_transition = IdentityCreateTransition::default();
+        identity_create_transition.set_protocol_version(self.protocol_version);
+        identity_create_transition.set_public_keys(self.public_keys);
+        identity_create_transition.set_asset_lock_proof(self.asset_lock_proof)?;
+
+        Ok(identity_create_transition)
+    }
+}
+
+/// Checks what can be checked without the core chain: the instant lock locks the asset lock
+/// transaction and the referenced output is an OP_RETURN carrying a 20 byte public key hash
+fn validate_instant_asset_lock_proof_format(
+    instant_proof: &InstantAssetLockProof,
+) -> Result<(), ProtocolError> {
+    let transaction = instant_proof.transaction();
+    if instant_proof.instant_lock().txid != transaction.txid() {
+        return Err(ConsensusError::from(
+            IdentityAssetLockProofLockedTransactionMismatchError::new(
+                instant_proof.instant_lock().txid,
+                transaction.txid(),
+            ),
+        )
+        .into());
+    }
+
+    validate_asset_lock_transaction_output(transaction, instant_proof.output_index())?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::identity::{KeyType, Purpose, SecurityLevel};
+    use crate::tests::fixtures::{
+        instant_asset_lock_is_lock_fixture, instant_asset_lock_proof_fixture,
+        instant_asset_lock_proof_transaction_fixture,
+    };
+    use platform_value::BinaryData;
+
+    fn public_key() -> IdentityPublicKeyInCreation {
+        IdentityPublicKeyInCreation {
+            id: 0,
+            key_type: KeyType::ECDSA_SECP256K1,
+            purpose: Purpose::AUTHENTICATION,
+            security_level: SecurityLevel::MASTER,
+            read_only: false,
+            data: BinaryData::new(vec![2; 33]),
+            signature: BinaryData::default(),
+        }
+    }
+
+    #[test]
+    fn should_build_transition_with_identity_id_from_outpoint() {
+        let asset_lock_proof = instant_asset_lock_proof_fixture(None);
+        let expected_identity_id = asset_lock_proof
+            .create_identifier()
+            .expect("expected an identifier");
+
+        let transition = IdentityCreateTransition::builder(asset_lock_proof)
+            .add_public_key(public_key())
+            .build()
+            .expect("expected to build the transition");
+
+        assert_eq!(transition.get_identity_id(), &expected_identity_id);
+        assert_eq!(transition.get_public_keys(), &[public_key()]);
+        assert_eq!(transition.protocol_version, LATEST_VERSION);
+    }
+
+    #[test]
+    fn should_reject_output_that_is_not_a_public_key_hash_burn() {
+        let transaction = instant_asset_lock_proof_transaction_fixture(None);
+        let instant_lock = instant_asset_lock_is_lock_fixture(transaction.txid());
+        // output 1 is a change output, output 2 burns only three bytes and there is no output 3
+        for output_index in [1, 2, 3] {
+            let asset_lock_proof = AssetLockProof::Instant(InstantAssetLockProof::new(
+                instant_lock.clone(),
+                transaction.clone(),
+                output_index,
+            ));
+
+            IdentityCreateTransition::builder(asset_lock_proof)
+                .add_public_key(public_key())
+                .build()
+                .expect_err("expected the asset lock proof to be rejected");
+        }
+    }
+}
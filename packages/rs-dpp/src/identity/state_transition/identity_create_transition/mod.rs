@@ -1,8 +1,10 @@
 pub use apply_identity_create_transition::*;
+pub use builder::*;
 pub use identity_create_transition::*;
 
 mod action;
 mod apply_identity_create_transition;
+mod builder;
 mod identity_create_transition;
 pub mod validation;
 pub use action::{IdentityCreateTransitionAction, IDENTITY_CREATE_TRANSITION_ACTION_VERSION};
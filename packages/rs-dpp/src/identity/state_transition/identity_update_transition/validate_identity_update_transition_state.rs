@@ -5,6 +5,8 @@ use std::sync::Arc;
 
 use crate::consensus::signature::IdentityNotFoundError;
 use crate::consensus::signature::SignatureError;
+use crate::consensus::state::identity::duplicated_identity_public_key_id_state_error::DuplicatedIdentityPublicKeyIdStateError;
+use crate::consensus::state::identity::duplicated_identity_public_key_state_error::DuplicatedIdentityPublicKeyStateError;
 use crate::consensus::state::identity::identity_public_key_disabled_at_window_violation_error::IdentityPublicKeyDisabledAtWindowViolationError;
 use crate::consensus::state::identity::identity_public_key_is_disabled_error::IdentityPublicKeyIsDisabledError;
 use crate::consensus::state::identity::identity_public_key_is_read_only_error::IdentityPublicKeyIsReadOnlyError;
@@ -12,12 +14,14 @@ use crate::consensus::state::identity::invalid_identity_public_key_id_error::Inv
 use crate::consensus::state::identity::invalid_identity_revision_error::InvalidIdentityRevisionError;
 use crate::consensus::state::state_error::StateError;
 use crate::identity::state_transition::identity_update_transition::IdentityUpdateTransitionAction;
+use crate::identity::validation::{duplicated_key_ids, duplicated_keys};
+use crate::identity::{Identity, IdentityPublicKey, KeyID};
 use crate::state_transition::state_transition_execution_context::StateTransitionExecutionContext;
 use crate::{
     block_time_window::validate_time_in_block_time_window::validate_time_in_block_time_window,
     identity::validation::{RequiredPurposeAndSecurityLevelValidator, TPublicKeysValidator},
     state_repository::StateRepositoryLike,
-    validation::ConsensusValidationResult,
+    validation::{ConsensusValidationResult, SimpleConsensusValidationResult},
     NonConsensusError,
 };
 
@@ -94,28 +98,12 @@ where
             return Ok(validation_result);
         }
 
-        for key_id in state_transition.get_public_key_ids_to_disable().iter() {
-            match identity.get_public_key_by_id(*key_id) {
-                None => {
-                    validation_result.add_error(StateError::InvalidIdentityPublicKeyIdError(
-                        InvalidIdentityPublicKeyIdError::new(*key_id),
-                    ));
-                }
-                Some(public_key_to_disable) => {
-                    if public_key_to_disable.read_only {
-                        validation_result.add_error(StateError::IdentityPublicKeyIsReadOnlyError(
-                            IdentityPublicKeyIsReadOnlyError::new(*key_id),
-                        ))
-                    }
-                    if public_key_to_disable.is_disabled() {
-                        validation_result.add_error(StateError::IdentityPublicKeyIsDisabledError(
-                            IdentityPublicKeyIsDisabledError::new(*key_id),
-                        ))
-                    }
-                }
-            }
-        }
-        if !validation_result.is_valid() {
+        let result = validate_public_keys_to_disable(
+            &identity,
+            state_transition.get_public_key_ids_to_disable(),
+        );
+        if !result.is_valid() {
+            validation_result.add_errors(result.errors);
             return Ok(validation_result);
         }
 
@@ -199,3 +187,95 @@ where
         Ok(action.into())
     }
 }
+
+impl IdentityUpdateTransition {
+    /// Checks the transition against the identity it updates, as fetched by the client, the
+    /// way its state is validated: the revision must follow the identity revision, the keys to
+    /// disable must exist and be neither read only nor disabled, and the keys to add must not
+    /// reuse the id or the data of a key of the identity.
+    ///
+    /// The disabled at time and the purposes and security levels of the keys are not checked,
+    /// they depend on the block the transition is executed in.
+    pub fn validate_against_identity(
+        &self,
+        identity: &Identity,
+    ) -> SimpleConsensusValidationResult {
+        let mut validation_result = SimpleConsensusValidationResult::default();
+
+        if identity.get_revision().checked_add(1) != Some(self.get_revision()) {
+            validation_result.add_error(StateError::InvalidIdentityRevisionError(
+                InvalidIdentityRevisionError::new(
+                    self.get_identity_id().to_owned(),
+                    identity.get_revision(),
+                ),
+            ));
+            return validation_result;
+        }
+
+        validation_result.merge(validate_public_keys_to_disable(
+            identity,
+            self.get_public_key_ids_to_disable(),
+        ));
+        if !validation_result.is_valid() {
+            return validation_result;
+        }
+
+        let public_keys = identity
+            .public_keys
+            .values()
+            .cloned()
+            .chain(
+                self.get_public_keys_to_add()
+                    .iter()
+                    .map(|k| k.to_identity_public_key()),
+            )
+            .collect::<Vec<IdentityPublicKey>>();
+
+        let duplicated_ids = duplicated_key_ids(&public_keys);
+        if !duplicated_ids.is_empty() {
+            validation_result.add_error(StateError::DuplicatedIdentityPublicKeyIdStateError(
+                DuplicatedIdentityPublicKeyIdStateError::new(duplicated_ids),
+            ));
+            return validation_result;
+        }
+
+        let duplicated_key_ids = duplicated_keys(&public_keys);
+        if !duplicated_key_ids.is_empty() {
+            validation_result.add_error(StateError::DuplicatedIdentityPublicKeyStateError(
+                DuplicatedIdentityPublicKeyStateError::new(duplicated_key_ids),
+            ));
+        }
+
+        validation_result
+    }
+}
+
+/// The keys to disable must be keys of the identity that are neither read only nor disabled
+fn validate_public_keys_to_disable(
+    identity: &Identity,
+    key_ids_to_disable: &[KeyID],
+) -> SimpleConsensusValidationResult {
+    let mut validation_result = SimpleConsensusValidationResult::default();
+    for key_id in key_ids_to_disable.iter() {
+        match identity.get_public_key_by_id(*key_id) {
+            None => {
+                validation_result.add_error(StateError::InvalidIdentityPublicKeyIdError(
+                    InvalidIdentityPublicKeyIdError::new(*key_id),
+                ));
+            }
+            Some(public_key_to_disable) => {
+                if public_key_to_disable.read_only {
+                    validation_result.add_error(StateError::IdentityPublicKeyIsReadOnlyError(
+                        IdentityPublicKeyIsReadOnlyError::new(*key_id),
+                    ))
+                }
+                if public_key_to_disable.is_disabled() {
+                    validation_result.add_error(StateError::IdentityPublicKeyIsDisabledError(
+                        IdentityPublicKeyIsDisabledError::new(*key_id),
+                    ))
+                }
+            }
+        }
+    }
+    validation_result
+}
@@ -1,6 +1,63 @@
-use crate::serialization_traits::PlatformDeserializable;
+use crate::serialization_traits::{PlatformDeserializable, PlatformSerializable};
 use crate::state_transition::StateTransition;
+use crate::util::hash::hash;
 use crate::ProtocolError;
+use bincode::config;
+use bincode::de::read::Reader;
+use bincode::error::DecodeError;
+use thiserror::Error;
+
+/// Names of the state transition variants, in the order of their wire tags
+const STATE_TRANSITION_VARIANT_NAMES: [&str; 8] = [
+    "DataContractCreate",
+    "DataContractUpdate",
+    "DocumentsBatch",
+    "IdentityCreate",
+    "IdentityTopUp",
+    "IdentityCreditWithdrawal",
+    "IdentityUpdate",
+    "IdentityCreditTransfer",
+];
+
+/// The version of the shareable format, written as its first byte
+const SHAREABLE_FORMAT_VERSION: u8 = 0;
+
+/// The size of the checksum closing the shareable format
+const SHAREABLE_CHECKSUM_SIZE: usize = 4;
+
+/// Where and why decoding a state transition from wire bytes failed
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("failed to decode {} state transition at byte {failed_at_offset}: {reason}", .variant_name.unwrap_or("unknown"))]
+pub struct DecodeDiagnostics {
+    /// The first byte, the variant tag of the state transition
+    pub transition_type_byte: Option<u8>,
+    /// The state transition variant the tag stands for, if it is a known one
+    pub variant_name: Option<&'static str>,
+    /// How many bytes were decoded before the failure
+    pub failed_at_offset: usize,
+    /// The decoding error
+    pub reason: String,
+}
+
+/// A slice reader keeping track of how far decoding got
+struct OffsetTrackingReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader for OffsetTrackingReader<'a> {
+    fn read(&mut self, bytes: &mut [u8]) -> Result<(), DecodeError> {
+        let remaining = &self.data[self.offset..];
+        if bytes.len() > remaining.len() {
+            return Err(DecodeError::UnexpectedEnd {
+                additional: bytes.len() - remaining.len(),
+            });
+        }
+        bytes.copy_from_slice(&remaining[..bytes.len()]);
+        self.offset += bytes.len();
+        Ok(())
+    }
+}
 
 impl StateTransition {
     pub fn deserialize_many(
@@ -11,6 +68,65 @@ impl StateTransition {
             .map(|raw_state_transition| Self::deserialize(raw_state_transition))
             .collect()
     }
+
+    /// Decodes a state transition like `deserialize` does, but on failure reports the
+    /// variant tag that was read and the byte offset decoding stopped at. Meant for debugging
+    /// transitions serialized by other clients.
+    pub fn inspect_bytes(data: &[u8]) -> Result<StateTransition, DecodeDiagnostics> {
+        let config = config::standard()
+            .with_big_endian()
+            .with_limit::<{ 100000 }>();
+        let mut reader = OffsetTrackingReader { data, offset: 0 };
+        bincode::decode_from_reader(&mut reader, config).map_err(|e| {
+            let transition_type_byte = data.first().copied();
+            DecodeDiagnostics {
+                transition_type_byte,
+                variant_name: transition_type_byte
+                    .and_then(|byte| STATE_TRANSITION_VARIANT_NAMES.get(byte as usize).copied()),
+                failed_at_offset: reader.offset,
+                reason: e.to_string(),
+            }
+        })
+    }
+
+    /// Serializes the signed state transition to a format meant to be handed to another device,
+    /// for example through a QR code or a file, to be broadcast from there.
+    ///
+    /// The wire bytes are preceded by the version of the format and followed by the first 4
+    /// bytes of their double sha256, with the version, so that truncated or altered payloads
+    /// are rejected by `from_shareable_bytes`.
+    pub fn to_shareable_bytes(&self) -> Result<Vec<u8>, ProtocolError> {
+        let mut bytes = vec![SHAREABLE_FORMAT_VERSION];
+        bytes.extend(self.serialize()?);
+        let checksum = hash(&bytes);
+        bytes.extend_from_slice(&checksum[..SHAREABLE_CHECKSUM_SIZE]);
+        Ok(bytes)
+    }
+
+    /// Deserializes a state transition serialized with `to_shareable_bytes`, checking its
+    /// checksum and format version first.
+    pub fn from_shareable_bytes(bytes: &[u8]) -> Result<Self, ProtocolError> {
+        if bytes.len() <= 1 + SHAREABLE_CHECKSUM_SIZE {
+            return Err(ProtocolError::DecodingError(
+                "shareable state transition is too short".to_string(),
+            ));
+        }
+        let (payload, checksum) = bytes.split_at(bytes.len() - SHAREABLE_CHECKSUM_SIZE);
+        if hash(payload)[..SHAREABLE_CHECKSUM_SIZE] != *checksum {
+            return Err(ProtocolError::DecodingError(
+                "shareable state transition checksum does not match, it is truncated or altered"
+                    .to_string(),
+            ));
+        }
+        let (version, wire_bytes) = payload.split_at(1);
+        if version[0] != SHAREABLE_FORMAT_VERSION {
+            return Err(ProtocolError::DecodingError(format!(
+                "unknown shareable state transition format version {}",
+                version[0]
+            )));
+        }
+        Self::deserialize(wire_bytes)
+    }
 }
 
 #[cfg(test)]
@@ -32,11 +148,15 @@ mod tests {
     use crate::serialization_traits::PlatformMessageSignable;
     use crate::serialization_traits::Signable;
     use crate::serialization_traits::{PlatformDeserializable, PlatformSerializable};
+    use crate::state_transition::serialization::{
+        SHAREABLE_CHECKSUM_SIZE, SHAREABLE_FORMAT_VERSION,
+    };
     use crate::state_transition::{StateTransition, StateTransitionLike, StateTransitionType};
     use crate::tests::fixtures::{
         get_data_contract_fixture, get_document_transitions_fixture,
         get_documents_fixture_with_owner_id_from_contract, raw_instant_asset_lock_proof_fixture,
     };
+    use crate::util::hash::hash;
     use crate::version::LATEST_VERSION;
     use crate::{NativeBlsModule, ProtocolError};
     use rand::rngs::StdRng;
@@ -278,4 +398,79 @@ mod tests {
             StateTransition::deserialize(&bytes).expect("expected to deserialize state transition");
         assert_eq!(state_transition, recovered_state_transition);
     }
+
+    #[test]
+    fn should_inspect_truncated_state_transition_bytes() {
+        let data_contract = get_data_contract_fixture(None).data_contract;
+        let documents =
+            get_documents_fixture_with_owner_id_from_contract(data_contract.clone()).unwrap();
+        let transitions = get_document_transitions_fixture([(Action::Create, documents)]);
+        let documents_batch_transition = DocumentsBatchTransition {
+            owner_id: data_contract.owner_id,
+            transitions,
+            ..Default::default()
+        };
+        let state_transition: StateTransition = documents_batch_transition.into();
+        let bytes = state_transition.serialize().expect("expected to serialize");
+
+        let inspected_state_transition =
+            StateTransition::inspect_bytes(&bytes).expect("expected to decode state transition");
+        assert_eq!(state_transition, inspected_state_transition);
+
+        let diagnostics = StateTransition::inspect_bytes(&bytes[..bytes.len() - 1])
+            .expect_err("expected truncated bytes not to decode");
+        assert_eq!(diagnostics.transition_type_byte, Some(2));
+        assert_eq!(diagnostics.variant_name, Some("DocumentsBatch"));
+        assert!(diagnostics.failed_at_offset > 0);
+        assert!(diagnostics.failed_at_offset < bytes.len());
+    }
+
+    #[test]
+    fn should_share_signed_state_transition_bytes() {
+        let data_contract = get_data_contract_fixture(None).data_contract;
+        let documents =
+            get_documents_fixture_with_owner_id_from_contract(data_contract.clone()).unwrap();
+        let transitions = get_document_transitions_fixture([(Action::Create, documents)]);
+        let documents_batch_transition = DocumentsBatchTransition {
+            owner_id: data_contract.owner_id,
+            transitions,
+            signature: Some([1u8; 65].to_vec().into()),
+            ..Default::default()
+        };
+        let state_transition: StateTransition = documents_batch_transition.into();
+        let bytes = state_transition
+            .to_shareable_bytes()
+            .expect("expected to serialize");
+        assert_ne!(
+            bytes,
+            state_transition.serialize().expect("expected to serialize")
+        );
+
+        let recovered_state_transition = StateTransition::from_shareable_bytes(&bytes)
+            .expect("expected to deserialize state transition");
+        assert_eq!(state_transition, recovered_state_transition);
+
+        assert!(matches!(
+            StateTransition::from_shareable_bytes(&bytes[..bytes.len() - 1]),
+            Err(ProtocolError::DecodingError(_))
+        ));
+
+        let mut altered_bytes = bytes.clone();
+        altered_bytes[10] ^= 1;
+        assert!(matches!(
+            StateTransition::from_shareable_bytes(&altered_bytes),
+            Err(ProtocolError::DecodingError(_))
+        ));
+
+        // an unknown format version is rejected even with a valid checksum
+        let mut altered_bytes = bytes[..bytes.len() - SHAREABLE_CHECKSUM_SIZE].to_vec();
+        altered_bytes[0] = SHAREABLE_FORMAT_VERSION + 1;
+        let checksum = hash(&altered_bytes);
+        altered_bytes.extend_from_slice(&checksum[..SHAREABLE_CHECKSUM_SIZE]);
+        assert!(matches!(
+            StateTransition::from_shareable_bytes(&altered_bytes),
+            Err(ProtocolError::DecodingError(message))
+                if message.contains("unknown shareable state transition format version")
+        ));
+    }
 }
@@ -198,4 +198,16 @@ mod api {
         identity.add_public_keys([identity_public_key_1, identity_public_key_2]);
         assert_eq!(99, identity.get_public_key_max_id());
     }
+
+    #[test]
+    fn should_summarize_identity() {
+        let identity = identity_fixture();
+
+        let summary = identity.summary();
+
+        assert_eq!(summary.id, identity.id);
+        assert_eq!(summary.balance, identity.balance);
+        assert_eq!(summary.public_key_count, identity.public_keys.len());
+        assert_eq!(summary.revision, identity.revision);
+    }
 }
@@ -431,3 +431,30 @@ async fn should_return_valid_result_on_dry_run() {
         .expect("the validation result should be returned");
     assert!(result.is_valid());
 }
+
+#[test]
+fn should_validate_against_identity_locally() {
+    let TestData {
+        identity,
+        mut state_transition,
+        ..
+    } = setup_test();
+
+    let result = state_transition.validate_against_identity(&identity);
+    assert!(result.is_valid());
+
+    state_transition.set_public_key_ids_to_disable(vec![5]);
+    let result = state_transition.validate_against_identity(&identity);
+    assert!(matches!(
+        get_state_error_from_result(&result, 0),
+        StateError::InvalidIdentityPublicKeyIdError(e) if e.id() == 5
+    ));
+
+    state_transition.set_public_key_ids_to_disable(vec![]);
+    state_transition.get_public_keys_to_add_mut()[0].id = 1;
+    let result = state_transition.validate_against_identity(&identity);
+    assert!(matches!(
+        get_state_error_from_result(&result, 0),
+        StateError::DuplicatedIdentityPublicKeyIdStateError(e) if e.duplicated_ids() == &vec![1]
+    ));
+}
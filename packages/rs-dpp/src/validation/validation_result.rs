@@ -1,3 +1,4 @@
+use crate::errors::consensus::codes::ErrorWithCode;
 use crate::errors::consensus::ConsensusError;
 use crate::ProtocolError;
 use std::fmt::Debug;
@@ -236,6 +237,18 @@ impl<TData: Clone, E: Debug> ValidationResult<TData, E> {
     }
 }
 
+impl<TData: Clone> ConsensusValidationResult<TData> {
+    /// User facing messages of the errors, in the order they were added
+    pub fn error_summary(&self) -> Vec<String> {
+        self.errors.iter().map(|error| error.to_string()).collect()
+    }
+
+    /// Code of the first error, if any
+    pub fn first_error_code(&self) -> Option<u32> {
+        self.first_error().map(|error| error.code())
+    }
+}
+
 impl<TData: Clone, E: Debug> From<TData> for ValidationResult<TData, E> {
     fn from(value: TData) -> Self {
         ValidationResult::new_with_data(value)
@@ -250,3 +263,27 @@ impl<TData: Clone, E: Debug, F: Into<E>> From<Result<TData, F>> for ValidationRe
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consensus::state::identity::duplicated_identity_public_key_id_state_error::DuplicatedIdentityPublicKeyIdStateError;
+
+    #[test]
+    fn should_summarize_errors() {
+        let result = SimpleConsensusValidationResult::new_with_error(
+            DuplicatedIdentityPublicKeyIdStateError::new(vec![1, 2]).into(),
+        );
+
+        assert_eq!(
+            result.error_summary(),
+            vec!["Duplicated public keys ids [1, 2] found".to_string()]
+        );
+        assert_eq!(result.first_error_code(), Some(4022));
+
+        let valid_result = SimpleConsensusValidationResult::default();
+
+        assert!(valid_result.error_summary().is_empty());
+        assert_eq!(valid_result.first_error_code(), None);
+    }
+}
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use lazy_static::lazy_static;
 
+pub use protocol_version::PlatformVersion;
 pub use protocol_version_validator::ProtocolVersionValidator;
 
 mod protocol_version;
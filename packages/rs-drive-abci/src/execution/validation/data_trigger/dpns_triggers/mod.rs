@@ -3,6 +3,7 @@ use std::collections::BTreeMap;
 
 use crate::error::execution::ExecutionError;
 use crate::error::Error;
+use dpp::data_contracts::dpns_contract::normalize_label;
 
 use dpp::document::document_transition::DocumentTransitionAction;
 use dpp::platform_value::btreemap_extensions::{BTreeValueMapHelper, BTreeValueMapPathHelper};
@@ -109,7 +110,7 @@ pub fn create_domain_data_trigger(
             result.add_error(err)
         }
 
-        if normalized_label != label.to_lowercase() {
+        if normalized_label != normalize_label(&label) {
             let err = create_error(
                 context,
                 document_create_transition,
@@ -1,3 +1,4 @@
+use crate::config::PlatformConfig;
 use crate::error::execution::ExecutionError;
 use crate::error::Error;
 use crate::rpc::core::QuorumListExtendedInfo;
@@ -203,6 +204,28 @@ impl PlatformState {
             initialization_information: None,
         }
     }
+
+    /// A deterministic initial state built from the genesis settings of a config.
+    ///
+    /// Masternode lists, quorums and validator sets are empty and no block is committed, the
+    /// core height is the configured genesis core height. This is meant for unit tests that
+    /// need a minimal state without running the chain.
+    pub fn from_genesis_config(
+        config: &PlatformConfig,
+        current_protocol_version_in_consensus: ProtocolVersion,
+        next_epoch_protocol_version: ProtocolVersion,
+    ) -> PlatformState {
+        PlatformState {
+            initialization_information: Some(PlatformInitializationState {
+                core_initialization_height: config.abci.genesis_core_height,
+            }),
+            ..Self::default_with_protocol_versions(
+                current_protocol_version_in_consensus,
+                next_epoch_protocol_version,
+            )
+        }
+    }
+
     /// The height of the platform, only committed blocks increase height
     pub fn height(&self) -> u64 {
         self.last_committed_block_info
@@ -325,3 +348,23 @@ impl PlatformState {
             )))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_genesis_config() {
+        let config = PlatformConfig::default();
+        let state = PlatformState::from_genesis_config(&config, 1, 2);
+
+        assert!(state.last_committed_block_info.is_none());
+        assert_eq!(state.current_protocol_version_in_consensus, 1);
+        assert_eq!(state.next_epoch_protocol_version, 2);
+        assert!(state.full_masternode_list.is_empty());
+        assert!(state.hpmn_masternode_list.is_empty());
+        assert!(state.validator_sets.is_empty());
+        assert_eq!(state.height(), 0);
+        assert_eq!(state.core_height(), config.abci.genesis_core_height);
+    }
+}
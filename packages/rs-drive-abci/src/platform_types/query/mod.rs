@@ -3,8 +3,13 @@ mod v0;
 use crate::error::query::QueryError;
 use crate::error::Error;
 use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::v0::PlatformState;
 
+use dapi_grpc::platform::v0::ResponseMetadata;
 use dpp::validation::ValidationResult;
+use drive::drive::verify::RootHash;
+use drive::error::Error::GroveDB;
+use drive::query::TransactionArg;
 
 /// A query validation result
 pub type QueryValidationResult<TData> = ValidationResult<TData, QueryError>;
@@ -19,4 +24,64 @@ impl<C> Platform<C> {
         //todo: choose based on protocol version
         self.query_v0(query_path, query_data)
     }
+
+    /// The root hash of the state with the metadata of the last committed block.
+    ///
+    /// Without a transaction the root hash is the app hash of that block, which lets a client
+    /// pin the root hash to a signed block and verify later proofs against it.
+    pub fn prove_root_with_metadata(
+        &self,
+        transaction: TransactionArg,
+    ) -> Result<(RootHash, ResponseMetadata), Error> {
+        let state = self.state.read().unwrap();
+        let root_hash = self
+            .drive
+            .grove
+            .root_hash(transaction)
+            .unwrap()
+            .map_err(|e| Error::Drive(GroveDB(e)))?;
+        Ok((root_hash, self.response_metadata(&state)))
+    }
+
+    /// The metadata of the last committed block returned with query responses
+    fn response_metadata(&self, state: &PlatformState) -> ResponseMetadata {
+        ResponseMetadata {
+            height: state.height(),
+            core_chain_locked_height: state.core_height(),
+            time_ms: state.last_block_time_ms().unwrap_or_default(),
+            chain_id: self.config.abci.chain_id.clone(),
+            protocol_version: state.current_protocol_version_in_consensus,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::helpers::setup::TestPlatformBuilder;
+
+    #[test]
+    fn should_prove_root_with_metadata() {
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let (root_hash, metadata) = platform
+            .prove_root_with_metadata(None)
+            .expect("expected to get the root hash with metadata");
+
+        assert_eq!(
+            root_hash,
+            platform
+                .drive
+                .grove
+                .root_hash(None)
+                .unwrap()
+                .expect("expected to get the root hash")
+        );
+
+        let state = platform.state.read().unwrap();
+        assert_eq!(metadata.height, state.height());
+        assert_eq!(metadata.core_chain_locked_height, state.core_height());
+        assert_eq!(metadata.chain_id, platform.config.abci.chain_id);
+    }
 }
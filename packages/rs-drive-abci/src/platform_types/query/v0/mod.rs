@@ -14,7 +14,7 @@ use dapi_grpc::platform::v0::{
     GetIdentityBalanceAndRevisionResponse, GetIdentityBalanceResponse,
     GetIdentityByPublicKeyHashesRequest, GetIdentityByPublicKeyHashesResponse,
     GetIdentityKeysRequest, GetIdentityKeysResponse, GetIdentityRequest, GetIdentityResponse,
-    GetProofsRequest, GetProofsResponse, Proof, ResponseMetadata,
+    GetProofsRequest, GetProofsResponse, Proof,
 };
 use dpp::identifier::Identifier;
 use dpp::platform_value::{Bytes20, Bytes32};
@@ -98,13 +98,7 @@ impl<C> Platform<C> {
         query_data: &[u8],
     ) -> Result<QueryValidationResult<Vec<u8>>, Error> {
         let state = self.state.read().unwrap();
-        let metadata = ResponseMetadata {
-            height: state.height(),
-            core_chain_locked_height: state.core_height(),
-            time_ms: state.last_block_time_ms().unwrap_or_default(),
-            chain_id: self.config.abci.chain_id.clone(),
-            protocol_version: state.current_protocol_version_in_consensus,
-        };
+        let metadata = self.response_metadata(&state);
         let quorum_type: u32 = self.config.quorum_type() as u32;
         match query_path {
             "/identity" => {
@@ -1,3 +1,5 @@
+#[cfg(feature = "arbitrary")]
+use arbitrary::{Arbitrary, Unstructured};
 use rand::distributions::{Distribution, Uniform};
 use rand::prelude::StdRng;
 use rand::Rng;
@@ -192,3 +194,21 @@ impl Frequency {
         picked_numbers
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for Frequency {
+    /// Between 0 and 4 events per block, with an optional chance of the block being hit
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let start = u.int_in_range(0..=2)?;
+        let len = u.int_in_range(1..=2)?;
+        let chance_per_block = if u.arbitrary()? {
+            Some(u.int_in_range(1..=100u8)? as f64 / 100.0)
+        } else {
+            None
+        };
+        Ok(Frequency {
+            times_per_block_range: start..start + len,
+            chance_per_block,
+        })
+    }
+}
@@ -42,7 +42,9 @@ use frequency::Frequency;
 
 use std::collections::BTreeMap;
 
-use strategy::{ChainExecutionOutcome, ChainExecutionParameters, Strategy, StrategyRandomness};
+use strategy::{
+    ChainExecutionOutcome, ChainExecutionParameters, Strategy, StrategyBuilder, StrategyRandomness,
+};
 
 mod core_update_tests;
 mod execution;
@@ -107,27 +109,7 @@ mod tests {
 
     #[test]
     fn run_chain_nothing_happening() {
-        let strategy = Strategy {
-            contracts_with_updates: vec![],
-            operations: vec![],
-            identities_inserts: Frequency {
-                times_per_block_range: Default::default(),
-                chance_per_block: None,
-            },
-            total_hpmns: 100,
-            extra_normal_mns: 0,
-            quorum_count: 24,
-            upgrading_info: None,
-            core_height_increase: Frequency {
-                times_per_block_range: Default::default(),
-                chance_per_block: None,
-            },
-            proposer_strategy: Default::default(),
-            rotate_quorums: false,
-            failure_testing: None,
-            query_testing: None,
-            verify_state_transition_results: false,
-        };
+        let strategy = StrategyBuilder::new().build();
         let config = PlatformConfig {
             verify_sum_trees: true,
             quorum_size: 100,
@@ -483,27 +465,15 @@ mod tests {
 
     #[test]
     fn run_chain_core_height_randomly_increasing_with_quorum_updates() {
-        let strategy = Strategy {
-            contracts_with_updates: vec![],
-            operations: vec![],
-            identities_inserts: Frequency {
-                times_per_block_range: Default::default(),
-                chance_per_block: None,
-            },
-            total_hpmns: 500,
-            extra_normal_mns: 0,
-            quorum_count: 24,
-            upgrading_info: None,
-            core_height_increase: Frequency {
+        let strategy = StrategyBuilder::new()
+            .total_hpmns(500)
+            .core_height_increase(Frequency {
                 times_per_block_range: 5..6,
                 chance_per_block: Some(0.5),
-            },
-            proposer_strategy: Default::default(),
-            rotate_quorums: true,
-            failure_testing: None,
-            query_testing: None,
-            verify_state_transition_results: true,
-        };
+            })
+            .rotate_quorums(true)
+            .verify_results(true)
+            .build();
         let config = PlatformConfig {
             verify_sum_trees: true,
             quorum_size: 10,
@@ -779,6 +749,41 @@ mod tests {
         assert!(has_disabled_keys);
     }
 
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn run_chain_for_arbitrary_strategy() {
+        use arbitrary::{Arbitrary, Unstructured};
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(567);
+        let data: Vec<u8> = (0..1024).map(|_| rng.gen()).collect();
+        let strategy = Strategy::arbitrary(&mut Unstructured::new(&data))
+            .expect("expected an arbitrary strategy");
+        let config = PlatformConfig {
+            verify_sum_trees: true,
+            quorum_size: 100,
+            validator_set_quorum_rotation_block_count: 25,
+            block_spacing_ms: 3000,
+            testing_configs: PlatformTestConfig::default_with_no_block_signing(),
+            ..Default::default()
+        };
+        let mut platform = TestPlatformBuilder::new()
+            .with_config(config.clone())
+            .build_with_mock_rpc();
+        platform
+            .core_rpc
+            .expect_get_best_chain_lock()
+            .returning(move || {
+                Ok(CoreChainLock {
+                    core_block_height: 10,
+                    core_block_hash: [1; 32].to_vec(),
+                    signature: [2; 96].to_vec(),
+                })
+            });
+        run_chain_for_strategy(&mut platform, 15, strategy, config, 15);
+    }
+
     #[test]
     fn run_chain_insert_one_new_identity_per_block_with_block_signing() {
         // drive_abci::logging::Loggers::default().try_install().ok();
@@ -1079,44 +1084,21 @@ mod tests {
         )
         .expect("expected to get contract from a json document");
 
-        let contract = &created_contract.data_contract;
-
-        let document_op = DocumentOp {
-            contract: contract.clone(),
-            action: DocumentAction::DocumentActionInsert,
-            document_type: contract
-                .document_type_for_name("contactRequest")
-                .expect("expected a profile document type")
-                .clone(),
-        };
+        let contract = created_contract.data_contract.clone();
 
-        let strategy = Strategy {
-            contracts_with_updates: vec![(created_contract, None)],
-            operations: vec![Operation {
-                op_type: OperationType::Document(document_op),
-                frequency: Frequency {
+        let strategy = StrategyBuilder::new()
+            .with_contract(created_contract)
+            .add_document_insert_op(
+                &contract,
+                "contactRequest",
+                Frequency {
                     times_per_block_range: 1..2,
                     chance_per_block: None,
                 },
-            }],
-            identities_inserts: Frequency {
-                times_per_block_range: 1..2,
-                chance_per_block: None,
-            },
-            total_hpmns: 100,
-            extra_normal_mns: 0,
-            quorum_count: 24,
-            upgrading_info: None,
-            core_height_increase: Frequency {
-                times_per_block_range: Default::default(),
-                chance_per_block: None,
-            },
-            proposer_strategy: Default::default(),
-            rotate_quorums: false,
-            failure_testing: None,
-            query_testing: None,
-            verify_state_transition_results: true,
-        };
+            )
+            .insert_identities_per_block(1..2)
+            .verify_results(true)
+            .build();
         let config = PlatformConfig {
             verify_sum_trees: true,
             quorum_size: 100,
@@ -1227,16 +1209,7 @@ mod tests {
         )
         .expect("expected to get contract from a json document");
 
-        let contract = &created_contract.data_contract;
-
-        let document_insertion_op = DocumentOp {
-            contract: contract.clone(),
-            action: DocumentAction::DocumentActionInsert,
-            document_type: contract
-                .document_type_for_name("contactRequest")
-                .expect("expected a profile document type")
-                .clone(),
-        };
+        let contract = created_contract.data_contract.clone();
 
         let document_deletion_op = DocumentOp {
             contract: contract.clone(),
@@ -1247,42 +1220,26 @@ mod tests {
                 .clone(),
         };
 
-        let strategy = Strategy {
-            contracts_with_updates: vec![(created_contract, None)],
-            operations: vec![
-                Operation {
-                    op_type: OperationType::Document(document_insertion_op),
-                    frequency: Frequency {
-                        times_per_block_range: 1..2,
-                        chance_per_block: None,
-                    },
+        let strategy = StrategyBuilder::new()
+            .with_contract(created_contract)
+            .add_document_insert_op(
+                &contract,
+                "contactRequest",
+                Frequency {
+                    times_per_block_range: 1..2,
+                    chance_per_block: None,
                 },
-                Operation {
-                    op_type: OperationType::Document(document_deletion_op),
-                    frequency: Frequency {
-                        times_per_block_range: 1..2,
-                        chance_per_block: None,
-                    },
+            )
+            .add_operation(
+                OperationType::Document(document_deletion_op),
+                Frequency {
+                    times_per_block_range: 1..2,
+                    chance_per_block: None,
                 },
-            ],
-            identities_inserts: Frequency {
-                times_per_block_range: 1..2,
-                chance_per_block: None,
-            },
-            total_hpmns: 100,
-            extra_normal_mns: 0,
-            quorum_count: 24,
-            upgrading_info: None,
-            core_height_increase: Frequency {
-                times_per_block_range: Default::default(),
-                chance_per_block: None,
-            },
-            proposer_strategy: Default::default(),
-            rotate_quorums: false,
-            failure_testing: None,
-            query_testing: None,
-            verify_state_transition_results: true,
-        };
+            )
+            .insert_identities_per_block(1..2)
+            .verify_results(true)
+            .build();
         let day_in_ms = 1000 * 60 * 60 * 24;
         let config = PlatformConfig {
             verify_sum_trees: true,
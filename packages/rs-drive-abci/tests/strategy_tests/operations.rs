@@ -1,4 +1,6 @@
 use crate::frequency::Frequency;
+#[cfg(feature = "arbitrary")]
+use arbitrary::{Arbitrary, Unstructured};
 use dpp::data_contract::document_type::random_document_type::RandomDocumentTypeParameters;
 use dpp::data_contract::document_type::DocumentType;
 use dpp::data_contract::DataContract as Contract;
@@ -52,6 +54,45 @@ pub enum OperationType {
     IdentityTransfer,
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for IdentityUpdateOp {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        if u.arbitrary()? {
+            Ok(IdentityUpdateOp::IdentityUpdateAddKeys(
+                u.int_in_range(1..=3)?,
+            ))
+        } else {
+            Ok(IdentityUpdateOp::IdentityUpdateDisableKey(
+                u.int_in_range(1..=2)?,
+            ))
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for OperationType {
+    /// Only identity operations are generated, document and contract operations need
+    /// contracts that an arbitrary strategy does not have
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(match u.int_in_range(0..=3)? {
+            0 => OperationType::IdentityTopUp,
+            1 => OperationType::IdentityUpdate(u.arbitrary()?),
+            2 => OperationType::IdentityWithdrawal,
+            _ => OperationType::IdentityTransfer,
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for Operation {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Operation {
+            op_type: u.arbitrary()?,
+            frequency: u.arbitrary()?,
+        })
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum FinalizeBlockOperation {
     IdentityAddKeys(Identifier, Vec<IdentityPublicKey>),
@@ -7,13 +7,15 @@ use crate::operations::{
 use crate::query::QueryStrategy;
 use crate::signer::SimpleSigner;
 use crate::BlockHeight;
+#[cfg(feature = "arbitrary")]
+use arbitrary::{Arbitrary, Unstructured};
 use dashcore_rpc::dashcore;
 use dashcore_rpc::dashcore::{ProTxHash, QuorumHash};
 use dpp::block::block_info::BlockInfo;
 use dpp::data_contract::document_type::random_document::CreateRandomDocument;
 use dpp::data_contract::state_transition::data_contract_create_transition::DataContractCreateTransition;
 use dpp::data_contract::state_transition::data_contract_update_transition::DataContractUpdateTransition;
-use dpp::data_contract::{generate_data_contract_id, CreatedDataContract};
+use dpp::data_contract::{generate_data_contract_id, CreatedDataContract, DataContract};
 use dpp::document::document_transition::document_base_transition::DocumentBaseTransition;
 use dpp::document::document_transition::{
     Action, DocumentCreateTransition, DocumentDeleteTransition, DocumentReplaceTransition,
@@ -37,6 +39,7 @@ use rand::prelude::{IteratorRandom, SliceRandom, StdRng};
 use rand::Rng;
 use std::borrow::Cow;
 use std::collections::{BTreeMap, HashMap, HashSet};
+use std::ops::Range;
 use tenderdash_abci::proto::abci::ValidatorSetUpdate;
 
 #[derive(Clone, Debug, Default)]
@@ -202,6 +205,137 @@ impl UpgradingInfo {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for Strategy {
+    /// A strategy without contracts, upgrades or failures, made for chains with a quorum
+    /// size of at most 100
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let operation_count = u.int_in_range(0..=4)?;
+        let operations = (0..operation_count)
+            .map(|_| u.arbitrary())
+            .collect::<arbitrary::Result<Vec<Operation>>>()?;
+        Ok(Strategy {
+            contracts_with_updates: vec![],
+            operations,
+            identities_inserts: u.arbitrary()?,
+            total_hpmns: u.int_in_range(100..=150)?,
+            extra_normal_mns: u.int_in_range(0..=20)?,
+            quorum_count: u.int_in_range(10..=24)?,
+            upgrading_info: None,
+            core_height_increase: u.arbitrary()?,
+            proposer_strategy: Default::default(),
+            rotate_quorums: u.arbitrary()?,
+            failure_testing: None,
+            query_testing: None,
+            verify_state_transition_results: true,
+        })
+    }
+}
+
+/// Builds a strategy from defaults: no contracts, operations or identity inserts, 100 hpmns,
+/// 24 quorums, no core height increase and no verification of state transition results
+#[derive(Clone, Debug)]
+pub struct StrategyBuilder {
+    strategy: Strategy,
+}
+
+impl Default for StrategyBuilder {
+    fn default() -> Self {
+        StrategyBuilder {
+            strategy: Strategy {
+                contracts_with_updates: vec![],
+                operations: vec![],
+                identities_inserts: Frequency::default(),
+                total_hpmns: 100,
+                extra_normal_mns: 0,
+                quorum_count: 24,
+                upgrading_info: None,
+                core_height_increase: Frequency::default(),
+                proposer_strategy: Default::default(),
+                rotate_quorums: false,
+                failure_testing: None,
+                query_testing: None,
+                verify_state_transition_results: false,
+            },
+        }
+    }
+}
+
+impl StrategyBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a contract created at the start of the chain, without updates
+    pub fn with_contract(mut self, created_contract: CreatedDataContract) -> Self {
+        self.strategy
+            .contracts_with_updates
+            .push((created_contract, None));
+        self
+    }
+
+    pub fn add_operation(mut self, op_type: OperationType, frequency: Frequency) -> Self {
+        self.strategy
+            .operations
+            .push(Operation { op_type, frequency });
+        self
+    }
+
+    /// Inserts documents of a type of the contract, the contract must be registered as well
+    pub fn add_document_insert_op(
+        self,
+        contract: &DataContract,
+        document_type_name: &str,
+        frequency: Frequency,
+    ) -> Self {
+        let document_type = contract
+            .document_type_for_name(document_type_name)
+            .expect("expected the contract to have the document type")
+            .clone();
+        self.add_operation(
+            OperationType::Document(DocumentOp {
+                contract: contract.clone(),
+                document_type,
+                action: DocumentAction::DocumentActionInsert,
+            }),
+            frequency,
+        )
+    }
+
+    /// Inserts a number of identities within the range on every block
+    pub fn insert_identities_per_block(mut self, times_per_block_range: Range<u16>) -> Self {
+        self.strategy.identities_inserts = Frequency {
+            times_per_block_range,
+            chance_per_block: None,
+        };
+        self
+    }
+
+    pub fn total_hpmns(mut self, total_hpmns: u16) -> Self {
+        self.strategy.total_hpmns = total_hpmns;
+        self
+    }
+
+    pub fn core_height_increase(mut self, core_height_increase: Frequency) -> Self {
+        self.strategy.core_height_increase = core_height_increase;
+        self
+    }
+
+    pub fn rotate_quorums(mut self, rotate_quorums: bool) -> Self {
+        self.strategy.rotate_quorums = rotate_quorums;
+        self
+    }
+
+    pub fn verify_results(mut self, verify_state_transition_results: bool) -> Self {
+        self.strategy.verify_state_transition_results = verify_state_transition_results;
+        self
+    }
+
+    pub fn build(self) -> Strategy {
+        self.strategy
+    }
+}
+
 impl Strategy {
     pub fn dont_finalize_block(&self) -> bool {
         self.failure_testing
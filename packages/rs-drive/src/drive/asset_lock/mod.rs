@@ -1,22 +1,57 @@
+#[cfg(feature = "full")]
 mod estimation_costs;
 
+#[cfg(feature = "full")]
 use crate::drive::grove_operations::DirectQueryType::{StatefulDirectQuery, StatelessDirectQuery};
+#[cfg(feature = "full")]
 use crate::drive::grove_operations::QueryTarget::QueryTargetValue;
+#[cfg(feature = "full")]
 use crate::drive::object_size_info::PathKeyElementInfo::PathFixedSizeKeyRefElement;
+#[cfg(any(feature = "full", feature = "verify"))]
 use crate::drive::{Drive, RootTree};
+#[cfg(feature = "full")]
 use crate::error::Error;
+#[cfg(feature = "full")]
 use crate::fee::op::LowLevelDriveOperation;
+#[cfg(any(feature = "full", feature = "verify"))]
 use dpp::platform_value::Bytes36;
+#[cfg(feature = "full")]
 use grovedb::batch::KeyInfoPath;
+#[cfg(feature = "full")]
 use grovedb::Element::Item;
+#[cfg(feature = "full")]
 use grovedb::{EstimatedLayerInformation, TransactionArg};
+#[cfg(any(feature = "full", feature = "verify"))]
+use grovedb::{PathQuery, Query, SizedQuery};
+#[cfg(feature = "full")]
 use std::collections::HashMap;
 
+#[cfg(any(feature = "full", feature = "verify"))]
 /// The asset lock root storage path
 pub(crate) fn asset_lock_storage_path() -> [&'static [u8]; 1] {
     [Into::<&[u8; 1]>::into(RootTree::SpentAssetLockTransactions)]
 }
 
+#[cfg(any(feature = "full", feature = "verify"))]
+/// The asset lock root storage path as a vector
+pub(crate) fn asset_lock_storage_path_vec() -> Vec<Vec<u8>> {
+    vec![vec![RootTree::SpentAssetLockTransactions as u8]]
+}
+
+#[cfg(any(feature = "full", feature = "verify"))]
+impl Drive {
+    /// The query for proving whether an asset lock outpoint was already used
+    pub(crate) fn asset_lock_outpoint_query(outpoint: &Bytes36) -> PathQuery {
+        let mut query = Query::new();
+        query.insert_key(outpoint.to_vec());
+        PathQuery::new(
+            asset_lock_storage_path_vec(),
+            SizedQuery::new(query, Some(1), None),
+        )
+    }
+}
+
+#[cfg(feature = "full")]
 impl Drive {
     /// Checks if a given `outpoint` is present as an asset lock in the transaction.
     ///
@@ -107,4 +142,62 @@ impl Drive {
         )?;
         Ok(drive_operations)
     }
+
+    /// Proves whether a given `outpoint` was already used as an asset lock.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the current object.
+    /// * `outpoint` - An `OutPoint` reference to be proved.
+    /// * `transaction` - The `TransactionArg` in which to prove the `outpoint`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the proof of presence or absence of the outpoint, or an `Error` otherwise.
+    pub fn prove_asset_lock_outpoint(
+        &self,
+        outpoint: &Bytes36,
+        transaction: TransactionArg,
+    ) -> Result<Vec<u8>, Error> {
+        let query = Self::asset_lock_outpoint_query(outpoint);
+        self.grove_get_proved_path_query(&query, false, transaction, &mut vec![])
+    }
+}
+
+#[cfg(feature = "full")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+
+    #[test]
+    fn should_prove_that_an_outpoint_is_unused_until_it_is_spent() {
+        let drive = setup_drive_with_initial_state_structure();
+        let outpoint = Bytes36::new([7; 36]);
+
+        let proof = drive
+            .prove_asset_lock_outpoint(&outpoint, None)
+            .expect("should not error when proving an outpoint");
+
+        let (_, unused) = Drive::verify_asset_lock_outpoint_unused(&proof, &outpoint)
+            .expect("expect that this be verified");
+
+        assert!(unused);
+
+        let operations = drive
+            .add_asset_lock_outpoint_operations(&outpoint, &mut None)
+            .expect("expected to get asset lock operations");
+        drive
+            .apply_batch_low_level_drive_operations(None, None, operations, &mut vec![])
+            .expect("expected to add the asset lock outpoint");
+
+        let proof = drive
+            .prove_asset_lock_outpoint(&outpoint, None)
+            .expect("should not error when proving an outpoint");
+
+        let (_, unused) = Drive::verify_asset_lock_outpoint_unused(&proof, &outpoint)
+            .expect("expect that this be verified");
+
+        assert!(!unused);
+    }
 }
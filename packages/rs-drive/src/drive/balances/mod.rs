@@ -84,6 +84,15 @@ pub(crate) fn balance_path_vec() -> Vec<Vec<u8>> {
     vec![Into::<&[u8; 1]>::into(RootTree::Balances).to_vec()]
 }
 
+/// The path of the balances of a token, identity ids are the keys
+#[cfg(any(feature = "full", feature = "verify"))]
+pub(crate) fn token_balances_path_vec(token_id: [u8; 32]) -> Vec<Vec<u8>> {
+    vec![
+        Into::<&[u8; 1]>::into(RootTree::TokenBalances).to_vec(),
+        token_id.to_vec(),
+    ]
+}
+
 /// The outcome of verifying credits
 #[cfg(feature = "full")]
 #[derive(Copy, Clone, Debug)]
@@ -1,9 +1,13 @@
 use crate::drive::batch::transitions::document::DriveHighLevelDocumentOperationConverter;
 use crate::drive::batch::transitions::DriveHighLevelOperationConverter;
 use crate::drive::batch::DriveOperation;
+use crate::drive::Drive;
 use crate::error::Error;
+use crate::fee::result::FeeResult;
+use dpp::block::block_info::BlockInfo;
 use dpp::block::epoch::Epoch;
 use dpp::document::state_transition::documents_batch_transition::DocumentsBatchTransitionAction;
+use grovedb::TransactionArg;
 
 impl DriveHighLevelOperationConverter for DocumentsBatchTransitionAction {
     fn into_high_level_drive_operations<'a>(
@@ -24,3 +28,85 @@ impl DriveHighLevelOperationConverter for DocumentsBatchTransitionAction {
             .collect())
     }
 }
+
+impl Drive {
+    /// Estimates the fee of applying a documents batch without applying it.
+    ///
+    /// The drive operations of the batch are run on the estimation path, costs are estimated
+    /// from the layer information of the trees instead of the state, so the fee is the one of
+    /// the worst case and nothing is written.
+    pub fn estimate_documents_batch_fee(
+        &self,
+        transition: &DocumentsBatchTransitionAction,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+    ) -> Result<FeeResult, Error> {
+        let operations = transition
+            .clone()
+            .into_high_level_drive_operations(&block_info.epoch)?;
+        self.apply_drive_operations(operations, false, block_info, transaction)
+    }
+}
+
+#[cfg(feature = "full")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drive::document::tests::setup_dashpay;
+    use dpp::data_contract::document_type::random_document::CreateRandomDocument;
+    use dpp::document::document_transition::{
+        DocumentBaseTransitionAction, DocumentCreateTransitionAction, DocumentTransitionAction,
+    };
+
+    #[test]
+    fn should_estimate_documents_batch_fee_without_applying() {
+        let (drive, dashpay) = setup_dashpay("estimate_documents_batch_fee", false);
+        let document_type = dashpay
+            .document_type_for_name("profile")
+            .expect("expected to get document type");
+
+        let transitions = document_type
+            .random_documents(3, Some(5))
+            .into_iter()
+            .map(|document| {
+                DocumentTransitionAction::CreateAction(DocumentCreateTransitionAction {
+                    base: DocumentBaseTransitionAction {
+                        id: document.id,
+                        document_type_name: "profile".to_string(),
+                        data_contract_id: dashpay.id,
+                        data_contract: dashpay.clone(),
+                    },
+                    created_at: document.created_at,
+                    updated_at: document.updated_at,
+                    data: document.properties,
+                })
+            })
+            .collect::<Vec<_>>();
+        let transition = DocumentsBatchTransitionAction {
+            owner_id: dashpay.owner_id,
+            transitions,
+            ..Default::default()
+        };
+
+        let root_hash_before = drive
+            .grove
+            .root_hash(None)
+            .unwrap()
+            .expect("should return app hash");
+
+        let fee_result = drive
+            .estimate_documents_batch_fee(&transition, &BlockInfo::default(), None)
+            .expect("expected to estimate the documents batch fee");
+
+        assert!(fee_result.storage_fee > 0);
+        assert!(fee_result.processing_fee > 0);
+
+        let root_hash_after = drive
+            .grove
+            .root_hash(None)
+            .unwrap()
+            .expect("should return app hash");
+
+        assert_eq!(root_hash_before, root_hash_after);
+    }
+}
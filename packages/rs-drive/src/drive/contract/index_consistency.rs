@@ -0,0 +1,91 @@
+use crate::drive::document::contract_document_type_path;
+use crate::drive::grove_operations::DirectQueryType::StatefulDirectQuery;
+use crate::drive::Drive;
+use crate::error::document::DocumentError;
+use crate::error::Error;
+use grovedb::{Element, TransactionArg};
+
+impl Drive {
+    /// Verifies that the index trees of every document type of a stored contract are in
+    /// GroveDB.
+    ///
+    /// The index structure of each document type is rebuilt from its indices, and the tree
+    /// of the primary key and the tree of each top level index property must exist under the
+    /// document type. Documents are inserted and queried along those trees, so a missing one
+    /// means the index is corrupted. Each missing tree is logged with the name of the document
+    /// type and of the tree.
+    ///
+    /// Returns `false` if a tree of any document type is missing.
+    pub fn verify_contract_index_consistency(
+        &self,
+        contract_id: [u8; 32],
+        transaction: TransactionArg,
+    ) -> Result<bool, Error> {
+        let contract_fetch_info = self
+            .get_contract_with_fetch_info(contract_id, false, transaction)?
+            .ok_or(Error::Document(DocumentError::ContractNotFound))?;
+        let mut consistent = true;
+        for (document_type_name, document_type) in &contract_fetch_info.contract.document_types {
+            let document_type_path = contract_document_type_path(&contract_id, document_type_name);
+            let tree_keys = std::iter::once(vec![0]).chain(
+                document_type
+                    .index_structure
+                    .sub_index_levels
+                    .keys()
+                    .map(|property_name| property_name.as_bytes().to_vec()),
+            );
+            for tree_key in tree_keys {
+                let element = self.grove_get_raw_optional(
+                    (&document_type_path).into(),
+                    tree_key.as_slice(),
+                    StatefulDirectQuery,
+                    transaction,
+                    &mut vec![],
+                )?;
+                if !matches!(element, Some(Element::Tree(..))) {
+                    tracing::warn!(
+                        contract_id = hex::encode(contract_id),
+                        document_type_name,
+                        tree_key = hex::encode(&tree_key),
+                        "index tree of the document type is missing"
+                    );
+                    consistent = false;
+                }
+            }
+        }
+        Ok(consistent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drive::document::tests::setup_dashpay;
+
+    #[test]
+    fn should_verify_contract_index_consistency() {
+        let (drive, dashpay) = setup_dashpay("index_consistency", false);
+        let contract_id = dashpay.id.to_buffer();
+
+        assert!(drive
+            .verify_contract_index_consistency(contract_id, None)
+            .expect("expected to verify the contract index consistency"));
+
+        drive
+            .grove_delete(
+                (&contract_document_type_path(&contract_id, "profile")).into(),
+                b"$ownerId",
+                None,
+                &mut vec![],
+            )
+            .expect("expected to delete the index tree");
+
+        assert!(!drive
+            .verify_contract_index_consistency(contract_id, None)
+            .expect("expected to verify the contract index consistency"));
+
+        drive
+            .verify_contract_index_consistency([7; 32], None)
+            .expect_err("expected the contract not to be found");
+    }
+}
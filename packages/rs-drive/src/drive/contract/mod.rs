@@ -34,6 +34,8 @@
 
 #[cfg(feature = "full")]
 mod estimation_costs;
+#[cfg(feature = "full")]
+mod index_consistency;
 /// Various paths for contract operations
 #[cfg(any(feature = "full", feature = "verify"))]
 pub(crate) mod paths;
@@ -107,6 +109,8 @@ use crate::drive::object_size_info::PathKeyInfo::PathFixedSizeKeyRef;
 #[cfg(feature = "full")]
 use crate::drive::{contract_documents_path, Drive, RootTree};
 #[cfg(feature = "full")]
+use crate::error::document::DocumentError;
+#[cfg(feature = "full")]
 use crate::error::drive::DriveError;
 #[cfg(feature = "full")]
 use crate::error::Error;
@@ -904,6 +908,45 @@ impl Drive {
         )
     }
 
+    /// Retrieves the specified contract, expecting it to be at a given version.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id` - A contract ID as a 32-byte array.
+    /// * `expected_version` - The version the contract is expected to be at.
+    /// * `transaction` - A `TransactionArg` object representing the transaction to be used
+    ///   for fetching the contract.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<Arc<ContractFetchInfo>>, Error>` - If successful, returns the contract
+    ///   with its fetch info, or `None` if the contract does not exist.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `DocumentError::ContractVersionMismatch` error if the contract
+    /// exists at another version, and an error if the contract fetching fails.
+    pub fn get_contract_expecting_version(
+        &self,
+        contract_id: [u8; 32],
+        expected_version: u32,
+        transaction: TransactionArg,
+    ) -> Result<Option<Arc<ContractFetchInfo>>, Error> {
+        let Some(contract_fetch_info) =
+            self.get_contract_with_fetch_info(contract_id, false, transaction)?
+        else {
+            return Ok(None);
+        };
+        let found = contract_fetch_info.contract.version;
+        if found != expected_version {
+            return Err(Error::Document(DocumentError::ContractVersionMismatch {
+                found,
+                expected: expected_version,
+            }));
+        }
+        Ok(Some(contract_fetch_info))
+    }
+
     /// Retrieves the specified contract along with its fetch info and calculates the fee if an epoch is provided.
     ///
     /// # Arguments
@@ -1380,6 +1423,71 @@ impl Drive {
         Ok(fees)
     }
 
+    /// Applies several contracts in a single batch and returns the aggregated fee.
+    ///
+    /// All contract operations are applied atomically in one grove batch, so either every
+    /// contract is applied or none is. Fees are calculated in the epoch of the first block info.
+    ///
+    /// # Arguments
+    ///
+    /// * `contracts` - The contracts to apply along with the block info of each contract.
+    /// * `apply` - A boolean indicating whether the contracts should be applied (`true`) or only
+    ///   their costs estimated (`false`).
+    /// * `storage_flags` - An optional `Cow<StorageFlags>` containing the storage flags for the contracts.
+    /// * `transaction` - A `TransactionArg` object representing the transaction to be used
+    ///   for applying the contracts.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the same contract is given more than once, or if the
+    /// contract application or fee calculation fails.
+    pub fn apply_contracts(
+        &self,
+        contracts: &[(Contract, BlockInfo)],
+        apply: bool,
+        storage_flags: Option<Cow<StorageFlags>>,
+        transaction: TransactionArg,
+    ) -> Result<FeeResult, Error> {
+        let Some((_, first_block_info)) = contracts.first() else {
+            return Ok(FeeResult::default());
+        };
+        let mut contract_ids = HashSet::new();
+        if !contracts
+            .iter()
+            .all(|(contract, _)| contract_ids.insert(contract.id))
+        {
+            return Err(Error::Drive(DriveError::NotSupported(
+                "applying the same contract more than once in a batch is not supported",
+            )));
+        }
+        let mut cost_operations = vec![];
+        let mut estimated_costs_only_with_layer_info = if apply {
+            None::<HashMap<KeyInfoPath, EstimatedLayerInformation>>
+        } else {
+            Some(HashMap::new())
+        };
+        let mut batch_operations = vec![];
+        for (contract, block_info) in contracts {
+            batch_operations.append(&mut self.apply_contract_operations(
+                contract,
+                block_info,
+                &mut estimated_costs_only_with_layer_info,
+                storage_flags.clone(),
+                transaction,
+            )?);
+        }
+        let fetch_cost = LowLevelDriveOperation::combine_cost_operations(&batch_operations);
+        self.apply_batch_low_level_drive_operations(
+            estimated_costs_only_with_layer_info,
+            transaction,
+            batch_operations,
+            &mut cost_operations,
+        )?;
+        cost_operations.push(CalculatedCostOperation(fetch_cost));
+        let fees = calculate_fee(None, Some(cost_operations), &first_block_info.epoch)?;
+        Ok(fees)
+    }
+
     /// Gets the operations for applying a contract
     /// If the contract already exists, we get operations for an update
     /// Otherwise we get operations for an insert
@@ -1548,6 +1656,7 @@ mod tests {
         DocumentAndContractInfo, DocumentInfo, OwnedDocumentInfo,
     };
     use crate::drive::Drive;
+    use crate::error::proof::ProofError;
     use dpp::data_contract::extra::common::json_document_to_contract;
 
     use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
@@ -1818,6 +1927,204 @@ mod tests {
             .expect("expected to apply contract successfully");
     }
 
+    #[test]
+    fn test_prove_and_verify_multiple_contracts() {
+        let (drive, contract) = setup_reference_contract();
+
+        let contract_id = contract.id.to_buffer();
+        let unknown_contract_id = [7; 32];
+
+        let proof = drive
+            .prove_contracts(&[contract_id, unknown_contract_id], None)
+            .expect("expected to prove contracts");
+
+        let (_, contracts) =
+            Drive::verify_contracts(proof.as_slice(), &[contract_id, unknown_contract_id])
+                .expect("expected to verify contracts");
+
+        assert_eq!(contracts.len(), 2);
+        assert_eq!(
+            contracts
+                .get(&contract_id)
+                .expect("expected the contract to be present")
+                .as_ref()
+                .map(|proved_contract| proved_contract.id),
+            Some(contract.id)
+        );
+        assert_eq!(
+            contracts
+                .get(&unknown_contract_id)
+                .expect("expected the unknown contract to be present"),
+            &None
+        );
+    }
+
+    #[test]
+    fn test_prove_and_verify_contracts_exist() {
+        let (drive, contract) = setup_reference_contract();
+
+        let contract_id = contract.id.to_buffer();
+        let unknown_contract_id = [7; 32];
+
+        let proof = drive
+            .prove_contracts_exist(&[contract_id, unknown_contract_id], None)
+            .expect("expected to prove contracts exist");
+
+        let (_, contracts_exist) =
+            Drive::verify_contracts_exist(proof.as_slice(), &[contract_id, unknown_contract_id])
+                .expect("expected to verify contracts exist");
+
+        assert_eq!(
+            contracts_exist,
+            BTreeMap::from([(contract_id, true), (unknown_contract_id, false)])
+        );
+
+        let full_proof = drive
+            .prove_contracts(&[contract_id], None)
+            .expect("expected to prove contracts");
+        assert!(proof.len() < full_proof.len());
+    }
+
+    #[test]
+    fn test_prove_and_verify_contract_schema_defs() {
+        let (drive, contract) = setup_reference_contract();
+
+        let contract_id = contract.id.to_buffer();
+
+        let proof = drive
+            .prove_contract(contract_id, None)
+            .expect("expected to prove the contract");
+
+        let (_, defs) = Drive::verify_contract_schema_defs(proof.as_slice(), contract_id, false)
+            .expect("expected to verify the contract schema defs");
+
+        let defs = defs.expect("expected the contract to exist");
+        assert_eq!(Some(&defs), contract.defs.as_ref());
+        assert!(defs.contains_key("regex"));
+    }
+
+    #[test]
+    fn test_prove_and_verify_contract_owner() {
+        let (drive, contract) = setup_reference_contract();
+
+        let contract_id = contract.id.to_buffer();
+
+        let proof = drive
+            .prove_contract(contract_id, None)
+            .expect("expected to prove the contract");
+
+        let (_, owner_id) = Drive::verify_contract_owner(proof.as_slice(), contract_id, false)
+            .expect("expected to verify the contract owner");
+
+        assert_eq!(owner_id, Some(contract.owner_id.to_buffer()));
+
+        let unknown_contract_id = [7; 32];
+        let proof = drive
+            .prove_contract(unknown_contract_id, None)
+            .expect("expected to prove the contract absence");
+
+        let (_, owner_id) =
+            Drive::verify_contract_owner(proof.as_slice(), unknown_contract_id, false)
+                .expect("expected to verify the contract owner");
+
+        assert_eq!(owner_id, None);
+    }
+
+    #[test]
+    fn test_verify_contract_against_trusted_root() {
+        let (drive, contract) = setup_reference_contract();
+
+        let contract_id = contract.id.to_buffer();
+
+        let root_hash = drive
+            .grove
+            .root_hash(None)
+            .unwrap()
+            .expect("there is always a root hash");
+
+        let proof = drive
+            .prove_contract(contract_id, None)
+            .expect("expected to prove the contract");
+
+        let proved_contract = Drive::verify_contract_with_expected_root(
+            proof.as_slice(),
+            None,
+            false,
+            contract_id,
+            root_hash,
+        )
+        .expect("expected to verify the contract against the trusted root hash");
+
+        assert_eq!(
+            proved_contract.map(|proved_contract| proved_contract.id),
+            Some(contract.id)
+        );
+
+        let mut wrong_root_hash = root_hash;
+        wrong_root_hash[0] = wrong_root_hash[0].wrapping_add(1);
+
+        assert!(matches!(
+            Drive::verify_contract_with_expected_root(
+                proof.as_slice(),
+                None,
+                false,
+                contract_id,
+                wrong_root_hash,
+            ),
+            Err(Error::Proof(ProofError::IncorrectRootHash { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_apply_contracts_in_one_batch() {
+        let drive = setup_drive_with_initial_state_structure();
+
+        let contracts = [
+            "tests/supporting_files/contract/references/references.json",
+            "tests/supporting_files/contract/family/family-contract.json",
+        ]
+        .into_iter()
+        .map(|contract_path| {
+            (
+                json_document_to_contract(contract_path).expect("expected to get a contract"),
+                BlockInfo::default(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+        let fee_result = drive
+            .apply_contracts(
+                &contracts,
+                true,
+                StorageFlags::optional_default_as_cow(),
+                None,
+            )
+            .expect("expected to apply contracts successfully");
+
+        assert_ne!(fee_result, FeeResult::default());
+
+        for (contract, _) in contracts.iter() {
+            drive
+                .get_contract_with_fetch_info(contract.id.to_buffer(), false, None)
+                .expect("should get contract")
+                .expect("should be present");
+        }
+
+        let duplicated_contracts = vec![contracts[0].clone(), contracts[0].clone()];
+
+        let result = drive.apply_contracts(
+            &duplicated_contracts,
+            true,
+            StorageFlags::optional_default_as_cow(),
+            None,
+        );
+
+        assert!(matches!(
+            result,
+            Err(Error::Drive(DriveError::NotSupported(_)))
+        ));
+    }
+
     mod get_contract_with_fetch_info {
         use super::*;
         use dpp::prelude::Identifier;
@@ -1868,6 +2175,31 @@ mod tests {
             assert!(result.1.is_none());
         }
 
+        #[test]
+        fn should_get_contract_expecting_version() {
+            let (drive, contract) = setup_reference_contract();
+
+            let fetch_info = drive
+                .get_contract_expecting_version(contract.id.to_buffer(), 1, None)
+                .expect("should get contract")
+                .expect("should be present");
+
+            assert_eq!(fetch_info.contract.version, 1);
+
+            assert!(matches!(
+                drive.get_contract_expecting_version(contract.id.to_buffer(), 2, None),
+                Err(Error::Document(DocumentError::ContractVersionMismatch {
+                    found: 1,
+                    expected: 2
+                }))
+            ));
+
+            assert!(drive
+                .get_contract_expecting_version([0; 32], 1, None)
+                .expect("should not error for a missing contract")
+                .is_none());
+        }
+
         #[test]
         fn should_return_fees_for_non_existing_contract_if_epoch_is_passed() {
             let drive = setup_drive_with_initial_state_structure();
@@ -2247,6 +2579,64 @@ mod tests {
             );
         }
 
+        #[test]
+        fn should_prove_full_history_including_a_revision_at_time_0() {
+            let TestData {
+                mut data_contract,
+                drive,
+            } = setup_test();
+
+            data_contract.config.keeps_history = true;
+            data_contract.config.readonly = false;
+
+            apply_contract(&drive, &data_contract, BlockInfo::default());
+            insert_n_contract_updates(&data_contract, &drive, 2);
+
+            let contract_id = data_contract.id.to_buffer();
+
+            let proof = drive
+                .prove_contract_full_history(contract_id, None, None, None)
+                .expect("expected to prove the contract history");
+
+            let (_, history) =
+                Drive::verify_contract_full_history(proof.as_slice(), contract_id, None, None)
+                    .expect("expected to verify the contract history");
+
+            let history = history.expect("expected a history");
+
+            assert_eq!(
+                history.keys().copied().collect::<Vec<u64>>(),
+                vec![0, 2000, 3000]
+            );
+
+            let proof = drive
+                .prove_contract_full_history(contract_id, None, Some(2), None)
+                .expect("expected to prove the contract history");
+
+            let (_, history) =
+                Drive::verify_contract_full_history(proof.as_slice(), contract_id, Some(2), None)
+                    .expect("expected to verify the contract history");
+
+            assert_eq!(
+                history
+                    .expect("expected a history")
+                    .keys()
+                    .copied()
+                    .collect::<Vec<u64>>(),
+                vec![0, 2000]
+            );
+
+            let proof = drive
+                .prove_contract_history(contract_id, None, 0, None, None)
+                .expect("expected to prove the contract history");
+
+            let (_, history) =
+                Drive::verify_contract_history(proof.as_slice(), contract_id, 0, None, None)
+                    .expect("expected to verify the contract history");
+
+            assert_eq!(history.expect("expected a history").len(), 2);
+        }
+
         fn setup_test() -> TestData {
             let data_contract = get_data_contract_fixture(None).data_contract;
 
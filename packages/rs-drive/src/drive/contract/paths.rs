@@ -76,6 +76,11 @@ pub(crate) fn all_contracts_global_root_path() -> [&'static [u8]; 1] {
     [Into::<&[u8; 1]>::into(RootTree::ContractDocuments)]
 }
 
+/// The global root path for all contracts as a vector
+pub(crate) fn all_contracts_global_root_path_vec() -> Vec<Vec<u8>> {
+    vec![Into::<&[u8; 1]>::into(RootTree::ContractDocuments).to_vec()]
+}
+
 /// Takes a contract ID and returns the contract's root path.
 pub(crate) fn contract_root_path(contract_id: &[u8]) -> [&[u8]; 2] {
     [
@@ -92,6 +92,30 @@ impl Drive {
         self.grove_get_proved_path_query(&contracts_query, false, transaction, &mut vec![])
     }
 
+    /// Proves whether the specified contracts exist, without proving their data.
+    ///
+    /// The proof only covers the contracts' keys under the global contracts root, so it
+    /// stays compact whatever the size of the contracts.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_ids` - A slice of contract IDs as 32-byte arrays.
+    /// * `transaction` - A `TransactionArg` object representing the transaction to be used
+    ///   for proving the existence of the contracts. This is either None or Some(&Transaction).
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<u8>, Error>` - If successful, returns a `Vec<u8>` containing the proof data.
+    ///   If an error occurs during the proof generation, returns an `Error`.
+    pub fn prove_contracts_exist(
+        &self,
+        contract_ids: &[[u8; 32]],
+        transaction: TransactionArg,
+    ) -> Result<Vec<u8>, Error> {
+        let contracts_exist_query = Self::contracts_exist_query(contract_ids);
+        self.grove_get_proved_path_query(&contracts_exist_query, false, transaction, &mut vec![])
+    }
+
     /// Proves the existence of the specified contract's history.
     ///
     /// This function creates a path query for each for the given contract id and limit and offset
@@ -126,4 +150,34 @@ impl Drive {
 
         self.grove_get_proved_path_query(&history_query, false, transaction, &mut vec![])
     }
+
+    /// Proves the existence of the specified contract's full history, from its earliest revision.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id` - A contract IDs as 32-byte array.
+    /// * `transaction` - A `TransactionArg` object representing the transaction to be used
+    ///   for proving the existence of the contracts. This is either None or Some(&Transaction).
+    /// * `limit` - The maximum number of items to return.
+    /// * `offset` - The number of items to skip before returning results.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<u8>, Error>` - If successful, returns a `Vec<u8>` containing the proof data.
+    ///   If an error occurs during the proof generation, returns an `Error`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the path query generation or proof generation fails.
+    pub fn prove_contract_full_history(
+        &self,
+        contract_id: [u8; 32],
+        transaction: TransactionArg,
+        limit: Option<u16>,
+        offset: Option<u16>,
+    ) -> Result<Vec<u8>, Error> {
+        let history_query = Self::fetch_contract_full_history_query(contract_id, limit, offset)?;
+
+        self.grove_get_proved_path_query(&history_query, false, transaction, &mut vec![])
+    }
 }
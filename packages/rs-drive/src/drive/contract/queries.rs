@@ -1,6 +1,7 @@
 use crate::common::encode::encode_u64;
 use crate::drive::contract::paths::{
-    contract_keeping_history_storage_path_vec, contract_root_path_vec,
+    all_contracts_global_root_path_vec, contract_keeping_history_storage_path_vec,
+    contract_root_path_vec,
 };
 use crate::drive::contract::{paths, MAX_CONTRACT_HISTORY_FETCH_LIMIT};
 use crate::drive::Drive;
@@ -72,6 +73,29 @@ impl Drive {
         PathQuery::merge(queries.iter().collect()).map_err(GroveDB)
     }
 
+    /// Creates a path query for the root trees of multiple contracts.
+    ///
+    /// Each contract has a tree under the global contracts root keyed by its id, so querying
+    /// these keys proves whether the contracts exist without proving their data.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_ids` - A slice of contract IDs as 32-byte arrays.
+    ///
+    /// # Returns
+    ///
+    /// * `PathQuery` - A `PathQuery` object representing the query for the contracts' root trees.
+    pub fn contracts_exist_query(contract_ids: &[[u8; 32]]) -> PathQuery {
+        let mut query = Query::new();
+        query.insert_keys(
+            contract_ids
+                .iter()
+                .map(|contract_id| contract_id.to_vec())
+                .collect(),
+        );
+        PathQuery::new_unsized(all_contracts_global_root_path_vec(), query)
+    }
+
     /// Creates a path query for historical entries of a specified contract.
     ///
     /// This function takes a slice of contract IDs and creates a path query for fetching
@@ -116,4 +140,44 @@ impl Drive {
             SizedQuery::new(query, Some(limit), offset),
         ))
     }
+
+    /// Creates a path query for all historical entries of a specified contract, in ascending
+    /// order starting from the earliest one, so a limit keeps the earliest entries.
+    ///
+    /// Unlike `fetch_contract_history_query` with a start date of 0, which excludes an entry
+    /// stored at time 0, this includes every stored revision.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id` - A contract ID as a 32-byte array.
+    /// * `limit` - The maximum number of items to return.
+    /// * `offset` - The number of items to skip before returning results.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the limit is out of the allowed range.
+    pub fn fetch_contract_full_history_query(
+        contract_id: [u8; 32],
+        limit: Option<u16>,
+        offset: Option<u16>,
+    ) -> Result<PathQuery, Error> {
+        let limit = limit.unwrap_or(MAX_CONTRACT_HISTORY_FETCH_LIMIT);
+        if !(1..=MAX_CONTRACT_HISTORY_FETCH_LIMIT).contains(&limit) {
+            return Err(Error::Drive(DriveError::InvalidContractHistoryFetchLimit(
+                limit,
+            )));
+        }
+
+        let query = Query::new_single_query_item_with_direction(
+            QueryItem::RangeFrom(std::ops::RangeFrom {
+                start: encode_u64(0),
+            }),
+            true,
+        );
+
+        Ok(PathQuery::new(
+            paths::contract_keeping_history_storage_path_vec(&contract_id),
+            SizedQuery::new(query, Some(limit), offset),
+        ))
+    }
 }
@@ -0,0 +1,160 @@
+use crate::drive::document::contract_documents_primary_key_path;
+use crate::drive::Drive;
+use crate::error::document::DocumentError;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use grovedb::TransactionArg;
+
+/// The number of document ids fetched from storage at a time
+const DOCUMENT_IDS_PAGE_SIZE: u16 = 100;
+
+/// Walks the primary key tree of a document type page by page, yielding document ids
+struct DocumentIds<'a> {
+    drive: &'a Drive,
+    primary_key_path: Vec<Vec<u8>>,
+    transaction: TransactionArg<'a, 'a>,
+    page: std::vec::IntoIter<Vec<u8>>,
+    last_key: Option<Vec<u8>>,
+    exhausted: bool,
+}
+
+impl<'a> DocumentIds<'a> {
+    /// Fetches the ids after the last yielded one
+    fn fetch_next_page(&mut self) -> Result<(), Error> {
+        let keys = self
+            .drive
+            .grove_get_raw_page_after_key(
+                self.primary_key_path.clone(),
+                self.last_key.take(),
+                DOCUMENT_IDS_PAGE_SIZE,
+                self.transaction,
+                &mut vec![],
+            )?
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect::<Vec<_>>();
+        if keys.len() < DOCUMENT_IDS_PAGE_SIZE as usize {
+            self.exhausted = true;
+        }
+        self.page = keys.into_iter();
+        Ok(())
+    }
+}
+
+impl<'a> Iterator for DocumentIds<'a> {
+    type Item = Result<[u8; 32], Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(key) = self.page.next() {
+                self.last_key = Some(key.clone());
+                return Some(key.try_into().map_err(|_| {
+                    Error::Drive(DriveError::CorruptedDriveState(
+                        "document primary keys should be document ids of 32 bytes".to_string(),
+                    ))
+                }));
+            }
+            if self.exhausted {
+                return None;
+            }
+            if let Err(e) = self.fetch_next_page() {
+                self.exhausted = true;
+                return Some(Err(e));
+            }
+        }
+    }
+}
+
+impl Drive {
+    /// Iterates over the ids of all documents of a document type in primary key order,
+    /// without fetching the documents themselves.
+    ///
+    /// Ids are fetched from storage lazily a page at a time, so memory stays bounded
+    /// whatever the number of documents.
+    pub fn iter_document_ids<'a>(
+        &'a self,
+        contract_id: [u8; 32],
+        document_type_name: &str,
+        transaction: TransactionArg<'a, 'a>,
+    ) -> Result<impl Iterator<Item = Result<[u8; 32], Error>> + 'a, Error> {
+        let contract_fetch_info = self
+            .get_contract_with_fetch_info(contract_id, false, transaction)?
+            .ok_or(Error::Document(DocumentError::ContractNotFound))?;
+        let document_type = contract_fetch_info
+            .contract
+            .document_type_for_name(document_type_name)?;
+        let primary_key_path =
+            contract_documents_primary_key_path(&contract_id, document_type.name.as_str())
+                .iter()
+                .map(|component| component.to_vec())
+                .collect();
+
+        Ok(DocumentIds {
+            drive: self,
+            primary_key_path,
+            transaction,
+            page: vec![].into_iter(),
+            last_key: None,
+            exhausted: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drive::document::tests::setup_dashpay;
+    use crate::drive::flags::StorageFlags;
+    use crate::drive::object_size_info::DocumentInfo::DocumentRefInfo;
+    use crate::drive::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+    use dpp::block::block_info::BlockInfo;
+    use dpp::data_contract::document_type::random_document::CreateRandomDocument;
+
+    #[test]
+    fn should_iterate_over_all_document_ids() {
+        let (drive, dashpay) = setup_dashpay("iter_ids", false);
+
+        let document_type = dashpay
+            .document_type_for_name("profile")
+            .expect("expected to get profile document type");
+
+        // more than a page of documents
+        let documents = document_type.random_documents(150, Some(3));
+
+        for document in &documents {
+            drive
+                .add_document_for_contract(
+                    DocumentAndContractInfo {
+                        owned_document_info: OwnedDocumentInfo {
+                            document_info: DocumentRefInfo((
+                                document,
+                                StorageFlags::optional_default_as_cow(),
+                            )),
+                            owner_id: None,
+                        },
+                        contract: &dashpay,
+                        document_type,
+                    },
+                    false,
+                    BlockInfo::default(),
+                    true,
+                    None,
+                )
+                .expect("expected to insert a document successfully");
+        }
+
+        let ids = drive
+            .iter_document_ids(dashpay.id.to_buffer(), "profile", None)
+            .expect("expected to iterate over document ids")
+            .collect::<Result<Vec<[u8; 32]>, Error>>()
+            .expect("expected to get document ids");
+
+        let mut expected_ids = documents
+            .iter()
+            .map(|document| document.id.to_buffer())
+            .collect::<Vec<[u8; 32]>>();
+        expected_ids.sort();
+
+        assert_eq!(ids, expected_ids);
+    }
+}
@@ -60,8 +60,15 @@ mod index_uniqueness;
 #[cfg(feature = "full")]
 mod insert;
 #[cfg(feature = "full")]
+mod iter_ids;
+#[cfg(feature = "full")]
+mod storage_footprint;
+#[cfg(feature = "full")]
 mod update;
 
+#[cfg(feature = "full")]
+pub use storage_footprint::StorageFootprint;
+
 #[cfg(any(feature = "full", feature = "verify"))]
 /// Returns the path to a contract document type.
 pub(crate) fn contract_document_type_path<'a>(
@@ -0,0 +1,180 @@
+use crate::drive::document::contract_document_type_path_vec;
+use crate::drive::Drive;
+use crate::error::document::DocumentError;
+use crate::error::Error;
+use grovedb::{Element, TransactionArg};
+
+/// The number of elements fetched from storage at a time while walking a subtree
+const STORAGE_FOOTPRINT_PAGE_SIZE: u16 = 100;
+
+/// The storage used by the documents of a document type
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StorageFootprint {
+    /// The number of documents of the document type
+    pub document_count: u64,
+    /// The approximate bytes used by the primary key tree, holding the documents and their
+    /// history when it is kept
+    pub primary_key_tree_bytes: u64,
+    /// The approximate bytes used by the trees of the document type indices
+    pub index_tree_bytes: u64,
+}
+
+impl StorageFootprint {
+    /// The approximate bytes used by the document type
+    pub fn total_bytes(&self) -> u64 {
+        self.primary_key_tree_bytes + self.index_tree_bytes
+    }
+}
+
+impl Drive {
+    /// Calls `f` on every key and element directly under a path, fetching them from storage
+    /// a page at a time
+    fn for_each_element_at_path(
+        &self,
+        path: &[Vec<u8>],
+        transaction: TransactionArg,
+        f: &mut impl FnMut(Vec<u8>, Element) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let mut last_key = None;
+        loop {
+            let key_elements = self.grove_get_raw_page_after_key(
+                path.to_vec(),
+                last_key.take(),
+                STORAGE_FOOTPRINT_PAGE_SIZE,
+                transaction,
+                &mut vec![],
+            )?;
+            let page_len = key_elements.len();
+            for (key, element) in key_elements {
+                last_key = Some(key.clone());
+                f(key, element)?;
+            }
+            if page_len < STORAGE_FOOTPRINT_PAGE_SIZE as usize {
+                return Ok(());
+            }
+        }
+    }
+
+    /// The approximate bytes used by an element, and by everything under it if it is a tree
+    fn element_footprint_bytes(
+        &self,
+        path: &[Vec<u8>],
+        key: Vec<u8>,
+        element: Element,
+        transaction: TransactionArg,
+    ) -> Result<u64, Error> {
+        let mut bytes = (key.len() + element.serialized_size()) as u64;
+        if matches!(element, Element::Tree(..) | Element::SumTree(..)) {
+            let mut subtree_path = path.to_vec();
+            subtree_path.push(key);
+            self.for_each_element_at_path(&subtree_path, transaction, &mut |key, element| {
+                bytes += self.element_footprint_bytes(&subtree_path, key, element, transaction)?;
+                Ok(())
+            })?;
+        }
+        Ok(bytes)
+    }
+
+    /// Computes the number of documents of a document type and the approximate bytes they
+    /// use in the primary key tree and in the index trees.
+    ///
+    /// Every subtree of the document type is walked a page at a time, so this is meant for
+    /// operators planning capacity and should not be called while processing blocks.
+    pub fn document_type_storage_footprint(
+        &self,
+        contract_id: [u8; 32],
+        document_type_name: &str,
+        transaction: TransactionArg,
+    ) -> Result<StorageFootprint, Error> {
+        let contract_fetch_info = self
+            .get_contract_with_fetch_info(contract_id, false, transaction)?
+            .ok_or(Error::Document(DocumentError::ContractNotFound))?;
+        let document_type = contract_fetch_info
+            .contract
+            .document_type_for_name(document_type_name)?;
+        let document_type_path =
+            contract_document_type_path_vec(&contract_id, document_type.name.as_str());
+
+        let mut footprint = StorageFootprint::default();
+        self.for_each_element_at_path(&document_type_path, transaction, &mut |key, element| {
+            if key != vec![0] {
+                footprint.index_tree_bytes +=
+                    self.element_footprint_bytes(&document_type_path, key, element, transaction)?;
+                return Ok(());
+            }
+            // every element of the primary key tree is a document, or the history of one
+            footprint.primary_key_tree_bytes += (key.len() + element.serialized_size()) as u64;
+            let mut primary_key_path = document_type_path.clone();
+            primary_key_path.push(key);
+            self.for_each_element_at_path(&primary_key_path, transaction, &mut |key, element| {
+                footprint.document_count += 1;
+                footprint.primary_key_tree_bytes +=
+                    self.element_footprint_bytes(&primary_key_path, key, element, transaction)?;
+                Ok(())
+            })
+        })?;
+        Ok(footprint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drive::document::tests::setup_dashpay;
+    use crate::drive::flags::StorageFlags;
+    use crate::drive::object_size_info::DocumentInfo::DocumentRefInfo;
+    use crate::drive::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+    use dpp::block::block_info::BlockInfo;
+    use dpp::data_contract::document_type::random_document::CreateRandomDocument;
+
+    #[test]
+    fn should_compute_the_storage_footprint_of_a_document_type() {
+        let (drive, dashpay) = setup_dashpay("storage_footprint", false);
+
+        let empty_footprint = drive
+            .document_type_storage_footprint(dashpay.id.to_buffer(), "profile", None)
+            .expect("expected to compute the storage footprint");
+        assert_eq!(empty_footprint.document_count, 0);
+
+        let document_type = dashpay
+            .document_type_for_name("profile")
+            .expect("expected to get profile document type");
+
+        // more than a page of documents
+        let documents = document_type.random_documents(150, Some(3));
+
+        for document in &documents {
+            drive
+                .add_document_for_contract(
+                    DocumentAndContractInfo {
+                        owned_document_info: OwnedDocumentInfo {
+                            document_info: DocumentRefInfo((
+                                document,
+                                StorageFlags::optional_default_as_cow(),
+                            )),
+                            owner_id: None,
+                        },
+                        contract: &dashpay,
+                        document_type,
+                    },
+                    false,
+                    BlockInfo::default(),
+                    true,
+                    None,
+                )
+                .expect("expected to insert a document successfully");
+        }
+
+        let footprint = drive
+            .document_type_storage_footprint(dashpay.id.to_buffer(), "profile", None)
+            .expect("expected to compute the storage footprint");
+
+        assert_eq!(footprint.document_count, 150);
+        assert!(footprint.primary_key_tree_bytes > empty_footprint.primary_key_tree_bytes);
+        assert!(footprint.index_tree_bytes > empty_footprint.index_tree_bytes);
+        assert_eq!(
+            footprint.total_bytes(),
+            footprint.primary_key_tree_bytes + footprint.index_tree_bytes
+        );
+    }
+}
@@ -75,7 +75,7 @@ use dpp::block::block_info::BlockInfo;
 
 use crate::drive::grove_operations::{
     BatchDeleteUpTreeApplyType, BatchInsertApplyType, BatchInsertTreeApplyType, DirectQueryType,
-    QueryType,
+    QueryType, RefreshTarget,
 };
 
 use crate::fee::result::FeeResult;
@@ -424,6 +424,7 @@ impl Drive {
         };
 
         let mut batch_insertion_cache: HashSet<Vec<Vec<u8>>> = HashSet::new();
+        let mut refresh_targets: Vec<RefreshTarget> = vec![];
         // fourth we need to store a reference to the document for each index
         for index in &document_type.indices {
             // at this point the contract path is to the contract documents
@@ -676,25 +677,24 @@ impl Drive {
                 if !index.unique || all_fields_null {
                     index_path.push(vec![0]);
 
-                    // here we should return an error if the element already exists
-                    self.batch_refresh_reference(
-                        index_path,
-                        document.id.to_vec(),
-                        document_reference.clone(),
+                    refresh_targets.push(RefreshTarget {
+                        path: index_path,
+                        key: document.id.to_vec(),
+                        reference: document_reference.clone(),
                         trust_refresh_reference,
-                        &mut batch_operations,
-                    )?;
+                    });
                 } else {
-                    self.batch_refresh_reference(
-                        index_path,
-                        vec![0],
-                        document_reference.clone(),
+                    refresh_targets.push(RefreshTarget {
+                        path: index_path,
+                        key: vec![0],
+                        reference: document_reference.clone(),
                         trust_refresh_reference,
-                        &mut batch_operations,
-                    )?;
+                    });
                 }
             }
         }
+        // the references of all unchanged indices are refreshed together
+        self.batch_refresh_references(&refresh_targets, &mut batch_operations)?;
         Ok(batch_operations)
     }
 
@@ -39,7 +39,7 @@ use crate::error::drive::DriveError;
 use crate::error::Error;
 use crate::fee::credits::{Creditable, Credits};
 use crate::fee::get_overflow_error;
-use dpp::block::epoch::Epoch;
+use dpp::block::epoch::{Epoch, EpochIndex};
 
 use crate::fee_pools::epochs::epoch_key_constants;
 use crate::fee_pools::epochs::paths::EpochProposers;
@@ -95,6 +95,21 @@ impl Drive {
         Ok(credits.to_unsigned())
     }
 
+    /// Proves the storage and processing fee pools of the Epoch.
+    pub fn prove_epoch_fee_pools(
+        &self,
+        epoch_index: EpochIndex,
+        transaction: TransactionArg,
+    ) -> Result<Vec<u8>, Error> {
+        let epoch = Epoch::new(epoch_index)?;
+        self.grove_get_proved_path_query(
+            &Self::epoch_fee_pools_query(&epoch),
+            false,
+            transaction,
+            &mut vec![],
+        )
+    }
+
     /// Gets the Fee Multiplier for the Epoch.
     pub(crate) fn get_epoch_fee_multiplier(
         &self,
@@ -234,6 +249,60 @@ mod tests {
         }
     }
 
+    mod prove_epoch_fee_pools {
+        use super::*;
+        use crate::drive::verify::epoch::FeePools;
+
+        #[test]
+        fn test_prove_and_verify_fee_pools() {
+            let drive = setup_drive_with_initial_state_structure();
+
+            let epoch = Epoch::new(0).unwrap();
+
+            let proof = drive
+                .prove_epoch_fee_pools(0, None)
+                .expect("should prove fee pools");
+
+            let (_, fee_pools) =
+                Drive::verify_fee_pools(&proof, 0).expect("should verify fee pools");
+
+            assert_eq!(fee_pools, FeePools::default());
+
+            let mut batch = GroveDbOpBatch::new();
+
+            batch.push(
+                epoch
+                    .update_processing_fee_pool_operation(42)
+                    .expect("should add operation"),
+            );
+
+            batch.push(
+                epoch
+                    .update_storage_fee_pool_operation(1000)
+                    .expect("should add operation"),
+            );
+
+            drive
+                .grove_apply_batch(batch, false, None)
+                .expect("should apply batch");
+
+            let proof = drive
+                .prove_epoch_fee_pools(0, None)
+                .expect("should prove fee pools");
+
+            let (_, fee_pools) =
+                Drive::verify_fee_pools(&proof, 0).expect("should verify fee pools");
+
+            assert_eq!(
+                fee_pools,
+                FeePools {
+                    storage_fees: 1000,
+                    processing_fees: 42,
+                }
+            );
+        }
+    }
+
     #[test]
     fn test_get_epoch_total_credits_for_distribution() {
         let drive = setup_drive_with_initial_state_structure();
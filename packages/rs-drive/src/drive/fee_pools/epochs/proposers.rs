@@ -39,7 +39,7 @@ use crate::drive::Drive;
 use crate::error::drive::DriveError;
 use crate::error::Error;
 use crate::fee_pools::epochs::paths::EpochProposers;
-use dpp::block::epoch::Epoch;
+use dpp::block::epoch::{Epoch, EpochIndex};
 
 impl Drive {
     /// Returns the given proposer's block count
@@ -73,6 +73,21 @@ impl Drive {
         Ok(proposer_block_count)
     }
 
+    /// Proves the block counts of all the proposers of an epoch
+    pub fn prove_epoch_proposer_block_counts(
+        &self,
+        epoch_index: EpochIndex,
+        transaction: TransactionArg,
+    ) -> Result<Vec<u8>, Error> {
+        let epoch = Epoch::new(epoch_index)?;
+        self.grove_get_proved_path_query(
+            &Self::epoch_proposers_query(&epoch),
+            false,
+            transaction,
+            &mut vec![],
+        )
+    }
+
     /// Returns true if the Epoch's Proposers Tree is empty
     pub fn is_epochs_proposers_tree_empty(
         &self,
@@ -257,4 +272,43 @@ mod tests {
             assert_eq!(result, vec!((pro_tx_hash.to_vec(), block_count)));
         }
     }
+
+    mod prove_epoch_proposer_block_counts {
+        use super::*;
+        use crate::fee_pools::epochs::operations_factory::EpochOperations;
+        use std::collections::BTreeMap;
+
+        #[test]
+        fn test_prove_and_verify_block_counts() {
+            let drive = setup_drive_with_initial_state_structure();
+
+            let first_pro_tx_hash: [u8; 32] = rand::random();
+            let second_pro_tx_hash: [u8; 32] = rand::random();
+
+            let epoch = Epoch::new(0).unwrap();
+
+            let mut batch = GroveDbOpBatch::new();
+
+            batch.push(epoch.init_proposers_tree_operation());
+
+            batch.push(epoch.update_proposer_block_count_operation(&first_pro_tx_hash, 42));
+            batch.push(epoch.update_proposer_block_count_operation(&second_pro_tx_hash, 7));
+
+            drive
+                .grove_apply_batch(batch, false, None)
+                .expect("should apply batch");
+
+            let proof = drive
+                .prove_epoch_proposer_block_counts(0, None)
+                .expect("should prove proposers");
+
+            let (_, block_counts) = Drive::verify_epoch_proposer_block_counts(&proof, 0)
+                .expect("should verify proposers");
+
+            assert_eq!(
+                block_counts,
+                BTreeMap::from([(first_pro_tx_hash, 42), (second_pro_tx_hash, 7)])
+            );
+        }
+    }
 }
@@ -32,7 +32,7 @@
 
 #[cfg(feature = "full")]
 use crate::drive::defaults::DEFAULT_HASH_SIZE;
-#[cfg(feature = "full")]
+#[cfg(any(feature = "full", feature = "verify"))]
 use crate::drive::flags::StorageFlags::{
     MultiEpoch, MultiEpochOwned, SingleEpoch, SingleEpochOwned,
 };
@@ -42,7 +42,7 @@ use costs::storage_cost::removal::StorageRemovedBytes::SectionedStorageRemoval;
 use costs::storage_cost::removal::{StorageRemovalPerEpochByIdentifier, StorageRemovedBytes};
 #[cfg(feature = "full")]
 use grovedb::ElementFlags;
-#[cfg(feature = "full")]
+#[cfg(any(feature = "full", feature = "verify"))]
 use integer_encoding::VarInt;
 #[cfg(feature = "full")]
 use intmap::IntMap;
@@ -53,9 +53,9 @@ use std::cmp::Ordering;
 #[cfg(any(feature = "full", feature = "verify"))]
 use std::collections::BTreeMap;
 
-#[cfg(feature = "full")]
+#[cfg(any(feature = "full", feature = "verify"))]
 use crate::error::storage_flags::StorageFlagsError;
-#[cfg(feature = "full")]
+#[cfg(any(feature = "full", feature = "verify"))]
 use crate::error::Error;
 
 #[cfg(any(feature = "full", feature = "verify"))]
@@ -95,6 +95,163 @@ pub enum StorageFlags {
     MultiEpochOwned(BaseEpoch, BTreeMap<EpochIndex, BytesAddedInEpoch>, OwnerId),
 }
 
+#[cfg(any(feature = "full", feature = "verify"))]
+impl StorageFlags {
+    /// Returns base epoch
+    pub fn base_epoch(&self) -> &BaseEpoch {
+        match self {
+            SingleEpoch(base_epoch)
+            | MultiEpoch(base_epoch, _)
+            | SingleEpochOwned(base_epoch, _)
+            | MultiEpochOwned(base_epoch, _, _) => base_epoch,
+        }
+    }
+
+    /// Deserialize single epoch storage flags from bytes
+    pub fn deserialize_single_epoch(data: &[u8]) -> Result<Self, Error> {
+        if data.len() != 3 {
+            Err(Error::StorageFlags(
+                StorageFlagsError::StorageFlagsWrongSize("single epoch must be 3 bytes total"),
+            ))
+        } else {
+            let epoch = u16::from_be_bytes(data[1..3].try_into().map_err(|_| {
+                Error::StorageFlags(StorageFlagsError::StorageFlagsWrongSize(
+                    "single epoch must be 3 bytes total",
+                ))
+            })?);
+            Ok(SingleEpoch(epoch))
+        }
+    }
+
+    /// Deserialize multi epoch storage flags from bytes
+    pub fn deserialize_multi_epoch(data: &[u8]) -> Result<Self, Error> {
+        let len = data.len();
+        if len < 6 {
+            Err(Error::StorageFlags(
+                StorageFlagsError::StorageFlagsWrongSize(
+                    "multi epoch must be at least 6 bytes total",
+                ),
+            ))
+        } else {
+            let base_epoch = u16::from_be_bytes(data[1..3].try_into().map_err(|_| {
+                Error::StorageFlags(StorageFlagsError::StorageFlagsWrongSize(
+                    "multi epoch must have enough bytes for the base epoch",
+                ))
+            })?);
+            let mut offset = 3;
+            let mut bytes_per_epoch: BTreeMap<u16, u32> = BTreeMap::default();
+            while offset + 2 < len {
+                // 2 for epoch size
+                let epoch_index =
+                    u16::from_be_bytes(data[offset..offset + 2].try_into().map_err(|_| {
+                        Error::StorageFlags(StorageFlagsError::StorageFlagsWrongSize(
+                            "multi epoch must have enough bytes epoch indexes",
+                        ))
+                    })?);
+                offset += 2;
+                let (bytes_at_epoch, bytes_used) = u32::decode_var(&data[offset..]).ok_or(
+                    Error::StorageFlags(StorageFlagsError::StorageFlagsWrongSize(
+                        "multi epoch must have enough bytes for the amount of bytes used",
+                    )),
+                )?;
+                offset += bytes_used;
+                bytes_per_epoch.insert(epoch_index, bytes_at_epoch);
+            }
+            Ok(MultiEpoch(base_epoch, bytes_per_epoch))
+        }
+    }
+
+    /// Deserialize single epoch owned storage flags from bytes
+    pub fn deserialize_single_epoch_owned(data: &[u8]) -> Result<Self, Error> {
+        if data.len() != 35 {
+            Err(Error::StorageFlags(
+                StorageFlagsError::StorageFlagsWrongSize(
+                    "single epoch owned must be 35 bytes total",
+                ),
+            ))
+        } else {
+            let owner_id: OwnerId = data[1..33].try_into().map_err(|_| {
+                Error::StorageFlags(StorageFlagsError::StorageFlagsWrongSize(
+                    "single epoch owned must be 35 bytes total for owner id",
+                ))
+            })?;
+            let epoch = u16::from_be_bytes(data[33..35].try_into().map_err(|_| {
+                Error::StorageFlags(StorageFlagsError::StorageFlagsWrongSize(
+                    "single epoch owned must be 35 bytes total for epoch",
+                ))
+            })?);
+            Ok(SingleEpochOwned(epoch, owner_id))
+        }
+    }
+
+    /// Deserialize multi epoch owned storage flags from bytes
+    pub fn deserialize_multi_epoch_owned(data: &[u8]) -> Result<Self, Error> {
+        let len = data.len();
+        if len < 38 {
+            Err(Error::StorageFlags(
+                StorageFlagsError::StorageFlagsWrongSize(
+                    "multi epoch owned must be at least 38 bytes total",
+                ),
+            ))
+        } else {
+            let owner_id: OwnerId = data[1..33].try_into().map_err(|_| {
+                Error::StorageFlags(StorageFlagsError::StorageFlagsWrongSize(
+                    "multi epoch owned must be 38 bytes total for owner id",
+                ))
+            })?;
+            let base_epoch = u16::from_be_bytes(data[33..35].try_into().map_err(|_| {
+                Error::StorageFlags(StorageFlagsError::StorageFlagsWrongSize(
+                    "multi epoch must have enough bytes for the base epoch",
+                ))
+            })?);
+            let mut offset = 35;
+            let mut bytes_per_epoch: BTreeMap<u16, u32> = BTreeMap::default();
+            while offset + 2 < len {
+                // 2 for epoch size
+                let epoch_index =
+                    u16::from_be_bytes(data[offset..offset + 2].try_into().map_err(|_| {
+                        Error::StorageFlags(StorageFlagsError::StorageFlagsWrongSize(
+                            "multi epoch must have enough bytes epoch indexes",
+                        ))
+                    })?);
+                offset += 2;
+                let (bytes_at_epoch, bytes_used) = u32::decode_var(&data[offset..]).ok_or(
+                    Error::StorageFlags(StorageFlagsError::StorageFlagsWrongSize(
+                        "multi epoch must have enough bytes for the amount of bytes used",
+                    )),
+                )?;
+                offset += bytes_used;
+                bytes_per_epoch.insert(epoch_index, bytes_at_epoch);
+            }
+            Ok(MultiEpochOwned(base_epoch, bytes_per_epoch, owner_id))
+        }
+    }
+
+    /// Deserialize storage flags from bytes
+    pub fn deserialize(data: &[u8]) -> Result<Option<Self>, Error> {
+        let first_byte = data.first();
+        match first_byte {
+            None => Ok(None),
+            Some(first_byte) => match *first_byte {
+                0 => Ok(Some(Self::deserialize_single_epoch(data)?)),
+                1 => Ok(Some(Self::deserialize_multi_epoch(data)?)),
+                2 => Ok(Some(Self::deserialize_single_epoch_owned(data)?)),
+                3 => Ok(Some(Self::deserialize_multi_epoch_owned(data)?)),
+                _ => Err(Error::StorageFlags(
+                    StorageFlagsError::DeserializeUnknownStorageFlagsType(
+                        "unknown storage flags serialization",
+                    ),
+                )),
+            },
+        }
+    }
+
+    /// Creates storage flags from a slice.
+    pub fn from_slice(data: &[u8]) -> Result<Option<Self>, Error> {
+        Self::deserialize(data)
+    }
+}
+
 #[cfg(feature = "full")]
 impl StorageFlags {
     /// Create new single epoch storage flags
@@ -293,16 +450,6 @@ impl StorageFlags {
         }
     }
 
-    /// Returns base epoch
-    pub fn base_epoch(&self) -> &BaseEpoch {
-        match self {
-            SingleEpoch(base_epoch)
-            | MultiEpoch(base_epoch, _)
-            | SingleEpochOwned(base_epoch, _)
-            | MultiEpochOwned(base_epoch, _, _) => base_epoch,
-        }
-    }
-
     /// Returns owner id
     pub fn owner_id(&self) -> Option<&OwnerId> {
         match self {
@@ -434,150 +581,6 @@ impl StorageFlags {
         buffer_len
     }
 
-    /// Deserialize single epoch storage flags from bytes
-    pub fn deserialize_single_epoch(data: &[u8]) -> Result<Self, Error> {
-        if data.len() != 3 {
-            Err(Error::StorageFlags(
-                StorageFlagsError::StorageFlagsWrongSize("single epoch must be 3 bytes total"),
-            ))
-        } else {
-            let epoch = u16::from_be_bytes(data[1..3].try_into().map_err(|_| {
-                Error::StorageFlags(StorageFlagsError::StorageFlagsWrongSize(
-                    "single epoch must be 3 bytes total",
-                ))
-            })?);
-            Ok(SingleEpoch(epoch))
-        }
-    }
-
-    /// Deserialize multi epoch storage flags from bytes
-    pub fn deserialize_multi_epoch(data: &[u8]) -> Result<Self, Error> {
-        let len = data.len();
-        if len < 6 {
-            Err(Error::StorageFlags(
-                StorageFlagsError::StorageFlagsWrongSize(
-                    "multi epoch must be at least 6 bytes total",
-                ),
-            ))
-        } else {
-            let base_epoch = u16::from_be_bytes(data[1..3].try_into().map_err(|_| {
-                Error::StorageFlags(StorageFlagsError::StorageFlagsWrongSize(
-                    "multi epoch must have enough bytes for the base epoch",
-                ))
-            })?);
-            let mut offset = 3;
-            let mut bytes_per_epoch: BTreeMap<u16, u32> = BTreeMap::default();
-            while offset + 2 < len {
-                // 2 for epoch size
-                let epoch_index =
-                    u16::from_be_bytes(data[offset..offset + 2].try_into().map_err(|_| {
-                        Error::StorageFlags(StorageFlagsError::StorageFlagsWrongSize(
-                            "multi epoch must have enough bytes epoch indexes",
-                        ))
-                    })?);
-                offset += 2;
-                let (bytes_at_epoch, bytes_used) = u32::decode_var(&data[offset..]).ok_or(
-                    Error::StorageFlags(StorageFlagsError::StorageFlagsWrongSize(
-                        "multi epoch must have enough bytes for the amount of bytes used",
-                    )),
-                )?;
-                offset += bytes_used;
-                bytes_per_epoch.insert(epoch_index, bytes_at_epoch);
-            }
-            Ok(MultiEpoch(base_epoch, bytes_per_epoch))
-        }
-    }
-
-    /// Deserialize single epoch owned storage flags from bytes
-    pub fn deserialize_single_epoch_owned(data: &[u8]) -> Result<Self, Error> {
-        if data.len() != 35 {
-            Err(Error::StorageFlags(
-                StorageFlagsError::StorageFlagsWrongSize(
-                    "single epoch owned must be 35 bytes total",
-                ),
-            ))
-        } else {
-            let owner_id: OwnerId = data[1..33].try_into().map_err(|_| {
-                Error::StorageFlags(StorageFlagsError::StorageFlagsWrongSize(
-                    "single epoch owned must be 35 bytes total for owner id",
-                ))
-            })?;
-            let epoch = u16::from_be_bytes(data[33..35].try_into().map_err(|_| {
-                Error::StorageFlags(StorageFlagsError::StorageFlagsWrongSize(
-                    "single epoch owned must be 35 bytes total for epoch",
-                ))
-            })?);
-            Ok(SingleEpochOwned(epoch, owner_id))
-        }
-    }
-
-    /// Deserialize multi epoch owned storage flags from bytes
-    pub fn deserialize_multi_epoch_owned(data: &[u8]) -> Result<Self, Error> {
-        let len = data.len();
-        if len < 38 {
-            Err(Error::StorageFlags(
-                StorageFlagsError::StorageFlagsWrongSize(
-                    "multi epoch owned must be at least 38 bytes total",
-                ),
-            ))
-        } else {
-            let owner_id: OwnerId = data[1..33].try_into().map_err(|_| {
-                Error::StorageFlags(StorageFlagsError::StorageFlagsWrongSize(
-                    "multi epoch owned must be 38 bytes total for owner id",
-                ))
-            })?;
-            let base_epoch = u16::from_be_bytes(data[33..35].try_into().map_err(|_| {
-                Error::StorageFlags(StorageFlagsError::StorageFlagsWrongSize(
-                    "multi epoch must have enough bytes for the base epoch",
-                ))
-            })?);
-            let mut offset = 35;
-            let mut bytes_per_epoch: BTreeMap<u16, u32> = BTreeMap::default();
-            while offset + 2 < len {
-                // 2 for epoch size
-                let epoch_index =
-                    u16::from_be_bytes(data[offset..offset + 2].try_into().map_err(|_| {
-                        Error::StorageFlags(StorageFlagsError::StorageFlagsWrongSize(
-                            "multi epoch must have enough bytes epoch indexes",
-                        ))
-                    })?);
-                offset += 2;
-                let (bytes_at_epoch, bytes_used) = u32::decode_var(&data[offset..]).ok_or(
-                    Error::StorageFlags(StorageFlagsError::StorageFlagsWrongSize(
-                        "multi epoch must have enough bytes for the amount of bytes used",
-                    )),
-                )?;
-                offset += bytes_used;
-                bytes_per_epoch.insert(epoch_index, bytes_at_epoch);
-            }
-            Ok(MultiEpochOwned(base_epoch, bytes_per_epoch, owner_id))
-        }
-    }
-
-    /// Deserialize storage flags from bytes
-    pub fn deserialize(data: &[u8]) -> Result<Option<Self>, Error> {
-        let first_byte = data.first();
-        match first_byte {
-            None => Ok(None),
-            Some(first_byte) => match *first_byte {
-                0 => Ok(Some(Self::deserialize_single_epoch(data)?)),
-                1 => Ok(Some(Self::deserialize_multi_epoch(data)?)),
-                2 => Ok(Some(Self::deserialize_single_epoch_owned(data)?)),
-                3 => Ok(Some(Self::deserialize_multi_epoch_owned(data)?)),
-                _ => Err(Error::StorageFlags(
-                    StorageFlagsError::DeserializeUnknownStorageFlagsType(
-                        "unknown storage flags serialization",
-                    ),
-                )),
-            },
-        }
-    }
-
-    /// Creates storage flags from a slice.
-    pub fn from_slice(data: &[u8]) -> Result<Option<Self>, Error> {
-        Self::deserialize(data)
-    }
-
     /// Creates storage flags from element flags.
     pub fn from_element_flags_ref(data: &ElementFlags) -> Result<Option<Self>, Error> {
         Self::from_slice(data.as_slice())
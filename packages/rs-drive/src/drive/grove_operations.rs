@@ -40,7 +40,10 @@ use grovedb::batch::estimated_costs::EstimatedCostsType::AverageCaseCostsType;
 use grovedb::batch::{
     key_info::KeyInfo, BatchApplyOptions, GroveDbOp, KeyInfoPath, Op, OpsByLevelPath,
 };
-use grovedb::{Element, EstimatedLayerInformation, GroveDb, PathQuery, TransactionArg};
+use grovedb::{
+    Element, EstimatedLayerInformation, GroveDb, PathQuery, Query, QueryItem, SizedQuery,
+    TransactionArg,
+};
 use path::SubtreePath;
 
 use crate::drive::flags::StorageFlags;
@@ -67,6 +70,7 @@ use grovedb::Error as GroveError;
 use integer_encoding::VarInt;
 use intmap::IntMap;
 use std::collections::HashMap;
+use std::ops::RangeFull;
 use storage::rocksdb_storage::RocksDbStorage;
 
 /// Pushes an operation's `OperationCost` to `drive_operations` given its `CostContext`
@@ -169,6 +173,19 @@ impl BatchInsertApplyType {
     }
 }
 
+/// A reference to refresh, see `batch_refresh_references`
+#[derive(Clone, Debug)]
+pub struct RefreshTarget {
+    /// The path of the tree holding the reference
+    pub path: Vec<Vec<u8>>,
+    /// The key of the reference
+    pub key: Vec<u8>,
+    /// The reference element
+    pub reference: Element,
+    /// Whether the reference content can be trusted not to have changed
+    pub trust_refresh_reference: bool,
+}
+
 pub type FlagsLen = u32;
 
 #[derive(Clone, Copy)]
@@ -638,6 +655,39 @@ impl Drive {
         value.map_err(Error::GroveDB)
     }
 
+    /// Gets a page of at most `page_size` keys and elements directly under a path, starting
+    /// after `after_key`, or at the first key if there is none, so a subtree can be walked a
+    /// page at a time by passing the last key of each page to get the next one.
+    /// Pushes the cost to `drive_operations` and returns the page in key order.
+    pub(crate) fn grove_get_raw_page_after_key(
+        &self,
+        path: Vec<Vec<u8>>,
+        after_key: Option<Vec<u8>>,
+        page_size: u16,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+    ) -> Result<Vec<(Vec<u8>, Element)>, Error> {
+        let query_item = match after_key {
+            None => QueryItem::RangeFull(RangeFull),
+            Some(after_key) => QueryItem::RangeAfter(after_key..),
+        };
+        let path_query = PathQuery::new(
+            path,
+            SizedQuery::new(
+                Query::new_single_query_item(query_item),
+                Some(page_size),
+                None,
+            ),
+        );
+        let (results, _) = self.grove_get_raw_path_query(
+            &path_query,
+            transaction,
+            QueryResultType::QueryKeyElementPairResultType,
+            drive_operations,
+        )?;
+        Ok(results.to_key_elements())
+    }
+
     /// Gets the return value and the cost of a groveDB proved path query.
     /// Pushes the cost to `drive_operations` and returns the return value.
     /// Verbose should be generally set to false unless one needs to prove
@@ -1679,6 +1729,29 @@ impl Drive {
         Ok(())
     }
 
+    /// Pushes a "refresh reference" operation to `drive_operations` for each reference.
+    ///
+    /// If any of the elements is not a reference no operation is pushed, so the references
+    /// are either all refreshed in the batch or none are.
+    pub(crate) fn batch_refresh_references(
+        &self,
+        references: &[RefreshTarget],
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+    ) -> Result<(), Error> {
+        let mut refresh_operations = Vec::with_capacity(references.len());
+        for target in references {
+            self.batch_refresh_reference(
+                target.path.clone(),
+                target.key.clone(),
+                target.reference.clone(),
+                target.trust_refresh_reference,
+                &mut refresh_operations,
+            )?;
+        }
+        drive_operations.extend(refresh_operations);
+        Ok(())
+    }
+
     /// Applies the given groveDB operation
     pub fn grove_apply_operation(
         &self,
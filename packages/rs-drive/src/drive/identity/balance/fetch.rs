@@ -1,7 +1,7 @@
 #[cfg(feature = "full")]
 use crate::drive::balances::balance_path;
 #[cfg(any(feature = "full", feature = "verify"))]
-use crate::drive::balances::balance_path_vec;
+use crate::drive::balances::{balance_path_vec, token_balances_path_vec};
 #[cfg(feature = "full")]
 use crate::drive::grove_operations::DirectQueryType;
 #[cfg(feature = "full")]
@@ -248,6 +248,22 @@ impl Drive {
         }
     }
 
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// The query for the balance an identity holds of a token
+    pub fn identity_token_balance_query(token_id: [u8; 32], identity_id: [u8; 32]) -> PathQuery {
+        let token_balances_path = token_balances_path_vec(token_id);
+        let mut query = Query::new();
+        query.insert_key(identity_id.to_vec());
+        PathQuery {
+            path: token_balances_path,
+            query: SizedQuery {
+                query,
+                limit: Some(1),
+                offset: None,
+            },
+        }
+    }
+
     #[cfg(feature = "full")]
     /// Creates the operations to get Identity's balance from the backing store
     /// This gets operations based on apply flag (stateful vs stateless)
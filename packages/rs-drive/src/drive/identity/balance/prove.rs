@@ -24,6 +24,27 @@ impl Drive {
         self.grove_get_proved_path_query(&balance_query, false, transaction, &mut vec![])
     }
 
+    /// Proves an Identity's negative credit (owed processing fees) from the backing store
+    pub fn prove_identity_negative_credit(
+        &self,
+        identity_id: [u8; 32],
+        transaction: TransactionArg,
+    ) -> Result<Vec<u8>, Error> {
+        let negative_credit_query = Self::identity_negative_credit_query(&identity_id);
+        self.grove_get_proved_path_query(&negative_credit_query, false, transaction, &mut vec![])
+    }
+
+    /// Proves the balance an Identity holds of a token from the backing store
+    pub fn prove_identity_token_balance(
+        &self,
+        identity_id: [u8; 32],
+        token_id: [u8; 32],
+        transaction: TransactionArg,
+    ) -> Result<Vec<u8>, Error> {
+        let token_balance_query = Self::identity_token_balance_query(token_id, identity_id);
+        self.grove_get_proved_path_query(&token_balance_query, false, transaction, &mut vec![])
+    }
+
     /// Proves multiple Identity balances from the backing store
     pub fn prove_many_identity_balances(
         &self,
@@ -70,6 +91,178 @@ mod tests {
         }
     }
 
+    mod verify_balance_delta {
+        use super::*;
+
+        #[test]
+        fn should_verify_the_balance_change_between_two_proofs() {
+            let drive = setup_drive_with_initial_state_structure();
+            let identity = Identity::random_identity(3, Some(14));
+
+            let identity_id = identity.id.to_buffer();
+            drive
+                .add_new_identity(identity, &BlockInfo::default(), true, None)
+                .expect("expected to add an identity");
+            let proof_before = drive
+                .prove_identity_balance(identity_id, None)
+                .expect("should not error when proving an identity balance");
+
+            drive
+                .add_to_identity_balance(identity_id, 300, &BlockInfo::default(), true, None)
+                .expect("expected to add to the identity balance");
+            drive
+                .remove_from_identity_balance(identity_id, 500, &BlockInfo::default(), true, None)
+                .expect("expected to remove from the identity balance");
+            let proof_after = drive
+                .prove_identity_balance(identity_id, None)
+                .expect("should not error when proving an identity balance");
+
+            let (root_hash_before, root_hash_after, delta) = Drive::verify_balance_delta(
+                proof_before.as_slice(),
+                proof_after.as_slice(),
+                identity_id,
+            )
+            .expect("expect that this be verified");
+
+            assert_ne!(root_hash_before, root_hash_after);
+            assert_eq!(delta, -200);
+
+            let (_, _, delta) = Drive::verify_balance_delta(
+                proof_after.as_slice(),
+                proof_before.as_slice(),
+                identity_id,
+            )
+            .expect("expect that this be verified");
+
+            assert_eq!(delta, 200);
+        }
+    }
+
+    mod prove_identity_negative_credit {
+        use super::*;
+
+        #[test]
+        fn should_prove_that_a_new_identity_has_no_debt() {
+            let drive = setup_drive_with_initial_state_structure();
+            let identity = Identity::random_identity(3, Some(14));
+
+            let identity_id = identity.id.to_buffer();
+            drive
+                .add_new_identity(identity, &BlockInfo::default(), true, None)
+                .expect("expected to add an identity");
+            let proof = drive
+                .prove_identity_negative_credit(identity_id, None)
+                .expect("should not error when proving an identity negative credit");
+
+            let (_, proved_negative_credit) =
+                Drive::verify_identity_negative_credit(proof.as_slice(), identity_id, false)
+                    .expect("expect that this be verified");
+
+            assert_eq!(proved_negative_credit, None);
+        }
+
+        #[test]
+        fn should_prove_the_debt_of_an_identity() {
+            let drive = setup_drive_with_initial_state_structure();
+            let identity = Identity::random_identity(3, Some(14));
+
+            let identity_id = identity.id.to_buffer();
+            drive
+                .add_new_identity(identity, &BlockInfo::default(), true, None)
+                .expect("expected to add an identity");
+
+            let batch = vec![drive.update_identity_negative_credit_operation(identity_id, 100)];
+            drive
+                .apply_batch_low_level_drive_operations(None, None, batch, &mut vec![])
+                .expect("expected to persist the negative credit");
+
+            let proof = drive
+                .prove_identity_negative_credit(identity_id, None)
+                .expect("should not error when proving an identity negative credit");
+
+            let (root_hash, proved_negative_credit) =
+                Drive::verify_identity_negative_credit(proof.as_slice(), identity_id, false)
+                    .expect("expect that this be verified");
+
+            assert_eq!(proved_negative_credit, Some(100));
+            assert_eq!(
+                root_hash,
+                drive
+                    .grove
+                    .root_hash(None)
+                    .unwrap()
+                    .expect("there is always a root hash")
+            );
+        }
+    }
+
+    mod prove_identity_token_balance {
+        use super::*;
+        use crate::drive::RootTree;
+        use grovedb::Element;
+
+        #[test]
+        fn should_prove_a_present_and_an_absent_token_balance() {
+            let drive = setup_drive_with_initial_state_structure();
+            let token_id = [3; 32];
+            let identity_id = [1; 32];
+
+            let token_balances_root: &[u8] = &[RootTree::TokenBalances as u8];
+            drive
+                .grove
+                .insert(
+                    &[token_balances_root],
+                    &token_id,
+                    Element::empty_sum_tree(),
+                    None,
+                    None,
+                )
+                .unwrap()
+                .expect("expected to insert the token balances tree");
+            drive
+                .grove
+                .insert(
+                    &[token_balances_root, token_id.as_slice()],
+                    &identity_id,
+                    Element::new_sum_item(500),
+                    None,
+                    None,
+                )
+                .unwrap()
+                .expect("expected to insert the token balance");
+
+            let proof = drive
+                .prove_identity_token_balance(identity_id, token_id, None)
+                .expect("should not error when proving a token balance");
+            let (root_hash, proved_token_balance) = Drive::verify_identity_token_balance(
+                proof.as_slice(),
+                identity_id,
+                token_id,
+                false,
+            )
+            .expect("expect that this be verified");
+
+            assert_eq!(proved_token_balance, Some(500));
+            assert_eq!(
+                root_hash,
+                drive
+                    .grove
+                    .root_hash(None)
+                    .unwrap()
+                    .expect("there is always a root hash")
+            );
+
+            let proof = drive
+                .prove_identity_token_balance([2; 32], token_id, None)
+                .expect("should not error when proving a token balance");
+            let (_, proved_token_balance) =
+                Drive::verify_identity_token_balance(proof.as_slice(), [2; 32], token_id, false)
+                    .expect("expect that this be verified");
+
+            assert_eq!(proved_token_balance, None);
+        }
+    }
+
     mod prove_many_identity_balances {
         use super::*;
         use crate::fee::credits::Credits;
@@ -1,11 +1,13 @@
 #[cfg(any(feature = "full", feature = "verify"))]
 use crate::drive::identity::identity_path_vec;
 #[cfg(any(feature = "full", feature = "verify"))]
-use crate::drive::identity::IdentityRootStructure::IdentityTreeRevision;
+use crate::drive::identity::IdentityRootStructure::{
+    IdentityTreeNegativeCredit, IdentityTreeRevision,
+};
 
 #[cfg(any(feature = "full", feature = "verify"))]
 use crate::drive::Drive;
-#[cfg(feature = "full")]
+#[cfg(any(feature = "full", feature = "verify"))]
 use crate::drive::RootTree;
 #[cfg(feature = "full")]
 use crate::error::drive::DriveError;
@@ -62,6 +64,38 @@ impl Drive {
         }
     }
 
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// The query for the identity negative credit (owed processing fees)
+    pub fn identity_negative_credit_query(identity_id: &[u8; 32]) -> PathQuery {
+        let identity_path = identity_path_vec(identity_id.as_slice());
+        let mut query = Query::new();
+        query.insert_key(vec![IdentityTreeNegativeCredit as u8]);
+        PathQuery {
+            path: identity_path,
+            query: SizedQuery {
+                query,
+                limit: Some(1),
+                offset: None,
+            },
+        }
+    }
+
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// The query for the identity tree element itself, whose storage flags hold the epoch
+    /// the identity was created in
+    pub fn identity_tree_element_query(identity_id: &[u8; 32]) -> PathQuery {
+        let mut query = Query::new();
+        query.insert_key(identity_id.to_vec());
+        PathQuery {
+            path: vec![vec![RootTree::Identities as u8]],
+            query: SizedQuery {
+                query,
+                limit: Some(1),
+                offset: None,
+            },
+        }
+    }
+
     #[cfg(feature = "full")]
     /// Given a vector of identities, fetches the identities from storage.
     pub fn verify_all_identities_exist(
@@ -0,0 +1,57 @@
+use crate::drive::Drive;
+use crate::error::Error;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Proves the epoch an identity was created in from the storage flags of its identity tree.
+    pub fn prove_identity_created_in_epoch(
+        &self,
+        identity_id: [u8; 32],
+        transaction: TransactionArg,
+    ) -> Result<Vec<u8>, Error> {
+        let query = Self::identity_tree_element_query(&identity_id);
+        self.grove_get_proved_path_query(&query, false, transaction, &mut vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::block::epoch::Epoch;
+    use dpp::identity::Identity;
+
+    #[test]
+    fn should_prove_the_epoch_an_identity_was_created_in() {
+        let drive = setup_drive_with_initial_state_structure();
+        let identity = Identity::random_identity(3, Some(14));
+
+        let identity_id = identity.id.to_buffer();
+        let block_info = BlockInfo::default_with_epoch(Epoch::new(3).expect("expected epoch 3"));
+        drive
+            .add_new_identity(identity, &block_info, true, None)
+            .expect("expected to add an identity");
+
+        let proof = drive
+            .prove_identity_created_in_epoch(identity_id, None)
+            .expect("should not error when proving an identity creation epoch");
+
+        let (_, created_in_epoch) =
+            Drive::verify_identity_created_in_epoch(proof.as_slice(), identity_id, false)
+                .expect("expect that this be verified");
+
+        assert_eq!(created_in_epoch, Some(3));
+
+        let unknown_identity_id = [7; 32];
+        let proof = drive
+            .prove_identity_created_in_epoch(unknown_identity_id, None)
+            .expect("should not error when proving an unknown identity");
+
+        let (_, created_in_epoch) =
+            Drive::verify_identity_created_in_epoch(proof.as_slice(), unknown_identity_id, false)
+                .expect("expect that this be verified");
+
+        assert_eq!(created_in_epoch, None);
+    }
+}
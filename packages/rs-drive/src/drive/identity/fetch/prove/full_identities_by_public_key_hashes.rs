@@ -168,6 +168,23 @@ mod tests {
                     .expect("expect that this be verified");
 
             assert_eq!(proved_identity_ids, key_hashes_to_identities);
+
+            #[cfg(feature = "parallel-verify")]
+            {
+                let (_, proved_identities_in_order): ([u8; 32], Vec<([u8; 20], Option<Identity>)>) =
+                    Drive::verify_full_identities_by_public_key_hashes_parallel(
+                        proof.as_slice(),
+                        &key_hashes,
+                    )
+                    .expect("expect that this be verified in parallel");
+
+                let expected_in_order = key_hashes
+                    .iter()
+                    .map(|key_hash| (*key_hash, key_hashes_to_identities[key_hash].clone()))
+                    .collect::<Vec<_>>();
+
+                assert_eq!(proved_identities_in_order, expected_in_order);
+            }
         }
     }
 }
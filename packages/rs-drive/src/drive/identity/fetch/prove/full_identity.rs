@@ -30,10 +30,12 @@ impl Drive {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::proof::ProofError;
     use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
     use dpp::block::block_info::BlockInfo;
 
-    use dpp::identity::Identity;
+    use crate::drive::verify::identity::SubmitReadiness;
+    use dpp::identity::{Identity, Purpose, SecurityLevel};
     use grovedb::query_result_type::QueryResultType;
     use grovedb::GroveDb;
     use grovedb::QueryItem;
@@ -386,5 +388,115 @@ mod tests {
             // We want to get a proof on the balance, the revision and 5 keys
             assert_eq!(proof.len(), 7);
         }
+
+        #[test]
+        fn should_verify_full_identity_against_trusted_root() {
+            let drive = setup_drive_with_initial_state_structure();
+
+            let identity = Identity::random_identity(5, Some(12345));
+
+            drive
+                .add_new_identity(identity.clone(), &BlockInfo::default(), true, None)
+                .expect("expected to insert identity");
+
+            let root_hash = drive
+                .grove
+                .root_hash(None)
+                .unwrap()
+                .expect("there is always a root hash");
+
+            let proof = drive
+                .prove_full_identity(identity.id.to_buffer(), None)
+                .expect("should prove an identity");
+
+            let proved_identity = Drive::verify_full_identity_by_identity_id_with_expected_root(
+                proof.as_slice(),
+                false,
+                identity.id.to_buffer(),
+                root_hash,
+            )
+            .expect("expected to verify the identity against the trusted root hash");
+
+            assert_eq!(proved_identity, Some(identity.clone()));
+
+            let mut wrong_root_hash = root_hash;
+            wrong_root_hash[0] = wrong_root_hash[0].wrapping_add(1);
+
+            assert!(matches!(
+                Drive::verify_full_identity_by_identity_id_with_expected_root(
+                    proof.as_slice(),
+                    false,
+                    identity.id.to_buffer(),
+                    wrong_root_hash,
+                ),
+                Err(Error::Proof(ProofError::IncorrectRootHash { .. }))
+            ));
+        }
+
+        #[test]
+        fn should_verify_identity_can_submit() {
+            let drive = setup_drive_with_initial_state_structure();
+
+            let identity = Identity::random_identity(5, Some(12345));
+
+            drive
+                .add_new_identity(identity.clone(), &BlockInfo::default(), true, None)
+                .expect("expected to insert identity");
+
+            let proof = drive
+                .prove_full_identity(identity.id.to_buffer(), None)
+                .expect("should prove an identity");
+
+            let key = identity
+                .public_keys
+                .values()
+                .next()
+                .expect("expected the identity to have a key");
+
+            let (_, readiness) = Drive::verify_identity_can_submit(
+                proof.as_slice(),
+                identity.id.to_buffer(),
+                key.purpose,
+                key.security_level,
+                identity.balance,
+            )
+            .expect("expected to verify the identity readiness");
+
+            assert!(readiness.is_ready());
+
+            // random identities only have authentication keys
+            let (_, readiness) = Drive::verify_identity_can_submit(
+                proof.as_slice(),
+                identity.id.to_buffer(),
+                Purpose::WITHDRAW,
+                SecurityLevel::MEDIUM,
+                identity.balance + 1,
+            )
+            .expect("expected to verify the identity readiness");
+
+            assert_eq!(
+                readiness,
+                SubmitReadiness {
+                    exists: true,
+                    has_key: false,
+                    has_balance: false,
+                }
+            );
+
+            let proof = drive
+                .prove_full_identity([7; 32], None)
+                .expect("should prove the identity is absent");
+
+            let (_, readiness) = Drive::verify_identity_can_submit(
+                proof.as_slice(),
+                [7; 32],
+                key.purpose,
+                key.security_level,
+                0,
+            )
+            .expect("expected to verify the identity readiness");
+
+            assert_eq!(readiness, SubmitReadiness::default());
+        }
     }
 }
@@ -1,4 +1,5 @@
 mod balance;
+mod created_in_epoch;
 mod full_identities_by_public_key_hashes;
 mod full_identity;
 mod identity_ids_by_public_key_hashes;
@@ -32,7 +32,7 @@ use dpp::block::epoch::Epoch;
 #[cfg(any(feature = "full", feature = "verify"))]
 use dpp::identity::KeyID;
 use dpp::identity::IDENTITY_MAX_KEYS;
-#[cfg(feature = "full")]
+#[cfg(any(feature = "full", feature = "verify"))]
 use dpp::identity::{Purpose, SecurityLevel};
 #[cfg(feature = "full")]
 use dpp::prelude::IdentityPublicKey;
@@ -605,6 +605,27 @@ impl IdentityKeysRequest {
         }
     }
 
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Make a request for all keys of the given purposes for the identity
+    /// Only authentication and withdrawal keys are referenced by purpose,
+    /// keys of other purposes will never be found by this request
+    pub fn new_all_keys_of_purposes_query(identity_id: &[u8; 32], purposes: &[Purpose]) -> Self {
+        let mut sec_btree_map = BTreeMap::new();
+        for security_level in 0..=SecurityLevel::last() as u8 {
+            sec_btree_map.insert(security_level, AllKeysOfKindRequest);
+        }
+        let purpose_btree_map = purposes
+            .iter()
+            .map(|purpose| (*purpose as u8, sec_btree_map.clone()))
+            .collect();
+        IdentityKeysRequest {
+            identity_id: *identity_id,
+            request_type: SearchKey(purpose_btree_map),
+            limit: None,
+            offset: None,
+        }
+    }
+
     #[cfg(any(feature = "full", feature = "verify"))]
     /// Make a request for specific keys for the identity
     pub fn new_specific_keys_query(identity_id: &[u8; 32], key_ids: Vec<KeyID>) -> Self {
@@ -1,6 +1,8 @@
 use crate::drive::identity::key::fetch::IdentityKeysRequest;
 use crate::drive::Drive;
+use crate::error::drive::DriveError;
 use crate::error::Error;
+use dpp::identity::{KeyID, Purpose};
 use grovedb::TransactionArg;
 
 impl Drive {
@@ -64,4 +66,301 @@ impl Drive {
         let identity_query = key_request.into_path_query();
         self.grove_get_proved_path_query(&identity_query, false, transaction, &mut vec![])
     }
+
+    /// Proves the keys of an identity that have one of the given purposes.
+    ///
+    /// Only the purpose subtrees of the identity's key references are queried, so keys of
+    /// other purposes are not part of the proof.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity_id` - The id of the identity whose keys should be proved.
+    /// * `purposes` - The purposes of the keys to prove, only authentication and withdrawal
+    ///   keys are referenced by purpose.
+    /// * `transaction` - A `TransactionArg` representing the current transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u8>)` - A proof of the identity keys with the requested purposes.
+    /// * `Err(Error)` - An error if a purpose is not searchable or the proof cannot be generated.
+    ///
+    pub fn prove_identity_keys_by_purpose(
+        &self,
+        identity_id: [u8; 32],
+        purposes: &[Purpose],
+        transaction: TransactionArg,
+    ) -> Result<Vec<u8>, Error> {
+        if purposes
+            .iter()
+            .any(|purpose| !Purpose::authentication_withdraw().contains(purpose))
+        {
+            return Err(Error::Drive(DriveError::NotSupported(
+                "only authentication and withdrawal keys can be proved by purpose",
+            )));
+        }
+        self.prove_identity_keys(
+            IdentityKeysRequest::new_all_keys_of_purposes_query(&identity_id, purposes),
+            transaction,
+        )
+    }
+
+    /// Proves a specific key of an identity together with the identity balance.
+    ///
+    /// The proof is verified with `Drive::verify_identity_owns_key`.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity_id` - The id of the identity.
+    /// * `key_id` - The id of the key to prove.
+    /// * `transaction` - A `TransactionArg` representing the current transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u8>)` - A proof of the key and of the identity balance.
+    /// * `Err(Error)` - An error if the proof cannot be generated.
+    ///
+    pub fn prove_identity_key_with_balance(
+        &self,
+        identity_id: [u8; 32],
+        key_id: KeyID,
+        transaction: TransactionArg,
+    ) -> Result<Vec<u8>, Error> {
+        let path_query = Self::identity_key_with_balance_query(&identity_id, key_id)?;
+        self.grove_get_proved_path_query(&path_query, true, transaction, &mut vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::identity::Identity;
+
+    mod prove_identity_keys {
+        use super::*;
+
+        #[test]
+        fn should_prove_when_a_key_was_disabled() {
+            let drive = setup_drive_with_initial_state_structure();
+            let identity = Identity::random_identity(3, Some(14));
+
+            let identity_id = identity.id.to_buffer();
+            drive
+                .add_new_identity(identity, &BlockInfo::default(), true, None)
+                .expect("expected to add an identity");
+
+            let proof = drive
+                .prove_identity_keys(
+                    IdentityKeysRequest::new_specific_key_query(&identity_id, 1),
+                    None,
+                )
+                .expect("should not error when proving a key");
+
+            let (_, disabled_at) =
+                Drive::verify_key_disabled_at(proof.as_slice(), identity_id, 1, false)
+                    .expect("expect that this be verified");
+
+            assert_eq!(disabled_at, Some(None));
+
+            drive
+                .disable_identity_keys(
+                    identity_id,
+                    vec![1],
+                    1_000,
+                    &BlockInfo::default(),
+                    true,
+                    None,
+                )
+                .expect("expected to disable the key");
+
+            let proof = drive
+                .prove_identity_keys(
+                    IdentityKeysRequest::new_specific_key_query(&identity_id, 1),
+                    None,
+                )
+                .expect("should not error when proving a key");
+
+            let (_, disabled_at) =
+                Drive::verify_key_disabled_at(proof.as_slice(), identity_id, 1, false)
+                    .expect("expect that this be verified");
+
+            assert_eq!(disabled_at, Some(Some(1_000)));
+        }
+
+        #[test]
+        fn should_prove_absence_of_an_unknown_key() {
+            let drive = setup_drive_with_initial_state_structure();
+            let identity = Identity::random_identity(3, Some(14));
+
+            let identity_id = identity.id.to_buffer();
+            drive
+                .add_new_identity(identity, &BlockInfo::default(), true, None)
+                .expect("expected to add an identity");
+
+            let proof = drive
+                .prove_identity_keys(
+                    IdentityKeysRequest::new_specific_key_query(&identity_id, 10),
+                    None,
+                )
+                .expect("should not error when proving a key");
+
+            let (_, disabled_at) =
+                Drive::verify_key_disabled_at(proof.as_slice(), identity_id, 10, false)
+                    .expect("expect that this be verified");
+
+            assert_eq!(disabled_at, None);
+        }
+    }
+
+    mod prove_identity_owns_key {
+        use super::*;
+
+        #[test]
+        fn should_prove_that_an_identity_owns_a_key() {
+            let drive = setup_drive_with_initial_state_structure();
+            let identity = Identity::random_identity(3, Some(14));
+
+            let identity_id = identity.id.to_buffer();
+            let (key_id, public_key) = identity
+                .public_keys
+                .iter()
+                .next()
+                .map(|(key_id, key)| (*key_id, key.data.to_vec()))
+                .expect("expected the identity to have a key");
+            drive
+                .add_new_identity(identity, &BlockInfo::default(), true, None)
+                .expect("expected to add an identity");
+
+            let proof = drive
+                .prove_identity_key_with_balance(identity_id, key_id, None)
+                .expect("should not error when proving the key");
+
+            let (_, owns_key) = Drive::verify_identity_owns_key(
+                proof.as_slice(),
+                identity_id,
+                key_id,
+                public_key.as_slice(),
+            )
+            .expect("expect that this be verified");
+            assert_eq!(owns_key, Some(true));
+
+            let (_, owns_key) =
+                Drive::verify_identity_owns_key(proof.as_slice(), identity_id, key_id, &[7; 33])
+                    .expect("expect that this be verified");
+            assert_eq!(owns_key, Some(false));
+
+            let proof = drive
+                .prove_identity_key_with_balance(identity_id, 10, None)
+                .expect("should not error when proving the key");
+
+            let (_, owns_key) = Drive::verify_identity_owns_key(
+                proof.as_slice(),
+                identity_id,
+                10,
+                public_key.as_slice(),
+            )
+            .expect("expect that this be verified");
+            assert_eq!(owns_key, Some(false));
+
+            let unknown_identity_id = [7; 32];
+            let proof = drive
+                .prove_identity_key_with_balance(unknown_identity_id, key_id, None)
+                .expect("should not error when proving the key");
+
+            let (_, owns_key) = Drive::verify_identity_owns_key(
+                proof.as_slice(),
+                unknown_identity_id,
+                key_id,
+                public_key.as_slice(),
+            )
+            .expect("expect that this be verified");
+            assert_eq!(owns_key, None);
+        }
+    }
+
+    mod prove_identity_keys_by_ids {
+        use super::*;
+
+        #[test]
+        fn should_prove_only_the_requested_key_ids() {
+            let drive = setup_drive_with_initial_state_structure();
+            let identity = Identity::random_identity(5, Some(14));
+
+            let identity_id = identity.id.to_buffer();
+            let expected_key = identity
+                .public_keys
+                .get(&3)
+                .expect("expected the identity to have a key 3")
+                .clone();
+            drive
+                .add_new_identity(identity, &BlockInfo::default(), true, None)
+                .expect("expected to add an identity");
+
+            let proof = drive
+                .prove_identity_keys(
+                    IdentityKeysRequest::new_specific_keys_query(&identity_id, vec![3, 10]),
+                    None,
+                )
+                .expect("should not error when proving keys");
+
+            let (_, keys) =
+                Drive::verify_identity_keys_by_ids(proof.as_slice(), identity_id, &[3, 10], false)
+                    .expect("expect that this be verified");
+
+            assert_eq!(keys.len(), 2);
+            assert_eq!(keys.get(&3), Some(&Some(expected_key)));
+            assert_eq!(keys.get(&10), Some(&None));
+        }
+    }
+
+    mod prove_identity_keys_by_purpose {
+        use super::*;
+        use dpp::identity::{IdentityPublicKey, KeyID};
+        use std::collections::BTreeMap;
+
+        #[test]
+        fn should_prove_only_keys_of_the_requested_purpose() {
+            let drive = setup_drive_with_initial_state_structure();
+            let identity = Identity::random_identity(3, Some(14));
+
+            let identity_id = identity.id.to_buffer();
+            let authentication_keys: BTreeMap<KeyID, IdentityPublicKey> = identity
+                .public_keys
+                .iter()
+                .filter(|(_, key)| key.purpose == Purpose::AUTHENTICATION)
+                .map(|(key_id, key)| (*key_id, key.clone()))
+                .collect();
+            drive
+                .add_new_identity(identity, &BlockInfo::default(), true, None)
+                .expect("expected to add an identity");
+
+            let proof = drive
+                .prove_identity_keys_by_purpose(identity_id, &[Purpose::AUTHENTICATION], None)
+                .expect("should not error when proving keys");
+
+            let (_, keys) = Drive::verify_identity_keys_by_purpose(
+                proof.as_slice(),
+                false,
+                identity_id,
+                &[Purpose::AUTHENTICATION],
+            )
+            .expect("expect that this be verified");
+
+            assert_eq!(keys, authentication_keys);
+        }
+
+        #[test]
+        fn should_not_prove_keys_of_purposes_without_references() {
+            let drive = setup_drive_with_initial_state_structure();
+
+            let result =
+                drive.prove_identity_keys_by_purpose([1; 32], &[Purpose::ENCRYPTION], None);
+
+            assert!(matches!(
+                result,
+                Err(Error::Drive(DriveError::NotSupported(_)))
+            ));
+        }
+    }
 }
@@ -2,6 +2,7 @@ use crate::drive::identity::key::fetch::IdentityKeysRequest;
 use crate::drive::Drive;
 use crate::error::Error;
 use crate::error::Error::GroveDB;
+use dpp::identity::KeyID;
 use grovedb::PathQuery;
 
 impl Drive {
@@ -41,4 +42,20 @@ impl Drive {
 
         PathQuery::merge(path_queries.iter().collect()).map_err(GroveDB)
     }
+
+    /// The query for a specific key of an identity together with the identity balance.
+    ///
+    /// Every identity has a balance, so the balance tells a missing identity apart from an
+    /// identity without the key. The key query has no limit, as limited path queries can not
+    /// be merged.
+    pub fn identity_key_with_balance_query(
+        identity_id: &[u8; 32],
+        key_id: KeyID,
+    ) -> Result<PathQuery, Error> {
+        let balance_query = Self::identity_balance_query(identity_id);
+        let key_query =
+            IdentityKeysRequest::new_specific_key_query_without_limit(identity_id, key_id)
+                .into_path_query();
+        PathQuery::merge(vec![&balance_query, &key_query]).map_err(GroveDB)
+    }
 }
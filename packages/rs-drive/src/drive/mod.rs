@@ -104,12 +104,12 @@ pub mod object_size_info;
 mod protocol_upgrade;
 #[cfg(feature = "full")]
 pub mod query;
-#[cfg(feature = "full")]
+#[cfg(any(feature = "full", feature = "verify"))]
 mod system;
 #[cfg(test)]
 mod test_utils;
 
-#[cfg(feature = "full")]
+#[cfg(any(feature = "full", feature = "verify"))]
 mod asset_lock;
 #[cfg(feature = "full")]
 mod prove;
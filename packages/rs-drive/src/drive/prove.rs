@@ -40,12 +40,21 @@ impl Drive {
             count += contract_ids.len();
         }
         if !document_queries.is_empty() {
-            path_queries.extend(
-                document_queries
-                    .iter()
-                    .map(|drive_query| drive_query.construct_path_query()),
-            );
             count += document_queries.len();
+            // limited path queries can not be merged
+            if count == 1 {
+                path_queries.extend(
+                    document_queries
+                        .iter()
+                        .map(|drive_query| drive_query.construct_path_query()),
+                );
+            } else {
+                path_queries.extend(
+                    document_queries
+                        .iter()
+                        .map(|drive_query| drive_query.construct_path_query_without_limit()),
+                );
+            }
         }
         let verbose = match count {
             0 => {
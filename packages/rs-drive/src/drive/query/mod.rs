@@ -41,7 +41,7 @@ use crate::error::query::QuerySyntaxError;
 use crate::error::Error;
 use crate::fee::calculate_fee;
 use crate::fee::op::LowLevelDriveOperation;
-use crate::query::DriveQuery;
+use crate::query::{DriveQuery, WhereClause};
 use dpp::data_contract::document_type::DocumentType;
 
 use dpp::document::Document;
@@ -50,6 +50,7 @@ use dpp::ProtocolError;
 
 use dpp::block::block_info::BlockInfo;
 use dpp::block::epoch::Epoch;
+use dpp::prelude::TimestampMillis;
 
 #[derive(Debug, Default)]
 /// The outcome of a query
@@ -434,4 +435,165 @@ impl Drive {
 
         query.execute_with_proof_only_get_elements_internal(self, transaction, drive_operations)
     }
+
+    /// Proves the documents of a document type created from `from_ms` included to `to_ms`
+    /// excluded, oldest first, through an index of the document type starting with
+    /// `$createdAt`.
+    ///
+    /// The proof is verified with `Drive::verify_documents_created_between`.
+    pub fn prove_documents_created_between(
+        &self,
+        contract_id: [u8; 32],
+        document_type_name: &str,
+        from_ms: TimestampMillis,
+        to_ms: TimestampMillis,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+    ) -> Result<Vec<u8>, Error> {
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+        let contract = self
+            .get_contract_with_fetch_info_and_add_to_operations(
+                contract_id,
+                None,
+                true,
+                transaction,
+                &mut drive_operations,
+            )?
+            .ok_or(Error::Query(QuerySyntaxError::ContractNotFound(
+                "contract not found",
+            )))?;
+        let query = Self::documents_created_between_query(
+            &contract.contract,
+            document_type_name,
+            from_ms,
+            to_ms,
+            limit,
+        )?;
+        query.execute_with_proof_internal(self, transaction, &mut drive_operations)
+    }
+
+    /// Proves the documents of a document type whose `field` starts with `prefix`, through an
+    /// index of the document type starting with `field`.
+    ///
+    /// The proof is verified with `Drive::verify_prefix_query`.
+    pub fn prove_documents_with_prefix(
+        &self,
+        contract_id: [u8; 32],
+        document_type_name: &str,
+        field: &str,
+        prefix: &str,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+    ) -> Result<Vec<u8>, Error> {
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+        let contract = self
+            .get_contract_with_fetch_info_and_add_to_operations(
+                contract_id,
+                None,
+                true,
+                transaction,
+                &mut drive_operations,
+            )?
+            .ok_or(Error::Query(QuerySyntaxError::ContractNotFound(
+                "contract not found",
+            )))?;
+        let query =
+            Self::prefix_query(&contract.contract, document_type_name, field, prefix, limit)?;
+        query.execute_with_proof_internal(self, transaction, &mut drive_operations)
+    }
+
+    /// Proves whether an identity owns documents of a document type, through its `$ownerId`
+    /// index tree.
+    ///
+    /// The proof is verified with `Drive::verify_identity_has_no_documents`.
+    pub fn prove_identity_has_no_documents(
+        &self,
+        contract_id: [u8; 32],
+        document_type_name: &str,
+        owner_id: [u8; 32],
+        transaction: TransactionArg,
+    ) -> Result<Vec<u8>, Error> {
+        let path_query =
+            Self::owner_documents_index_query(contract_id, document_type_name, owner_id);
+        self.grove_get_proved_path_query(&path_query, false, transaction, &mut vec![])
+    }
+
+    /// Proves the revisions of a document of a document type keeping history, newest first.
+    ///
+    /// The proof is verified with `Drive::verify_document_history`.
+    pub fn prove_document_history(
+        &self,
+        contract_id: [u8; 32],
+        document_type_name: &str,
+        document_id: [u8; 32],
+        limit: Option<u16>,
+        transaction: TransactionArg,
+    ) -> Result<Vec<u8>, Error> {
+        let path_query =
+            Self::document_history_query(contract_id, document_type_name, document_id, limit);
+        self.grove_get_proved_path_query(&path_query, false, transaction, &mut vec![])
+    }
+
+    /// Proves whether any document of a document type matches where clauses, through the
+    /// first matching document if there is one.
+    ///
+    /// The proof is verified with `Drive::verify_query_empty`.
+    pub fn prove_query_empty(
+        &self,
+        contract_id: [u8; 32],
+        document_type_name: &str,
+        where_clauses: &[WhereClause],
+        transaction: TransactionArg,
+    ) -> Result<Vec<u8>, Error> {
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+        let contract = self
+            .get_contract_with_fetch_info_and_add_to_operations(
+                contract_id,
+                None,
+                true,
+                transaction,
+                &mut drive_operations,
+            )?
+            .ok_or(Error::Query(QuerySyntaxError::ContractNotFound(
+                "contract not found",
+            )))?;
+        let document_type = contract
+            .contract
+            .document_type_for_name(document_type_name)?;
+        let query =
+            Self::first_matching_document_query(&contract.contract, document_type, where_clauses)?;
+        query.execute_with_proof_internal(self, transaction, &mut drive_operations)
+    }
+
+    /// Proves the full identity and the documents of a document type it owns, through an index
+    /// of the document type starting with `$ownerId`, in one proof.
+    ///
+    /// All the documents the identity owns are proven, as the proof can not be limited.
+    /// The proof is verified with `Drive::verify_identity_with_documents`.
+    pub fn prove_identity_with_documents(
+        &self,
+        identity_id: [u8; 32],
+        contract_id: [u8; 32],
+        document_type_name: &str,
+        transaction: TransactionArg,
+    ) -> Result<Vec<u8>, Error> {
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+        let contract = self
+            .get_contract_with_fetch_info_and_add_to_operations(
+                contract_id,
+                None,
+                true,
+                transaction,
+                &mut drive_operations,
+            )?
+            .ok_or(Error::Query(QuerySyntaxError::ContractNotFound(
+                "contract not found",
+            )))?;
+        let path_query = Self::identity_with_documents_query(
+            identity_id,
+            &contract.contract,
+            document_type_name,
+        )?;
+        self.grove_get_proved_path_query(&path_query, true, transaction, &mut drive_operations)
+    }
 }
@@ -1,11 +1,14 @@
+#[cfg(feature = "full")]
 mod estimation_costs;
 /// Constants for the misc tree
 pub mod misc_tree_constants;
 /// Protocol version module
+#[cfg(feature = "full")]
 pub mod protocol_version;
 
 use crate::drive::RootTree;
 
+#[cfg(feature = "full")]
 pub(crate) fn misc_path() -> [&'static [u8]; 1] {
     [Into::<&[u8; 1]>::into(RootTree::Misc)]
 }
@@ -32,6 +32,15 @@ impl Drive {
             })?
     }
 
+    /// Proves the current protocol version
+    pub fn prove_current_protocol_version(
+        &self,
+        transaction: TransactionArg,
+    ) -> Result<Vec<u8>, Error> {
+        let query = Self::current_protocol_version_query();
+        self.grove_get_proved_path_query(&query, false, transaction, &mut vec![])
+    }
+
     /// Sets the current protocol version
     pub fn set_current_protocol_version_operations(
         &self,
@@ -70,6 +79,15 @@ impl Drive {
             })?
     }
 
+    /// Proves the next protocol version
+    pub fn prove_next_protocol_version(
+        &self,
+        transaction: TransactionArg,
+    ) -> Result<Vec<u8>, Error> {
+        let query = Self::next_protocol_version_query();
+        self.grove_get_proved_path_query(&query, false, transaction, &mut vec![])
+    }
+
     /// Sets the next protocol version
     pub fn set_next_protocol_version_operations(
         &self,
@@ -0,0 +1,57 @@
+use crate::drive::asset_lock::asset_lock_storage_path_vec;
+use crate::drive::verify::RootHash;
+use crate::drive::Drive;
+use crate::error::proof::ProofError;
+use crate::error::Error;
+use dpp::platform_value::Bytes36;
+use grovedb::GroveDb;
+
+impl Drive {
+    /// Verifies that an asset lock outpoint has not already been used.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of presence or absence of the outpoint.
+    /// - `outpoint`: The 36 byte outpoint of the asset lock transaction.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// a `bool`. The `RootHash` represents the root hash of GroveDB, and the `bool` is `true`
+    /// when the outpoint is provably absent from the spent asset locks, meaning it is unused.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is not valid.
+    /// - The proved key value is not for the correct path or key in the spent asset locks tree.
+    /// - More than one element is found.
+    ///
+    pub fn verify_asset_lock_outpoint_unused(
+        proof: &[u8],
+        outpoint: &Bytes36,
+    ) -> Result<(RootHash, bool), Error> {
+        let path_query = Self::asset_lock_outpoint_query(outpoint);
+        let (root_hash, mut proved_key_values) =
+            GroveDb::verify_query_with_absence_proof(proof, &path_query)?;
+        if proved_key_values.len() == 1 {
+            let (path, key, maybe_element) = proved_key_values.remove(0);
+            if path != asset_lock_storage_path_vec() {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct path in the spent asset locks",
+                )));
+            }
+            if key != outpoint.as_slice() {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct outpoint",
+                )));
+            }
+            Ok((root_hash, maybe_element.is_none()))
+        } else {
+            Err(Error::Proof(ProofError::TooManyElements(
+                "expected one asset lock outpoint",
+            )))
+        }
+    }
+}
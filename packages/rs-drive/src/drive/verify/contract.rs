@@ -1,17 +1,26 @@
 use crate::drive::contract::paths::{
-    contract_keeping_history_storage_path, contract_root_path, contract_storage_path_vec,
+    all_contracts_global_root_path, contract_keeping_history_storage_path, contract_root_path,
+    contract_storage_path_vec,
 };
-use crate::drive::verify::RootHash;
+use crate::drive::verify::{verify_expected_root_hash, RootHash};
 use crate::drive::Drive;
 use crate::error::proof::ProofError;
 use crate::error::Error;
 use crate::error::Error::GroveDB;
+use dpp::data_contract::JsonSchema;
 use dpp::prelude::DataContract;
 use std::collections::BTreeMap;
 
 use crate::common::decode;
 use crate::error::drive::DriveError;
-use grovedb::GroveDb;
+use dpp::data_contracts::{dashpay_contract, dpns_contract};
+use grovedb::{GroveDb, PathQuery};
+
+/// The well-known id of the DPNS system data contract
+pub const DPNS_CONTRACT_ID: [u8; 32] = dpns_contract::ID_BYTES;
+
+/// The well-known id of the Dashpay system data contract
+pub const DASHPAY_CONTRACT_ID: [u8; 32] = dashpay_contract::ID_BYTES;
 
 impl Drive {
     /// Verifies that the contract is included in the proof.
@@ -40,6 +49,83 @@ impl Drive {
         is_proof_subset: bool,
         contract_id: [u8; 32],
     ) -> Result<(RootHash, Option<DataContract>), Error> {
+        let (root_hash, serialized_contract) = Self::verify_contract_keep_serialized(
+            proof,
+            contract_known_keeps_history,
+            is_proof_subset,
+            contract_id,
+        )?;
+        let contract = serialized_contract
+            .map(|bytes| DataContract::deserialize_no_limit(&bytes).map_err(Error::Protocol))
+            .transpose()?;
+        Ok((root_hash, contract))
+    }
+
+    /// Verifies that the contract is included in the proof against a root hash the caller
+    /// already trusts, for example one taken from a verified block header.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `contract_known_keeps_history`: An optional boolean indicating whether the contract keeps a history.
+    /// - `is_proof_subset`: A boolean indicating whether to verify a subset of a larger proof.
+    /// - `contract_id`: The contract's unique identifier.
+    /// - `expected_root`: The trusted root hash the proof must resolve to.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with an `Option<DataContract>`, the verified contract if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is corrupted.
+    /// - The GroveDb query fails.
+    /// - The computed root hash does not match `expected_root`.
+    pub fn verify_contract_with_expected_root(
+        proof: &[u8],
+        contract_known_keeps_history: Option<bool>,
+        is_proof_subset: bool,
+        contract_id: [u8; 32],
+        expected_root: RootHash,
+    ) -> Result<Option<DataContract>, Error> {
+        let (root_hash, contract) = Self::verify_contract(
+            proof,
+            contract_known_keeps_history,
+            is_proof_subset,
+            contract_id,
+        )?;
+        verify_expected_root_hash(expected_root, root_hash)?;
+        Ok(contract)
+    }
+
+    /// Verifies that the contract is included in the proof while keeping it serialized.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `contract_known_keeps_history`: An optional boolean indicating whether the contract keeps a history.
+    /// - `is_proof_subset`: A boolean indicating whether to verify a subset of a larger proof.
+    /// - `contract_id`: The contract's unique identifier.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and `Option<Vec<u8>>`. The `Option<Vec<u8>>`
+    /// represents the serialized contract if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is corrupted.
+    /// - The GroveDb query fails.
+    pub fn verify_contract_keep_serialized(
+        proof: &[u8],
+        contract_known_keeps_history: Option<bool>,
+        is_proof_subset: bool,
+        contract_id: [u8; 32],
+    ) -> Result<(RootHash, Option<Vec<u8>>), Error> {
         let path_query = if contract_known_keeps_history.unwrap_or_default() {
             Self::fetch_contract_with_history_latest_query(contract_id)
         } else {
@@ -56,7 +142,12 @@ impl Drive {
             Err(e) => {
                 return if contract_known_keeps_history.is_none() {
                     // most likely we are trying to prove a historical contract
-                    Self::verify_contract(proof, Some(true), is_proof_subset, contract_id)
+                    Self::verify_contract_keep_serialized(
+                        proof,
+                        Some(true),
+                        is_proof_subset,
+                        contract_id,
+                    )
                 } else {
                     Err(e)
                 };
@@ -79,6 +170,142 @@ impl Drive {
                     )));
             };
 
+            if key != vec![0] {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct key for the contract",
+                )));
+            }
+            let serialized_contract = maybe_element
+                .map(|element| element.into_item_bytes().map_err(Error::GroveDB))
+                .transpose()?;
+            Ok((root_hash, serialized_contract))
+        } else {
+            Err(Error::Proof(ProofError::TooManyElements(
+                "expected one contract id",
+            )))
+        }
+    }
+
+    /// Verifies that the contract is included in the proof and returns only its owner, without
+    /// deserializing the whole contract.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `contract_id`: The contract's unique identifier.
+    /// - `is_proof_subset`: A boolean indicating whether to verify a subset of a larger proof.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and `Option<[u8; 32]>`, the id of the
+    /// identity owning the contract if the contract exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is corrupted.
+    /// - The GroveDb query fails.
+    /// - The owner id can not be read from the serialized contract.
+    pub fn verify_contract_owner(
+        proof: &[u8],
+        contract_id: [u8; 32],
+        is_proof_subset: bool,
+    ) -> Result<(RootHash, Option<[u8; 32]>), Error> {
+        let (root_hash, serialized_contract) =
+            Self::verify_contract_keep_serialized(proof, None, is_proof_subset, contract_id)?;
+        let owner_id = serialized_contract
+            .map(|bytes| {
+                DataContract::owner_id_from_serialized(&bytes)
+                    .map(|owner_id| owner_id.to_buffer())
+                    .map_err(Error::Protocol)
+            })
+            .transpose()?;
+        Ok((root_hash, owner_id))
+    }
+
+    /// Verifies that the contract is included in the proof and returns the shared definitions
+    /// of its `$defs` section, which its document types reference.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `contract_id`: The contract's unique identifier.
+    /// - `is_proof_subset`: A boolean indicating whether to verify a subset of a larger proof.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and the definitions by name if the
+    /// contract exists. A contract without a `$defs` section has no definitions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is corrupted.
+    /// - The GroveDb query fails.
+    /// - The contract serialization fails.
+    pub fn verify_contract_schema_defs(
+        proof: &[u8],
+        contract_id: [u8; 32],
+        is_proof_subset: bool,
+    ) -> Result<(RootHash, Option<BTreeMap<String, JsonSchema>>), Error> {
+        let (root_hash, contract) =
+            Self::verify_contract(proof, None, is_proof_subset, contract_id)?;
+        Ok((
+            root_hash,
+            contract.map(|contract| contract.defs.unwrap_or_default()),
+        ))
+    }
+
+    /// Verifies that multiple contracts are included in a single proof.
+    ///
+    /// Contracts that keep history are stored in a tree and can not be verified with this
+    /// method, use `verify_contract` for them instead.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `contract_ids`: The unique identifiers of the contracts.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and a `BTreeMap` from every requested
+    /// contract id to an `Option<DataContract>`, which is `None` if the contract was proved absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is corrupted.
+    /// - The GroveDb query fails.
+    /// - The proof contains a contract that was not requested.
+    /// - The contract serialization fails.
+    pub fn verify_contracts(
+        proof: &[u8],
+        contract_ids: &[[u8; 32]],
+    ) -> Result<(RootHash, BTreeMap<[u8; 32], Option<DataContract>>), Error> {
+        let path_query = Self::fetch_contracts_query(contract_ids)?;
+
+        let (root_hash, proved_key_values) = GroveDb::verify_query(proof, &path_query)?;
+
+        let mut contracts: BTreeMap<[u8; 32], Option<DataContract>> = contract_ids
+            .iter()
+            .map(|contract_id| (*contract_id, None))
+            .collect();
+        for (path, key, maybe_element) in proved_key_values {
+            let contract_id = path
+                .get(1)
+                .and_then(|contract_id| <[u8; 32]>::try_from(contract_id.as_slice()).ok())
+                .filter(|contract_id| contracts.contains_key(contract_id))
+                .ok_or(Error::Proof(ProofError::CorruptedProof(
+                    "we got back a contract that we did not request",
+                )))?;
+            if path != contract_root_path(&contract_id) {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct path for the contract",
+                )));
+            }
             if key != vec![0] {
                 return Err(Error::Proof(ProofError::CorruptedProof(
                     "we did not get back an element for the correct key for the contract",
@@ -94,12 +321,110 @@ impl Drive {
                         })
                 })
                 .transpose()?;
-            Ok((root_hash, contract))
-        } else {
-            Err(Error::Proof(ProofError::TooManyElements(
-                "expected one contract id",
-            )))
+            contracts.insert(contract_id, contract);
         }
+
+        Ok((root_hash, contracts))
+    }
+
+    /// Verifies whether multiple contracts exist, from a proof of their keys under the global
+    /// contracts root.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `contract_ids`: The unique identifiers of the contracts.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and a `BTreeMap` from every requested
+    /// contract id to whether the contract exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is corrupted.
+    /// - The GroveDb query fails.
+    /// - The proof contains a contract that was not requested.
+    pub fn verify_contracts_exist(
+        proof: &[u8],
+        contract_ids: &[[u8; 32]],
+    ) -> Result<(RootHash, BTreeMap<[u8; 32], bool>), Error> {
+        let path_query = Self::contracts_exist_query(contract_ids);
+
+        let (root_hash, proved_key_values) = GroveDb::verify_query(proof, &path_query)?;
+
+        let mut contracts_exist: BTreeMap<[u8; 32], bool> = contract_ids
+            .iter()
+            .map(|contract_id| (*contract_id, false))
+            .collect();
+        for (path, key, maybe_element) in proved_key_values {
+            if path != all_contracts_global_root_path() {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct path for the contracts",
+                )));
+            }
+            let contract_id = <[u8; 32]>::try_from(key.as_slice())
+                .ok()
+                .filter(|contract_id| contracts_exist.contains_key(contract_id))
+                .ok_or(Error::Proof(ProofError::CorruptedProof(
+                    "we got back a contract that we did not request",
+                )))?;
+            contracts_exist.insert(contract_id, maybe_element.is_some());
+        }
+
+        Ok((root_hash, contracts_exist))
+    }
+
+    /// Verifies that the DPNS system data contract is included in the proof.
+    ///
+    /// This is a convenience wrapper around `verify_contract` with the well-known
+    /// DPNS contract id baked in. System contracts do not keep history.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and `Option<DataContract>`. The `Option<DataContract>`
+    /// represents the verified DPNS contract if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is corrupted.
+    /// - The GroveDb query fails.
+    pub fn verify_dpns_contract(proof: &[u8]) -> Result<(RootHash, Option<DataContract>), Error> {
+        Self::verify_contract(proof, Some(false), false, DPNS_CONTRACT_ID)
+    }
+
+    /// Verifies that the Dashpay system data contract is included in the proof.
+    ///
+    /// This is a convenience wrapper around `verify_contract` with the well-known
+    /// Dashpay contract id baked in. System contracts do not keep history.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and `Option<DataContract>`. The `Option<DataContract>`
+    /// represents the verified Dashpay contract if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is corrupted.
+    /// - The GroveDb query fails.
+    pub fn verify_dashpay_contract(
+        proof: &[u8],
+    ) -> Result<(RootHash, Option<DataContract>), Error> {
+        Self::verify_contract(proof, Some(false), false, DASHPAY_CONTRACT_ID)
     }
 
     /// Verifies that the contract's history is included in the proof.
@@ -134,7 +459,48 @@ impl Drive {
         let path_query =
             Self::fetch_contract_history_query(contract_id, start_at_date, limit, offset)?;
 
-        let (root_hash, mut proved_key_values) = GroveDb::verify_query(proof, &path_query)?;
+        Self::verify_contract_history_for_path_query(proof, contract_id, &path_query)
+    }
+
+    /// Verifies that the contract's full history, from its earliest revision, is included in
+    /// the proof.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `contract_id`: The contract's unique identifier.
+    /// - `limit`: An optional limit for the number of items to be retrieved.
+    /// - `offset`: An optional offset for the items to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and `Option<BTreeMap<u64, DataContract>>`. The `Option<BTreeMap<u64, DataContract>>`
+    /// represents a mapping from dates to contracts if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is corrupted.
+    /// - The GroveDb query fails.
+    /// - The contract serialization fails.
+    pub fn verify_contract_full_history(
+        proof: &[u8],
+        contract_id: [u8; 32],
+        limit: Option<u16>,
+        offset: Option<u16>,
+    ) -> Result<(RootHash, Option<BTreeMap<u64, DataContract>>), Error> {
+        let path_query = Self::fetch_contract_full_history_query(contract_id, limit, offset)?;
+
+        Self::verify_contract_history_for_path_query(proof, contract_id, &path_query)
+    }
+
+    fn verify_contract_history_for_path_query(
+        proof: &[u8],
+        contract_id: [u8; 32],
+        path_query: &PathQuery,
+    ) -> Result<(RootHash, Option<BTreeMap<u64, DataContract>>), Error> {
+        let (root_hash, mut proved_key_values) = GroveDb::verify_query(proof, path_query)?;
 
         let mut contracts: BTreeMap<u64, DataContract> = BTreeMap::new();
         for (path, key, maybe_element) in proved_key_values.drain(..) {
@@ -0,0 +1,236 @@
+use crate::drive::verify::RootHash;
+use crate::drive::Drive;
+use crate::error::query::QuerySyntaxError;
+use crate::error::Error;
+use crate::query::{DriveQuery, InternalClauses, OrderClause, WhereClause, WhereOperator};
+use dpp::document::Document;
+use dpp::platform_value::Value;
+use dpp::prelude::{DataContract, TimestampMillis};
+use indexmap::IndexMap;
+
+/// The system property holding the time a document was created at
+const CREATED_AT: &str = "$createdAt";
+
+impl Drive {
+    /// Builds the query for the documents of a document type created from `from_ms` included to
+    /// `to_ms` excluded, oldest first.
+    ///
+    /// # Parameters
+    ///
+    /// - `contract`: The data contract of the document type.
+    /// - `document_type_name`: The name of the document type.
+    /// - `from_ms`: The time from which documents are returned, included.
+    /// - `to_ms`: The time until which documents are returned, excluded.
+    /// - `limit`: The maximum number of documents returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the contract has no such document type, or if the document type
+    /// has no index starting with `$createdAt`.
+    pub fn documents_created_between_query<'a>(
+        contract: &'a DataContract,
+        document_type_name: &str,
+        from_ms: TimestampMillis,
+        to_ms: TimestampMillis,
+        limit: Option<u16>,
+    ) -> Result<DriveQuery<'a>, Error> {
+        let document_type = contract.document_type_for_name(document_type_name)?;
+        if !document_type.indices.iter().any(|index| {
+            index
+                .properties
+                .first()
+                .map_or(false, |property| property.name == CREATED_AT)
+        }) {
+            return Err(Error::Query(
+                QuerySyntaxError::WhereClauseOnNonIndexedProperty(
+                    "documents can only be queried by creation time if the document type has an index starting with $createdAt",
+                ),
+            ));
+        }
+        Ok(DriveQuery {
+            contract,
+            document_type,
+            internal_clauses: InternalClauses {
+                range_clause: Some(WhereClause {
+                    field: CREATED_AT.to_string(),
+                    operator: WhereOperator::BetweenExcludeRight,
+                    value: Value::Array(vec![Value::U64(from_ms), Value::U64(to_ms)]),
+                }),
+                ..Default::default()
+            },
+            offset: None,
+            limit,
+            order_by: IndexMap::from([(
+                CREATED_AT.to_string(),
+                OrderClause {
+                    field: CREATED_AT.to_string(),
+                    ascending: true,
+                },
+            )]),
+            start_at: None,
+            start_at_included: true,
+            block_time_ms: None,
+        })
+    }
+
+    /// Verifies the proof of the documents of a document type created within a time range.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `contract`: The data contract of the document type.
+    /// - `document_type_name`: The name of the document type.
+    /// - `from_ms`: The time from which documents were requested, included.
+    /// - `to_ms`: The time until which documents were requested, excluded.
+    /// - `limit`: The maximum number of documents that were requested.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and the proven documents, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The document type does not exist or has no index starting with `$createdAt`.
+    /// - The proof is corrupted or a document can not be deserialized.
+    pub fn verify_documents_created_between(
+        proof: &[u8],
+        contract: &DataContract,
+        document_type_name: &str,
+        from_ms: TimestampMillis,
+        to_ms: TimestampMillis,
+        limit: Option<u16>,
+    ) -> Result<(RootHash, Vec<Document>), Error> {
+        Self::documents_created_between_query(contract, document_type_name, from_ms, to_ms, limit)?
+            .verify_proof(proof)
+    }
+}
+
+#[cfg(feature = "full")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drive::flags::StorageFlags;
+    use crate::drive::object_size_info::DocumentInfo::DocumentRefInfo;
+    use crate::drive::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::platform_value::platform_value;
+    use dpp::prelude::Identifier;
+
+    #[test]
+    fn should_prove_documents_created_between() {
+        let drive = setup_drive_with_initial_state_structure();
+
+        let contract = DataContract::from_raw_object(platform_value!({
+            "protocolVersion": 1,
+            "$id": "BZUodcFoFL6KvnonehrnMVggTvCe8W5MiRnZuqLb6M54",
+            "$schema": "https://schema.dash.org/dpp-0-4-0/meta/data-contract",
+            "version": 1,
+            "ownerId": "GZVdTnLFAN2yE9rLeCHBDBCr7YQgmXJuoExkY347j7Z5",
+            "documents": {
+                "note": {
+                    "type": "object",
+                    "indices": [
+                        {"name":"createdAt", "properties": [{"$createdAt":"asc"}]},
+                    ],
+                    "properties":{
+                        "message": {
+                            "type": "string",
+                            "maxLength": 63,
+                        },
+                    },
+                    "required": ["message", "$createdAt"],
+                    "additionalProperties": false,
+                },
+                "undatedNote": {
+                    "type": "object",
+                    "properties":{
+                        "message": {
+                            "type": "string",
+                            "maxLength": 63,
+                        },
+                    },
+                    "required": ["message"],
+                    "additionalProperties": false,
+                },
+            },
+        }))
+        .expect("expected to create a contract");
+
+        drive
+            .apply_contract(&contract, BlockInfo::default(), true, None, None)
+            .expect("expected to apply the contract");
+
+        let document_type = contract
+            .document_type_for_name("note")
+            .expect("expected to get the document type");
+
+        for (i, created_at) in [1_000u64, 2_000, 3_000, 4_000].into_iter().enumerate() {
+            let document = Document {
+                id: Identifier::new([i as u8 + 1; 32]),
+                owner_id: contract.owner_id,
+                properties: [("message".to_string(), Value::Text(format!("note {i}")))].into(),
+                revision: None,
+                created_at: Some(created_at),
+                updated_at: None,
+            };
+            drive
+                .add_document_for_contract(
+                    DocumentAndContractInfo {
+                        owned_document_info: OwnedDocumentInfo {
+                            document_info: DocumentRefInfo((
+                                &document,
+                                StorageFlags::optional_default_as_cow(),
+                            )),
+                            owner_id: None,
+                        },
+                        contract: &contract,
+                        document_type,
+                    },
+                    false,
+                    BlockInfo::default(),
+                    true,
+                    None,
+                )
+                .expect("expected to insert a document");
+        }
+
+        let proof = drive
+            .prove_documents_created_between(
+                contract.id.to_buffer(),
+                "note",
+                2_000,
+                4_000,
+                Some(10),
+                None,
+            )
+            .expect("expected to prove the documents");
+
+        let (_, documents) = Drive::verify_documents_created_between(
+            proof.as_slice(),
+            &contract,
+            "note",
+            2_000,
+            4_000,
+            Some(10),
+        )
+        .expect("expected to verify the documents");
+
+        assert_eq!(
+            documents
+                .iter()
+                .map(|document| document.created_at)
+                .collect::<Vec<_>>(),
+            vec![Some(2_000), Some(3_000)]
+        );
+
+        assert!(matches!(
+            Drive::documents_created_between_query(&contract, "undatedNote", 2_000, 4_000, None),
+            Err(Error::Query(
+                QuerySyntaxError::WhereClauseOnNonIndexedProperty(_)
+            ))
+        ));
+    }
+}
@@ -1,10 +1,37 @@
-use crate::drive::verify::RootHash;
+use crate::drive::verify::{verify_expected_root_hash, RootHash};
 
 use crate::error::proof::ProofError;
 use crate::error::Error;
 use crate::query::DriveQuery;
+use dpp::data_contract::errors::DataContractError;
 use dpp::document::Document;
+use dpp::prelude::Identifier;
+use dpp::ProtocolError;
+use grovedb::query_result_type::{Key, Path, PathKeyOptionalElementTrio};
 use grovedb::{GroveDb, PathQuery};
+use std::collections::BTreeMap;
+
+/// The lowest and highest positions covered by a documents proof, each given as the path of
+/// the subtree and the key within it. Index values are part of the path, so comparing them
+/// with the bounds of a range query tells which part of the range the proof answered.
+///
+/// The positions are those of the proved documents. When no document is proved, they are the
+/// bounds of the query in the subtree it starts at, an unbounded side being given as an empty
+/// key, as the proof shows the whole queried range is empty.
+///
+/// When the limit of the query was reached, more documents of the range may follow the last
+/// proved one, so the range was only fully answered if the limit was not reached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProvedKeyRange {
+    /// The lowest path and key the proof covers
+    pub lowest: (Path, Key),
+    /// The highest path and key the proof covers
+    pub highest: (Path, Key),
+    /// Whether the proof holds as many documents as the limit of the query, that is whether
+    /// `documents.len() >= limit`. It is a count check, a range holding exactly as many
+    /// documents as the limit is also reported as reached.
+    pub limit_reached: bool,
+}
 
 impl<'a> DriveQuery<'a> {
     /// Verifies the given proof and returns the root hash of the GroveDB tree and a vector
@@ -26,6 +53,151 @@ impl<'a> DriveQuery<'a> {
         &self,
         proof: &[u8],
     ) -> Result<(RootHash, Vec<Vec<u8>>), Error> {
+        let (root_hash, proved_key_values) = self.verify_proved_key_values(proof)?;
+
+        let documents = proved_key_values
+            .into_iter()
+            .filter_map(|(_path, _key, element)| element)
+            .map(|element| element.into_item_bytes().map_err(Error::GroveDB))
+            .collect::<Result<Vec<Vec<u8>>, Error>>()?;
+        Ok((root_hash, documents))
+    }
+
+    /// Verifies a proof for a collection of documents and also returns the lowest and
+    /// highest path and key the proof covers, and whether the limit of the query was reached,
+    /// so a client can confirm a range query was fully answered and not truncated by the limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - A byte slice representing the proof to be verified.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the root hash, the deserialized `Document`s and the covered
+    /// `ProvedKeyRange`, which holds the queried bounds when the proof contains no documents.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an `Error` variant if:
+    /// 1. The proof verification fails.
+    /// 2. There is a deserialization error when parsing the serialized document(s) into `Document` struct(s).
+    pub fn verify_proof_with_key_range(
+        &self,
+        proof: &[u8],
+    ) -> Result<(RootHash, Vec<Document>, ProvedKeyRange), Error> {
+        let (root_hash, path_query, proved_key_values) = self.verify_proved_path_query(proof)?;
+
+        let mut key_range: Option<ProvedKeyRange> = None;
+        let mut documents = Vec::with_capacity(proved_key_values.len());
+        for (path, key, element) in proved_key_values {
+            let Some(element) = element else {
+                continue;
+            };
+            let serialized = element.into_item_bytes().map_err(Error::GroveDB)?;
+            documents.push(
+                Document::from_bytes(serialized.as_slice(), self.document_type)
+                    .map_err(Error::Protocol)?,
+            );
+            let position = (path, key);
+            match key_range.as_mut() {
+                None => {
+                    key_range = Some(ProvedKeyRange {
+                        lowest: position.clone(),
+                        highest: position,
+                        limit_reached: false,
+                    })
+                }
+                Some(key_range) => {
+                    if position < key_range.lowest {
+                        key_range.lowest = position;
+                    } else if position > key_range.highest {
+                        key_range.highest = position;
+                    }
+                }
+            }
+        }
+        let mut key_range = key_range.unwrap_or_else(|| {
+            let items = &path_query.query.query.items;
+            let bound = |bound: Option<&[u8]>| bound.map(<[u8]>::to_vec).unwrap_or_default();
+            ProvedKeyRange {
+                lowest: (
+                    path_query.path.clone(),
+                    bound(items.first().and_then(|item| item.lower_bound().0)),
+                ),
+                highest: (
+                    path_query.path.clone(),
+                    bound(items.last().and_then(|item| item.upper_bound().0)),
+                ),
+                limit_reached: false,
+            }
+        });
+        if let Some(limit) = self.limit {
+            key_range.limit_reached = documents.len() >= limit as usize;
+        }
+        Ok((root_hash, documents, key_range))
+    }
+
+    /// Verifies a proof for a collection of documents and deserializes at most
+    /// `max_documents` of them, in the order they were proven.
+    ///
+    /// The whole proof is still verified, the cap only bounds how many documents are
+    /// deserialized and returned, so a client can refuse or page through answers holding
+    /// more documents than it is willing to process.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - A byte slice representing the proof to be verified.
+    /// * `max_documents` - The most documents to return.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the root hash, the first `max_documents` deserialized
+    /// `Document`s and whether the proof held more documents than that.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an `Error` variant if:
+    /// 1. The proof verification fails.
+    /// 2. There is a deserialization error when parsing the serialized document(s) into `Document` struct(s).
+    pub fn verify_proof_limited(
+        &self,
+        proof: &[u8],
+        max_documents: usize,
+    ) -> Result<(RootHash, Vec<Document>, bool), Error> {
+        let (root_hash, proved_key_values) = self.verify_proved_key_values(proof)?;
+
+        let mut elements = proved_key_values
+            .into_iter()
+            .filter_map(|(_path, _key, element)| element);
+        let documents = elements
+            .by_ref()
+            .take(max_documents)
+            .map(|element| {
+                let serialized = element.into_item_bytes().map_err(Error::GroveDB)?;
+                Document::from_bytes(serialized.as_slice(), self.document_type)
+                    .map_err(Error::Protocol)
+            })
+            .collect::<Result<Vec<Document>, Error>>()?;
+        let exceeded_max_documents = elements.next().is_some();
+        Ok((root_hash, documents, exceeded_max_documents))
+    }
+
+    /// Verifies the proof against the query's path query and returns the proved path, key and
+    /// element trios.
+    fn verify_proved_key_values(
+        &self,
+        proof: &[u8],
+    ) -> Result<(RootHash, Vec<PathKeyOptionalElementTrio>), Error> {
+        let (root_hash, _, proved_key_values) = self.verify_proved_path_query(proof)?;
+        Ok((root_hash, proved_key_values))
+    }
+
+    /// Verifies the proof against the query's path query and returns the path query with the
+    /// proved path, key and element trios.
+    fn verify_proved_path_query(
+        &self,
+        proof: &[u8],
+    ) -> Result<(RootHash, PathQuery, Vec<PathKeyOptionalElementTrio>), Error> {
         let path_query = if let Some(start_at) = &self.start_at {
             let (_, start_document) =
                 self.verify_start_at_document_in_proof(proof, true, *start_at)?;
@@ -41,13 +213,7 @@ impl<'a> DriveQuery<'a> {
         } else {
             GroveDb::verify_query(proof, &path_query)?
         };
-
-        let documents = proved_key_values
-            .into_iter()
-            .filter_map(|(_path, _key, element)| element)
-            .map(|element| element.into_item_bytes().map_err(Error::GroveDB))
-            .collect::<Result<Vec<Vec<u8>>, Error>>()?;
-        Ok((root_hash, documents))
+        Ok((root_hash, path_query, proved_key_values))
     }
 
     /// Verifies a proof for a collection of documents.
@@ -84,6 +250,126 @@ impl<'a> DriveQuery<'a> {
             })?
     }
 
+    /// Verifies a proof for a collection of documents and returns them by document id.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - A byte slice representing the proof to be verified.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the root hash and the deserialized `Document`s by their id.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an `Error` variant if:
+    /// 1. The proof verification fails.
+    /// 2. There is a deserialization error when parsing the serialized document(s) into `Document` struct(s).
+    /// 3. The proof holds two documents with the same id.
+    pub fn verify_proof_map(
+        &self,
+        proof: &[u8],
+    ) -> Result<(RootHash, BTreeMap<Identifier, Document>), Error> {
+        let (root_hash, documents) = self.verify_proof(proof)?;
+        let mut documents_by_id = BTreeMap::new();
+        for document in documents {
+            if documents_by_id.insert(document.id, document).is_some() {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we got back two documents with the same id",
+                )));
+            }
+        }
+        Ok((root_hash, documents_by_id))
+    }
+
+    /// Verifies a proof for a collection of documents and checks that every proved document
+    /// conforms to the properties of the query's document type.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - A byte slice representing the proof to be verified.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the root hash and the deserialized `Document`s if the proof is
+    /// valid and all documents conform to their document type.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an `Error` variant if:
+    /// 1. The proof verification fails.
+    /// 2. There is a deserialization error when parsing the serialized document(s) into `Document` struct(s).
+    /// 3. A document does not conform to the document type, the error names the document id and field.
+    pub fn verify_proof_validated(&self, proof: &[u8]) -> Result<(RootHash, Vec<Document>), Error> {
+        let (root_hash, documents) = self.verify_proof(proof)?;
+        for document in documents.iter() {
+            self.document_type
+                .validate_document(document)
+                .map_err(|error| match error {
+                    ProtocolError::DataContractError(
+                        DataContractError::InvalidDocumentProperty { field, reason },
+                    ) => Error::Proof(ProofError::InvalidDocument {
+                        document_id: document.id.to_buffer(),
+                        field,
+                        reason,
+                    }),
+                    error => Error::Protocol(error),
+                })?;
+        }
+        Ok((root_hash, documents))
+    }
+
+    /// Verifies a proof for a collection of documents against a root hash the caller
+    /// already trusts, for example one taken from a verified block header.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - A byte slice representing the proof to be verified.
+    /// * `expected_root` - The trusted root hash the proof must resolve to.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the deserialized `Document`s if the proof is valid and resolves
+    /// to `expected_root`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an `Error` variant if:
+    /// 1. The proof verification fails.
+    /// 2. The computed root hash does not match `expected_root`.
+    /// 3. There is a deserialization error when parsing the serialized document(s) into `Document` struct(s).
+    pub fn verify_proof_with_expected_root(
+        &self,
+        proof: &[u8],
+        expected_root: RootHash,
+    ) -> Result<Vec<Document>, Error> {
+        let (root_hash, documents) = self.verify_proof(proof)?;
+        verify_expected_root_hash(expected_root, root_hash)?;
+        Ok(documents)
+    }
+
+    /// Verifies a proof for a collection of documents against a trusted root hash
+    /// while keeping the documents serialized.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - A byte slice representing the proof to be verified.
+    /// * `expected_root` - The trusted root hash the proof must resolve to.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an `Error` if the proof verification fails
+    /// or if the computed root hash does not match `expected_root`.
+    pub fn verify_proof_keep_serialized_with_expected_root(
+        &self,
+        proof: &[u8],
+        expected_root: RootHash,
+    ) -> Result<Vec<Vec<u8>>, Error> {
+        let (root_hash, documents) = self.verify_proof_keep_serialized(proof)?;
+        verify_expected_root_hash(expected_root, root_hash)?;
+        Ok(documents)
+    }
+
     /// Verifies if a document exists at the beginning of a proof,
     /// and returns the root hash and the optionally found document.
     ///
@@ -152,3 +438,57 @@ impl<'a> DriveQuery<'a> {
         }
     }
 }
+
+#[cfg(feature = "full")]
+#[cfg(test)]
+mod tests {
+    use crate::drive::document::tests::setup_dashpay;
+    use crate::drive::flags::StorageFlags;
+    use crate::drive::object_size_info::DocumentInfo::DocumentRefInfo;
+    use crate::drive::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+    use crate::query::DriveQuery;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::data_contract::document_type::random_document::CreateRandomDocument;
+
+    #[test]
+    fn should_verify_documents_with_required_timestamps_validated() {
+        let (drive, dashpay) = setup_dashpay("verify_proof_validated", false);
+        let document_type = dashpay
+            .document_type_for_name("profile")
+            .expect("expected to get document type");
+        assert!(document_type.required_fields.contains("$createdAt"));
+        assert!(document_type.required_fields.contains("$updatedAt"));
+
+        let document = document_type.random_document(Some(3));
+        drive
+            .add_document_for_contract(
+                DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentRefInfo((
+                            &document,
+                            StorageFlags::optional_default_as_cow(),
+                        )),
+                        owner_id: None,
+                    },
+                    contract: &dashpay,
+                    document_type,
+                },
+                false,
+                BlockInfo::default(),
+                true,
+                None,
+            )
+            .expect("expected to insert a document successfully");
+
+        let query = DriveQuery::any_item_query(&dashpay, document_type);
+        let (proof, _) = query
+            .clone()
+            .execute_with_proof(&drive, None, None)
+            .expect("expected to prove the query");
+        let (_, documents) = query
+            .verify_proof_validated(&proof)
+            .expect("expected the proved document to conform to the document type");
+
+        assert_eq!(documents, vec![document]);
+    }
+}
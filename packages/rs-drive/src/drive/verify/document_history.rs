@@ -0,0 +1,205 @@
+use crate::drive::document::contract_documents_primary_key_path;
+use crate::drive::verify::RootHash;
+use crate::drive::Drive;
+use crate::error::proof::ProofError;
+use crate::error::query::QuerySyntaxError;
+use crate::error::Error;
+use dpp::data_contract::document_type::DocumentType;
+use dpp::document::Document;
+use dpp::prelude::Revision;
+use grovedb::{GroveDb, PathQuery, Query, SizedQuery};
+use std::collections::BTreeMap;
+
+impl Drive {
+    /// The query for the revisions of a document of a document type keeping history, newest
+    /// first.
+    ///
+    /// Every revision is stored in the tree of the document under the time it was stored at,
+    /// next to a reference to the latest revision under the key `0`, which is not queried.
+    pub fn document_history_query(
+        contract_id: [u8; 32],
+        document_type_name: &str,
+        document_id: [u8; 32],
+        limit: Option<u16>,
+    ) -> PathQuery {
+        let mut path = contract_documents_primary_key_path(&contract_id, document_type_name)
+            .into_iter()
+            .map(|key| key.to_vec())
+            .collect::<Vec<Vec<u8>>>();
+        path.push(document_id.to_vec());
+        let mut query = Query::new_with_direction(false);
+        query.insert_range_after(vec![0]..);
+        PathQuery::new(path, SizedQuery::new(query, limit, None))
+    }
+
+    /// Verifies the revisions of a document of a document type keeping history.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `contract_id`: The id of the data contract of the document type.
+    /// - `document_type`: The document type, which must keep history.
+    /// - `document_id`: The id of the document.
+    /// - `limit`: The maximum number of revisions that were requested, the newest ones.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and the proven revisions of the document
+    /// by revision number. The map is empty if the document does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The document type does not keep history.
+    /// - The proof is corrupted or holds an element outside of the document tree.
+    /// - A revision can not be deserialized, is not of the document or has no revision number.
+    pub fn verify_document_history(
+        proof: &[u8],
+        contract_id: [u8; 32],
+        document_type: &DocumentType,
+        document_id: [u8; 32],
+        limit: Option<u16>,
+    ) -> Result<(RootHash, BTreeMap<Revision, Document>), Error> {
+        if !document_type.documents_keep_history {
+            return Err(Error::Query(QuerySyntaxError::InvalidParameter(format!(
+                "documents of document type {} do not keep history",
+                document_type.name
+            ))));
+        }
+        let path_query =
+            Self::document_history_query(contract_id, &document_type.name, document_id, limit);
+        let (root_hash, proved_key_values) = GroveDb::verify_query(proof, &path_query)?;
+
+        let mut history = BTreeMap::new();
+        for (path, _key, maybe_element) in proved_key_values {
+            if path != path_query.path {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct path for the document history",
+                )));
+            }
+            let Some(element) = maybe_element else {
+                continue;
+            };
+            let serialized_document = element.into_item_bytes().map_err(Error::GroveDB)?;
+            let document = Document::from_bytes(serialized_document.as_slice(), document_type)?;
+            if document.id.to_buffer() != document_id {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we got back a revision of another document",
+                )));
+            }
+            let revision = document
+                .revision
+                .ok_or(Error::Proof(ProofError::CorruptedProof(
+                    "revisions of documents keeping history must have a revision number",
+                )))?;
+            history.insert(revision, document);
+        }
+        Ok((root_hash, history))
+    }
+}
+
+#[cfg(feature = "full")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::setup_contract;
+    use crate::drive::flags::StorageFlags;
+    use crate::drive::object_size_info::DocumentInfo::DocumentRefInfo;
+    use crate::drive::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::data_contract::document_type::random_document::CreateRandomDocument;
+    use dpp::platform_value::Value;
+
+    #[test]
+    fn should_prove_document_history() {
+        let drive = setup_drive_with_initial_state_structure();
+
+        let contract = setup_contract(
+            &drive,
+            "tests/supporting_files/contract/dashpay/dashpay-contract-with-profile-history.json",
+            None,
+            None,
+        );
+        let document_type = contract
+            .document_type_for_name("profile")
+            .expect("expected to get document type");
+
+        let mut document = document_type.random_document(Some(3));
+        document.revision = Some(1);
+        drive
+            .add_document_for_contract(
+                DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentRefInfo((
+                            &document,
+                            StorageFlags::optional_default_as_cow(),
+                        )),
+                        owner_id: None,
+                    },
+                    contract: &contract,
+                    document_type,
+                },
+                false,
+                BlockInfo::default_with_time(1_000),
+                true,
+                None,
+            )
+            .expect("expected to insert a document successfully");
+
+        let mut updated_document = document.clone();
+        updated_document.revision = Some(2);
+        updated_document.set("publicMessage", Value::Text("updated".to_string()));
+        drive
+            .update_document_for_contract(
+                &updated_document,
+                &contract,
+                document_type,
+                None,
+                BlockInfo::default_with_time(2_000),
+                true,
+                StorageFlags::optional_default_as_cow(),
+                None,
+            )
+            .expect("expected to update a document with history successfully");
+
+        let document_id = document.id.to_buffer();
+        let proof = drive
+            .prove_document_history(contract.id.to_buffer(), "profile", document_id, None, None)
+            .expect("expected to prove the document history");
+        let (_, history) = Drive::verify_document_history(
+            proof.as_slice(),
+            contract.id.to_buffer(),
+            document_type,
+            document_id,
+            None,
+        )
+        .expect("expected to verify the document history");
+
+        assert_eq!(
+            history,
+            BTreeMap::from([(1, document), (2, updated_document)])
+        );
+
+        let proof = drive
+            .prove_document_history(
+                contract.id.to_buffer(),
+                "profile",
+                document_id,
+                Some(1),
+                None,
+            )
+            .expect("expected to prove the document history");
+        let (_, history) = Drive::verify_document_history(
+            proof.as_slice(),
+            contract.id.to_buffer(),
+            document_type,
+            document_id,
+            Some(1),
+        )
+        .expect("expected to verify the document history");
+
+        assert_eq!(history.keys().collect::<Vec<_>>(), vec![&2]);
+    }
+}
@@ -0,0 +1,141 @@
+use crate::drive::verify::RootHash;
+use crate::drive::Drive;
+use crate::error::proof::ProofError;
+use crate::error::Error;
+use crate::query::{DriveQuery, InternalClauses, WhereClause, WhereOperator};
+use dpp::identifier::Identifier;
+use dpp::platform_value::Value;
+use dpp::prelude::DataContract;
+use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
+use std::collections::BTreeMap;
+
+impl Drive {
+    /// Builds the query for the DPNS domain document registered under a normalized label and
+    /// parent domain name, it goes through the unique `parentNameAndLabel` index.
+    ///
+    /// # Parameters
+    ///
+    /// - `contract`: The DPNS data contract.
+    /// - `normalized_label`: The lowercase label of the name, e.g. `bob`.
+    /// - `parent_domain`: The lowercase parent domain name, e.g. `dash`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the contract has no `domain` document type.
+    pub fn dpns_domain_query<'a>(
+        contract: &'a DataContract,
+        normalized_label: &str,
+        parent_domain: &str,
+    ) -> Result<DriveQuery<'a>, Error> {
+        let document_type = contract.document_type_for_name("domain")?;
+        let equal_clause = |field: &str, value: &str| {
+            (
+                field.to_string(),
+                WhereClause {
+                    field: field.to_string(),
+                    operator: WhereOperator::Equal,
+                    value: Value::Text(value.to_string()),
+                },
+            )
+        };
+        Ok(DriveQuery {
+            contract,
+            document_type,
+            internal_clauses: InternalClauses {
+                equal_clauses: BTreeMap::from([
+                    equal_clause("normalizedParentDomainName", parent_domain),
+                    equal_clause("normalizedLabel", normalized_label),
+                ]),
+                ..Default::default()
+            },
+            offset: None,
+            limit: Some(1),
+            order_by: Default::default(),
+            start_at: None,
+            start_at_included: true,
+            block_time_ms: None,
+        })
+    }
+
+    /// Verifies the proof of a DPNS name and returns the identity the name resolves to.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `normalized_label`: The lowercase label of the name, e.g. `bob`.
+    /// - `parent_domain`: The lowercase parent domain name, e.g. `dash`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and `Option<Identifier>`. The identifier is
+    /// the identity the name resolves to, it is `None` if the name is proven not to be
+    /// registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The DPNS system contract can not be loaded.
+    /// - The proof is corrupted or the domain document can not be deserialized.
+    /// - The domain document resolves to no identity.
+    pub fn verify_dpns_username(
+        proof: &[u8],
+        normalized_label: &str,
+        parent_domain: &str,
+    ) -> Result<(RootHash, Option<Identifier>), Error> {
+        let contract = load_system_data_contract(SystemDataContract::DPNS)?;
+        Self::verify_dpns_username_with_contract(proof, &contract, normalized_label, parent_domain)
+    }
+
+    /// Verifies the proof of a DPNS name against an already loaded DPNS contract and returns
+    /// the identity the name resolves to.
+    ///
+    /// The name resolves to the unique identity record of the domain document, or to its
+    /// alias identity record when it is an alias.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `contract`: The DPNS data contract.
+    /// - `normalized_label`: The lowercase label of the name, e.g. `bob`.
+    /// - `parent_domain`: The lowercase parent domain name, e.g. `dash`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and `Option<Identifier>`, `None` if the
+    /// name is proven not to be registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is corrupted or the domain document can not be deserialized.
+    /// - The proof contains more than one domain document.
+    /// - The domain document resolves to no identity.
+    pub fn verify_dpns_username_with_contract(
+        proof: &[u8],
+        contract: &DataContract,
+        normalized_label: &str,
+        parent_domain: &str,
+    ) -> Result<(RootHash, Option<Identifier>), Error> {
+        let query = Self::dpns_domain_query(contract, normalized_label, parent_domain)?;
+        let (root_hash, mut documents) = query.verify_proof(proof)?;
+        let domain = match documents.len() {
+            0 => return Ok((root_hash, None)),
+            1 => documents.remove(0),
+            _ => {
+                return Err(Error::Proof(ProofError::TooManyElements(
+                    "expected at most one domain document for a name",
+                )))
+            }
+        };
+        let identity_id = domain
+            .get("records.dashUniqueIdentityId")
+            .or_else(|| domain.get("records.dashAliasIdentityId"))
+            .ok_or(Error::Proof(ProofError::CorruptedProof(
+                "the domain document does not resolve to an identity",
+            )))?
+            .to_identifier()?;
+        Ok((root_hash, Some(identity_id)))
+    }
+}
@@ -0,0 +1,143 @@
+use crate::drive::verify::RootHash;
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::query::{DriveQuery, InternalClauses, WhereClause};
+use dpp::data_contract::document_type::DocumentType;
+use dpp::prelude::DataContract;
+
+impl Drive {
+    /// Builds the query for the first document of a document type matching where clauses.
+    ///
+    /// At most one document is queried, which is enough to prove whether any document
+    /// matches, and the clauses must follow the properties of an index of the document type.
+    ///
+    /// # Parameters
+    ///
+    /// - `contract`: The data contract of the document type.
+    /// - `document_type`: The document type.
+    /// - `where_clauses`: The where clauses documents must match.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the where clauses are invalid or do not follow the properties of
+    /// an index of the document type.
+    pub fn first_matching_document_query<'a>(
+        contract: &'a DataContract,
+        document_type: &'a DocumentType,
+        where_clauses: &[WhereClause],
+    ) -> Result<DriveQuery<'a>, Error> {
+        let query = DriveQuery {
+            contract,
+            document_type,
+            internal_clauses: InternalClauses::extract_from_clauses(where_clauses.to_vec())?,
+            offset: None,
+            limit: Some(1),
+            order_by: Default::default(),
+            start_at: None,
+            start_at_included: true,
+            block_time_ms: None,
+        };
+        query.validate_clause_order()?;
+        Ok(query)
+    }
+
+    /// Verifies whether no document of a document type matches where clauses.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `contract`: The data contract of the document type.
+    /// - `document_type`: The document type.
+    /// - `where_clauses`: The where clauses that were requested.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and `true` if the proof shows that no
+    /// document matches the where clauses, `false` if one does.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the query can not be built, see
+    /// `Drive::first_matching_document_query`, or if the proof does not verify, see
+    /// `DriveQuery::verify_proof`.
+    pub fn verify_query_empty(
+        proof: &[u8],
+        contract: &DataContract,
+        document_type: &DocumentType,
+        where_clauses: &[WhereClause],
+    ) -> Result<(RootHash, bool), Error> {
+        let (root_hash, documents) =
+            Self::first_matching_document_query(contract, document_type, where_clauses)?
+                .verify_proof(proof)?;
+        Ok((root_hash, documents.is_empty()))
+    }
+}
+
+#[cfg(feature = "full")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drive::document::tests::setup_dashpay;
+    use crate::drive::flags::StorageFlags;
+    use crate::drive::object_size_info::DocumentInfo::DocumentRefInfo;
+    use crate::drive::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+    use crate::query::WhereOperator;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::data_contract::document_type::random_document::CreateRandomDocument;
+    use dpp::platform_value::Value;
+
+    #[test]
+    fn should_verify_that_no_document_matches_a_query() {
+        let (drive, dashpay) = setup_dashpay("empty_query", false);
+        let document_type = dashpay
+            .document_type_for_name("profile")
+            .expect("expected to get document type");
+
+        let document = document_type.random_document(Some(3));
+        drive
+            .add_document_for_contract(
+                DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentRefInfo((
+                            &document,
+                            StorageFlags::optional_default_as_cow(),
+                        )),
+                        owner_id: None,
+                    },
+                    contract: &dashpay,
+                    document_type,
+                },
+                false,
+                BlockInfo::default(),
+                true,
+                None,
+            )
+            .expect("expected to insert a document successfully");
+
+        let owner_clauses = |owner_id: [u8; 32]| {
+            vec![WhereClause {
+                field: "$ownerId".to_string(),
+                operator: WhereOperator::Equal,
+                value: Value::Identifier(owner_id),
+            }]
+        };
+
+        let where_clauses = owner_clauses([7; 32]);
+        let proof = drive
+            .prove_query_empty(dashpay.id.to_buffer(), "profile", &where_clauses, None)
+            .expect("expected to prove the query");
+        let (_, is_empty) =
+            Drive::verify_query_empty(proof.as_slice(), &dashpay, document_type, &where_clauses)
+                .expect("expected to verify the query");
+        assert!(is_empty);
+
+        let where_clauses = owner_clauses(document.owner_id.to_buffer());
+        let proof = drive
+            .prove_query_empty(dashpay.id.to_buffer(), "profile", &where_clauses, None)
+            .expect("expected to prove the query");
+        let (_, is_empty) =
+            Drive::verify_query_empty(proof.as_slice(), &dashpay, document_type, &where_clauses)
+                .expect("expected to verify the query");
+        assert!(!is_empty);
+    }
+}
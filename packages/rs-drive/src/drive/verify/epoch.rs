@@ -0,0 +1,169 @@
+use crate::drive::verify::RootHash;
+use crate::drive::Drive;
+use crate::error::proof::ProofError;
+use crate::error::Error;
+use crate::fee::credits::Credits;
+use crate::fee_pools::epochs::epoch_key_constants::{
+    KEY_POOL_PROCESSING_FEES, KEY_POOL_STORAGE_FEES,
+};
+use crate::fee_pools::epochs::paths::EpochProposers;
+use dpp::block::epoch::{Epoch, EpochIndex};
+use grovedb::{Element, GroveDb, PathQuery, Query, SizedQuery};
+use std::collections::BTreeMap;
+
+/// The fees an epoch collected for distribution to masternodes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FeePools {
+    /// The storage fees distributed in this epoch
+    pub storage_fees: Credits,
+    /// The processing fees collected during this epoch
+    pub processing_fees: Credits,
+}
+
+impl Drive {
+    /// The query for the block counts of all the proposers of an epoch.
+    pub fn epoch_proposers_query(epoch: &Epoch) -> PathQuery {
+        let mut query = Query::new();
+        query.insert_all();
+        PathQuery::new_unsized(epoch.get_proposers_path_vec(), query)
+    }
+
+    /// Verifies the block counts of the proposers of an epoch, as tracked for reward
+    /// distribution.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `epoch_index`: The index of the epoch.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and a `BTreeMap` from the pro tx hash of
+    /// every proposer of the epoch to the number of blocks it proposed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The epoch index is too high.
+    /// - The proof is corrupted.
+    /// - A proposer key is not a pro tx hash or its block count is not an encoded `u64`.
+    pub fn verify_epoch_proposer_block_counts(
+        proof: &[u8],
+        epoch_index: EpochIndex,
+    ) -> Result<(RootHash, BTreeMap<[u8; 32], u64>), Error> {
+        let epoch = Epoch::new(epoch_index)?;
+        let path_query = Self::epoch_proposers_query(&epoch);
+
+        let (root_hash, proved_key_values) = GroveDb::verify_query(proof, &path_query)?;
+
+        let block_counts = proved_key_values
+            .into_iter()
+            .filter_map(|(path, key, maybe_element)| {
+                maybe_element.map(|element| (path, key, element))
+            })
+            .map(|(path, key, element)| {
+                if path != path_query.path {
+                    return Err(Error::Proof(ProofError::CorruptedProof(
+                        "we did not get back an element for the correct path for the epoch proposers",
+                    )));
+                }
+                let pro_tx_hash: [u8; 32] = key.try_into().map_err(|_| {
+                    Error::Proof(ProofError::IncorrectValueSize("pro tx hash should be 32 bytes"))
+                })?;
+                let Element::Item(encoded_block_count, _) = element else {
+                    return Err(Error::Proof(ProofError::CorruptedProof(
+                        "epochs proposer block count must be an item",
+                    )));
+                };
+                let block_count = u64::from_be_bytes(
+                    encoded_block_count.as_slice().try_into().map_err(|_| {
+                        Error::Proof(ProofError::IncorrectValueSize(
+                            "epochs proposer block count must be u64",
+                        ))
+                    })?,
+                );
+                Ok((pro_tx_hash, block_count))
+            })
+            .collect::<Result<BTreeMap<[u8; 32], u64>, Error>>()?;
+
+        Ok((root_hash, block_counts))
+    }
+
+    /// The query for the storage and processing fee pools of an epoch.
+    pub fn epoch_fee_pools_query(epoch: &Epoch) -> PathQuery {
+        let mut query = Query::new();
+        query.insert_key(KEY_POOL_PROCESSING_FEES.to_vec());
+        query.insert_key(KEY_POOL_STORAGE_FEES.to_vec());
+        PathQuery::new(epoch.get_path_vec(), SizedQuery::new(query, Some(2), None))
+    }
+
+    /// Verifies the storage and processing fee pools of an epoch, which are distributed to
+    /// the proposers of the epoch once it ends.
+    ///
+    /// The pools of an epoch are removed once they are paid out, and the processing fee pool
+    /// is only created with the first processing fees, so a pool proven absent holds no
+    /// credits.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `epoch_index`: The index of the epoch.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and the `FeePools` of the epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The epoch index is too high.
+    /// - The proof is corrupted.
+    /// - A pool is not a sum item or holds negative credits.
+    pub fn verify_fee_pools(
+        proof: &[u8],
+        epoch_index: EpochIndex,
+    ) -> Result<(RootHash, FeePools), Error> {
+        let epoch = Epoch::new(epoch_index)?;
+        let path_query = Self::epoch_fee_pools_query(&epoch);
+
+        let (root_hash, proved_key_values) =
+            GroveDb::verify_query_with_absence_proof(proof, &path_query)?;
+
+        let mut fee_pools = FeePools::default();
+        for (path, key, maybe_element) in proved_key_values {
+            if path != path_query.path {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct path for the epoch fee pools",
+                )));
+            }
+            let Some(element) = maybe_element else {
+                continue;
+            };
+            let Element::SumItem(credits, _) = element else {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "epochs fee pools must be sum items",
+                )));
+            };
+            let credits = Credits::try_from(credits).map_err(|_| {
+                Error::Proof(ProofError::CorruptedProof(
+                    "epochs fee pools must not hold negative credits",
+                ))
+            })?;
+            match key.as_slice() {
+                key if key == KEY_POOL_STORAGE_FEES.as_slice() => fee_pools.storage_fees = credits,
+                key if key == KEY_POOL_PROCESSING_FEES.as_slice() => {
+                    fee_pools.processing_fees = credits
+                }
+                _ => {
+                    return Err(Error::Proof(ProofError::CorruptedProof(
+                        "we got back an element that is not an epoch fee pool",
+                    )))
+                }
+            }
+        }
+
+        Ok((root_hash, fee_pools))
+    }
+}
@@ -1,7 +1,12 @@
-use crate::drive::balances::balance_path;
+use crate::drive::balances::{balance_path, token_balances_path_vec};
 use crate::drive::defaults::PROTOCOL_VERSION;
-use crate::drive::identity::IdentityRootStructure::IdentityTreeRevision;
-use crate::drive::identity::{identity_key_tree_path, identity_path};
+use crate::drive::flags::StorageFlags;
+use crate::drive::identity::IdentityRootStructure::{
+    IdentityTreeNegativeCredit, IdentityTreeRevision,
+};
+use crate::drive::identity::{
+    identity_key_tree_path, identity_path, identity_query_keys_tree_path_vec,
+};
 use crate::drive::{unique_key_hashes_tree_path_vec, Drive};
 
 use crate::error::proof::ProofError;
@@ -9,14 +14,37 @@ use crate::error::Error;
 use crate::fee::credits::Credits;
 
 use crate::drive::identity::key::fetch::IdentityKeysRequest;
-use crate::drive::verify::RootHash;
+use crate::drive::verify::{verify_expected_root_hash, RootHash};
+use dpp::block::epoch::EpochIndex;
 use dpp::identifier::Identifier;
-use dpp::identity::{IdentityPublicKey, KeyID, PartialIdentity};
+use dpp::identity::{IdentityPublicKey, KeyID, PartialIdentity, Purpose, SecurityLevel};
+use dpp::prelude::TimestampMillis;
 pub use dpp::prelude::{Identity, Revision};
 use dpp::serialization_traits::PlatformDeserializable;
 use grovedb::GroveDb;
+use integer_encoding::VarInt;
+#[cfg(feature = "parallel-verify")]
+use rayon::prelude::*;
 use std::collections::BTreeMap;
 
+/// Whether an identity can submit a state transition, as proven by a full identity proof
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SubmitReadiness {
+    /// The identity exists
+    pub exists: bool,
+    /// The identity has an enabled key of the required purpose and security level
+    pub has_key: bool,
+    /// The identity has at least the required balance
+    pub has_balance: bool,
+}
+
+impl SubmitReadiness {
+    /// Whether the identity can submit the state transition
+    pub fn is_ready(&self) -> bool {
+        self.exists && self.has_key && self.has_balance
+    }
+}
+
 impl Drive {
     /// Verifies the full identity of a user by their public key hash.
     ///
@@ -122,6 +150,71 @@ impl Drive {
         Ok((root_hash, maybe_identity))
     }
 
+    /// Verifies the full identities of multiple users by their public key hashes,
+    /// verifying the identities in parallel.
+    ///
+    /// This behaves like `verify_full_identities_by_public_key_hashes`: the identity ids
+    /// are verified against the proof first, serially, then the per-identity
+    /// verification and deserialization fans out over the rayon thread pool.
+    /// Results are returned in the same order as `public_key_hashes`.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of authentication from the users.
+    /// - `public_key_hashes`: A reference to a slice of 20-byte arrays, each representing
+    ///    a hash of a public key of a user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof of authentication is not valid.
+    /// - Any of the public key hashes do not correspond to a valid identity ID.
+    /// - Any of the identity IDs do not correspond to a valid full identity.
+    ///
+    #[cfg(feature = "parallel-verify")]
+    pub fn verify_full_identities_by_public_key_hashes_parallel<
+        T: FromIterator<([u8; 20], Option<Identity>)>,
+    >(
+        proof: &[u8],
+        public_key_hashes: &[[u8; 20]],
+    ) -> Result<(RootHash, T), Error> {
+        let (root_hash, identity_ids_by_key_hashes) =
+            Self::verify_identity_ids_by_public_key_hashes::<BTreeMap<_, _>>(
+                proof,
+                true,
+                public_key_hashes,
+            )?;
+        // the proof returns keys in key order, restore the order of the request
+        let identity_ids_in_order = public_key_hashes
+            .iter()
+            .map(|key_hash| {
+                let identity_id = identity_ids_by_key_hashes
+                    .get(key_hash)
+                    .ok_or(Error::Proof(ProofError::IncompleteProof(
+                        "proof did not contain a requested public key hash",
+                    )))?;
+                Ok((*key_hash, *identity_id))
+            })
+            .collect::<Result<Vec<([u8; 20], Option<[u8; 32]>)>, Error>>()?;
+        let maybe_identities = identity_ids_in_order
+            .into_par_iter()
+            .map(|(key_hash, identity_id)| match identity_id {
+                None => Ok((key_hash, None)),
+                Some(identity_id) => {
+                    let identity =
+                        Self::verify_full_identity_by_identity_id(proof, true, identity_id)
+                            .map(|(_, maybe_identity)| maybe_identity)?;
+                    let identity = identity.ok_or(Error::Proof(ProofError::IncompleteProof(
+                        "proof returned an identity id without identity information",
+                    )))?;
+                    Ok((key_hash, Some(identity)))
+                }
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok((root_hash, maybe_identities.into_iter().collect()))
+    }
+
     /// Verifies the full identity of a user by their identity ID.
     ///
     /// # Parameters
@@ -240,6 +333,98 @@ impl Drive {
         Ok((root_hash, maybe_identity))
     }
 
+    /// Verifies the full identity of a user by their identity ID against a root hash the
+    /// caller already trusts, for example one taken from a verified block header.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of authentication from the user.
+    /// - `is_proof_subset`: A boolean indicating whether the proof is a subset.
+    /// - `identity_id`: A 32-byte array representing the identity ID of the user.
+    /// - `expected_root`: The trusted root hash the proof must resolve to.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with an `Option` of `Identity`,
+    /// the full identity of the user if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof does not verify, see `verify_full_identity_by_identity_id`.
+    /// - The computed root hash does not match `expected_root`.
+    ///
+    pub fn verify_full_identity_by_identity_id_with_expected_root(
+        proof: &[u8],
+        is_proof_subset: bool,
+        identity_id: [u8; 32],
+        expected_root: RootHash,
+    ) -> Result<Option<Identity>, Error> {
+        let (root_hash, identity) =
+            Self::verify_full_identity_by_identity_id(proof, is_proof_subset, identity_id)?;
+        verify_expected_root_hash(expected_root, root_hash)?;
+        Ok(identity)
+    }
+
+    /// Verifies the full identity of a user by their identity ID and checks whether it can
+    /// submit a state transition signed with a key of the required purpose and security level
+    /// and costing up to `min_balance`.
+    ///
+    /// A key meets the required security level if it is enabled and at least as secure, master
+    /// keys only meet a master security level requirement.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of the full identity.
+    /// - `identity_id`: A 32-byte array representing the identity ID of the user.
+    /// - `required_purpose`: The purpose of the key the transition is signed with.
+    /// - `required_security_level`: The security level the transition requires.
+    /// - `min_balance`: The balance the identity needs, usually the estimated fee.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// the `SubmitReadiness` of the identity. If the identity does not exist, none of the flags
+    /// are set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the proof does not prove the full identity, see
+    /// `verify_full_identity_by_identity_id`.
+    ///
+    pub fn verify_identity_can_submit(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        required_purpose: Purpose,
+        required_security_level: SecurityLevel,
+        min_balance: Credits,
+    ) -> Result<(RootHash, SubmitReadiness), Error> {
+        let (root_hash, maybe_identity) =
+            Self::verify_full_identity_by_identity_id(proof, false, identity_id)?;
+        let Some(identity) = maybe_identity else {
+            return Ok((root_hash, SubmitReadiness::default()));
+        };
+        let has_key = identity.public_keys.values().any(|key| {
+            let meets_security_level = if required_security_level == SecurityLevel::MASTER {
+                key.security_level == SecurityLevel::MASTER
+            } else {
+                // lower enum representation means higher in security
+                key.security_level != SecurityLevel::MASTER
+                    && key.security_level <= required_security_level
+            };
+            !key.is_disabled() && key.purpose == required_purpose && meets_security_level
+        });
+        Ok((
+            root_hash,
+            SubmitReadiness {
+                exists: true,
+                has_key,
+                has_balance: identity.balance >= min_balance,
+            },
+        ))
+    }
+
     /// Verifies the identity keys of a user by their identity ID.
     ///
     /// # Parameters
@@ -308,6 +493,302 @@ impl Drive {
         Ok((root_hash, maybe_identity))
     }
 
+    /// Verifies whether a public key is the key of an identity with the given key id, so a
+    /// signature made with it can be bound to the identity.
+    ///
+    /// The proof must be of the key and of the identity balance, as built with
+    /// `prove_identity_key_with_balance`. Only the requested key is proven, the balance tells a
+    /// missing identity apart from an identity without the key. Disabled keys are still keys of
+    /// the identity, use `verify_key_disabled_at` to know whether the key can still be used.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of the key and of the identity balance.
+    /// - `identity_id`: A 32-byte array representing the identity ID.
+    /// - `key_id`: The id of the key holding the public key.
+    /// - `public_key`: The data of the public key.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// an `Option<bool>`, `None` if the identity does not exist, otherwise whether its key with
+    /// the key id holds the public key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof of the key or of the identity balance is not valid.
+    /// - The proved key is not in the identity keys tree or does not have the key id.
+    /// - The key and the balance are not proven against the same root hash.
+    ///
+    pub fn verify_identity_owns_key(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        key_id: KeyID,
+        public_key: &[u8],
+    ) -> Result<(RootHash, Option<bool>), Error> {
+        let (root_hash, proved_balances) =
+            GroveDb::verify_subset_query(proof, &Self::identity_balance_query(&identity_id))?;
+        let key_request =
+            IdentityKeysRequest::new_specific_key_query_without_limit(&identity_id, key_id);
+        let (key_root_hash, proved_key_values) =
+            GroveDb::verify_subset_query(proof, &key_request.into_path_query())?;
+        if key_root_hash != root_hash {
+            return Err(Error::Proof(ProofError::CorruptedProof(
+                "the identity key and balance were not proven against the same root hash",
+            )));
+        }
+        if proved_balances.len() > 1 {
+            return Err(Error::Proof(ProofError::TooManyElements(
+                "expected at most one identity balance",
+            )));
+        }
+        let identity_exists = proved_balances
+            .into_iter()
+            .any(|(path, key, maybe_element)| {
+                path == balance_path() && key == identity_id && maybe_element.is_some()
+            });
+        if !identity_exists {
+            return Ok((root_hash, None));
+        }
+        let mut owns_key = false;
+        for (path, key, maybe_element) in proved_key_values {
+            if path != identity_key_tree_path(identity_id.as_slice())
+                || key != key_id.encode_var_vec()
+            {
+                return Err(Error::Proof(ProofError::TooManyElements(
+                    "we got back items that we did not request",
+                )));
+            }
+            let Some(element) = maybe_element else {
+                continue;
+            };
+            let item_bytes = element.into_item_bytes().map_err(Error::GroveDB)?;
+            let identity_key = IdentityPublicKey::deserialize(&item_bytes)?;
+            if identity_key.id != key_id {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "the proved identity key does not have the requested key id",
+                )));
+            }
+            owns_key = identity_key.data.as_slice() == public_key;
+        }
+        Ok((root_hash, Some(owns_key)))
+    }
+
+    /// Verifies the keys of an identity that have one of the given purposes.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of authentication from the user.
+    /// - `is_proof_subset`: A boolean indicating whether the proof is a subset.
+    /// - `identity_id`: A 32-byte array representing the identity ID of the user.
+    /// - `purposes`: The purposes of the keys that were requested.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// the proved keys by key id. Keys of purposes that were not requested are never returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof of authentication is not valid.
+    /// - A proved key is not in the identity's key references or has a purpose that was not
+    ///   requested.
+    ///
+    pub fn verify_identity_keys_by_purpose(
+        proof: &[u8],
+        is_proof_subset: bool,
+        identity_id: [u8; 32],
+        purposes: &[Purpose],
+    ) -> Result<(RootHash, BTreeMap<KeyID, IdentityPublicKey>), Error> {
+        let key_request =
+            IdentityKeysRequest::new_all_keys_of_purposes_query(&identity_id, purposes);
+        let path_query = key_request.into_path_query();
+        let (root_hash, proved_key_values) = if is_proof_subset {
+            GroveDb::verify_subset_query(proof, &path_query)?
+        } else {
+            GroveDb::verify_query(proof, &path_query)?
+        };
+        let mut keys = BTreeMap::<KeyID, IdentityPublicKey>::new();
+        let identity_key_references_path = identity_query_keys_tree_path_vec(identity_id);
+        for (path, _key, maybe_element) in proved_key_values {
+            if !path.starts_with(&identity_key_references_path) {
+                return Err(Error::Proof(ProofError::TooManyElements(
+                    "we got back items that we did not request",
+                )));
+            }
+            let Some(element) = maybe_element else {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we received an absence proof for a key but didn't request one",
+                )));
+            };
+            let item_bytes = element.into_item_bytes().map_err(Error::GroveDB)?;
+            let key = IdentityPublicKey::deserialize(&item_bytes)?;
+            if !purposes.contains(&key.purpose) {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we got back a key with a purpose we did not request",
+                )));
+            }
+            keys.insert(key.id, key);
+        }
+        Ok((root_hash, keys))
+    }
+
+    /// Verifies whether a single key of an identity is disabled, and if so since when.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of authentication from the user.
+    /// - `identity_id`: A 32-byte array representing the identity ID of the user.
+    /// - `key_id`: The id of the key we want to check.
+    /// - `is_proof_subset`: A boolean indicating whether the proof is a subset.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// an `Option<Option<TimestampMillis>>`. The outer `Option` is `None` if the key does not
+    /// exist on the identity, the inner `Option` is `None` if the key exists and is enabled,
+    /// otherwise it contains the time at which the key was disabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof of authentication is not valid.
+    /// - The proved key value is not for the correct path or key in the identity keys tree.
+    /// - The proved key does not have the requested key id.
+    /// - More than one key is found.
+    ///
+    pub fn verify_key_disabled_at(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        key_id: KeyID,
+        is_proof_subset: bool,
+    ) -> Result<(RootHash, Option<Option<TimestampMillis>>), Error> {
+        let key_request = IdentityKeysRequest::new_specific_key_query(&identity_id, key_id);
+        let path_query = key_request.into_path_query();
+        let (root_hash, mut proved_key_values) = if is_proof_subset {
+            GroveDb::verify_subset_query_with_absence_proof(proof, &path_query)?
+        } else {
+            GroveDb::verify_query_with_absence_proof(proof, &path_query)?
+        };
+        if proved_key_values.len() == 1 {
+            let (path, key, maybe_element) = proved_key_values.remove(0);
+            if path != identity_key_tree_path(identity_id.as_slice()) {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct path in the identity keys tree",
+                )));
+            }
+            if key != key_id.encode_var_vec() {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct key in the identity keys tree",
+                )));
+            }
+            let maybe_disabled_at = maybe_element
+                .map(|element| {
+                    let item_bytes = element.into_item_bytes().map_err(Error::GroveDB)?;
+                    let public_key = IdentityPublicKey::deserialize(&item_bytes)?;
+                    if public_key.id != key_id {
+                        return Err(Error::Proof(ProofError::CorruptedProof(
+                            "the proved identity key does not have the requested key id",
+                        )));
+                    }
+                    Ok(public_key.disabled_at)
+                })
+                .transpose()?;
+            Ok((root_hash, maybe_disabled_at))
+        } else {
+            Err(Error::Proof(ProofError::TooManyElements(
+                "expected one identity key",
+            )))
+        }
+    }
+
+    /// Verifies specific keys of an identity by their key ids.
+    ///
+    /// The proof must be of the requested key ids only, as built with
+    /// `IdentityKeysRequest::new_specific_keys_query` from the same key ids, without duplicates.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of the identity keys.
+    /// - `identity_id`: A 32-byte array representing the identity ID.
+    /// - `key_ids`: The ids of the keys that were requested.
+    /// - `is_proof_subset`: A boolean indicating whether the proof is a subset.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// every requested key id mapped to its key, or to `None` if the identity has no key with
+    /// that id.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof of authentication is not valid.
+    /// - A proved key value is not in the identity keys tree or is for a key id that was not
+    ///   requested.
+    /// - A requested key id is missing from the proof.
+    /// - A proved key does not have the key id it is stored under.
+    ///
+    pub fn verify_identity_keys_by_ids(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        key_ids: &[KeyID],
+        is_proof_subset: bool,
+    ) -> Result<(RootHash, BTreeMap<KeyID, Option<IdentityPublicKey>>), Error> {
+        let requested_key_ids = key_ids
+            .iter()
+            .map(|key_id| (key_id.encode_var_vec(), *key_id))
+            .collect::<BTreeMap<Vec<u8>, KeyID>>();
+        let key_request = IdentityKeysRequest::new_specific_keys_query(
+            &identity_id,
+            requested_key_ids.values().copied().collect(),
+        );
+        let path_query = key_request.into_path_query();
+        let (root_hash, proved_key_values) = if is_proof_subset {
+            GroveDb::verify_subset_query_with_absence_proof(proof, &path_query)?
+        } else {
+            GroveDb::verify_query_with_absence_proof(proof, &path_query)?
+        };
+        let mut keys = BTreeMap::<KeyID, Option<IdentityPublicKey>>::new();
+        for (path, key, maybe_element) in proved_key_values {
+            if path != identity_key_tree_path(identity_id.as_slice()) {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct path in the identity keys tree",
+                )));
+            }
+            let Some(key_id) = requested_key_ids.get(&key).copied() else {
+                return Err(Error::Proof(ProofError::TooManyElements(
+                    "we got back a key id that we did not request",
+                )));
+            };
+            let maybe_public_key = maybe_element
+                .map(|element| {
+                    let item_bytes = element.into_item_bytes().map_err(Error::GroveDB)?;
+                    let public_key = IdentityPublicKey::deserialize(&item_bytes)?;
+                    if public_key.id != key_id {
+                        return Err(Error::Proof(ProofError::CorruptedProof(
+                            "the proved identity key does not have the requested key id",
+                        )));
+                    }
+                    Ok(public_key)
+                })
+                .transpose()?;
+            keys.insert(key_id, maybe_public_key);
+        }
+        if keys.len() != requested_key_ids.len() {
+            return Err(Error::Proof(ProofError::IncompleteProof(
+                "proof did not contain a requested key id",
+            )));
+        }
+        Ok((root_hash, keys))
+    }
+
     /// Verifies the identity ID of a user by their public key hash.
     ///
     /// # Parameters
@@ -444,6 +925,292 @@ impl Drive {
         }
     }
 
+    /// Verifies the platform payout balance of a masternode.
+    ///
+    /// Masternode rewards are paid out to the masternode owner identity, whose identity ID is
+    /// the masternode's pro tx hash, so this verifies the balance of that identity.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of the balance.
+    /// - `pro_tx_hash`: The pro tx hash of the masternode.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// an `Option<Credits>`, the balance of the masternode owner identity if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the proof does not prove the balance of the masternode owner
+    /// identity, see `verify_identity_balance_for_identity_id`.
+    ///
+    pub fn verify_masternode_reward_balance(
+        proof: &[u8],
+        pro_tx_hash: [u8; 32],
+    ) -> Result<(RootHash, Option<Credits>), Error> {
+        Self::verify_identity_balance_for_identity_id(proof, pro_tx_hash, false)
+    }
+
+    /// Verifies the balance of an identity in two proofs taken at different heights and
+    /// returns the change of the balance from the first to the second.
+    ///
+    /// Each proof is verified on its own against its own root hash. An identity that is not
+    /// proven to have a balance is counted as having a balance of 0, so the delta of an
+    /// identity created between the two proofs is its whole balance.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof_before`: A byte slice representing the proof of the earlier balance.
+    /// - `proof_after`: A byte slice representing the proof of the later balance.
+    /// - `identity_id`: A 32-byte array representing the identity ID.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of the `RootHash`
+    /// of each proof and the signed change of the balance, negative if the balance decreased.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if either proof does not prove the balance of the identity, see
+    /// `verify_identity_balance_for_identity_id`, or if the change does not fit an `i64`.
+    ///
+    pub fn verify_balance_delta(
+        proof_before: &[u8],
+        proof_after: &[u8],
+        identity_id: [u8; 32],
+    ) -> Result<(RootHash, RootHash, i64), Error> {
+        let (root_hash_before, balance_before) =
+            Self::verify_identity_balance_for_identity_id(proof_before, identity_id, false)?;
+        let (root_hash_after, balance_after) =
+            Self::verify_identity_balance_for_identity_id(proof_after, identity_id, false)?;
+        let to_signed = |balance: Option<Credits>| {
+            i64::try_from(balance.unwrap_or_default())
+                .map_err(|_| Error::Proof(ProofError::Overflow("balance does not fit an i64")))
+        };
+        let delta = to_signed(balance_after)?
+            .checked_sub(to_signed(balance_before)?)
+            .ok_or(Error::Proof(ProofError::Overflow(
+                "balance change does not fit an i64",
+            )))?;
+        Ok((root_hash_before, root_hash_after, delta))
+    }
+
+    /// Verifies the balance an identity holds of a token.
+    ///
+    /// Token balances are stored in a subtree per token under the token balances root tree,
+    /// keyed by identity id.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of authentication from the user.
+    /// - `identity_id`: A 32-byte array representing the identity ID of the user.
+    /// - `token_id`: A 32-byte array representing the ID of the token.
+    /// - `verify_subset_of_proof`: A boolean indicating whether we are verifying a subset of a larger proof.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// an `Option<u64>`. The `Option<u64>` is the token balance of the identity, `None` if the
+    /// identity holds none of the token.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof of authentication is not valid.
+    /// - The proved key value is not for the correct path or key in the token balances.
+    /// - More than one token balance is found.
+    ///
+    pub fn verify_identity_token_balance(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        token_id: [u8; 32],
+        verify_subset_of_proof: bool,
+    ) -> Result<(RootHash, Option<u64>), Error> {
+        let path_query = Self::identity_token_balance_query(token_id, identity_id);
+        let (root_hash, mut proved_key_values) = if verify_subset_of_proof {
+            GroveDb::verify_subset_query_with_absence_proof(proof, &path_query)?
+        } else {
+            GroveDb::verify_query_with_absence_proof(proof, &path_query)?
+        };
+        if proved_key_values.len() == 1 {
+            let (path, key, maybe_element) = &proved_key_values.remove(0);
+            if path != &token_balances_path_vec(token_id) {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct path in token balances",
+                )));
+            }
+            if key != &identity_id {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct key in token balances",
+                )));
+            }
+
+            let balance = maybe_element
+                .as_ref()
+                .map(|element| {
+                    element
+                        .as_sum_item_value()
+                        .map_err(Error::GroveDB)?
+                        .try_into()
+                        .map_err(|_| {
+                            Error::Proof(ProofError::IncorrectValueSize("value size is incorrect"))
+                        })
+                })
+                .transpose()?;
+            Ok((root_hash, balance))
+        } else {
+            Err(Error::Proof(ProofError::TooManyElements(
+                "expected one identity token balance",
+            )))
+        }
+    }
+
+    /// Verifies the negative credit (owed processing fees) of an identity by its identity ID.
+    ///
+    /// An identity goes into processing fee debt when it could not pay for an operation.
+    /// The owed amount is stored under the identity's negative credit subtree.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of authentication from the user.
+    /// - `identity_id`: A 32-byte array representing the identity ID of the user.
+    /// - `verify_subset_of_proof`: A boolean indicating whether we are verifying a subset of a larger proof.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// an `Option<Credits>`. The `RootHash` represents the root hash of GroveDB, and the
+    /// `Option<Credits>` represents the owed amount, `None` if the identity has no debt.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof of authentication is not valid.
+    /// - The proved key value is not for the correct path or key in the identity tree.
+    /// - The negative credit is not an 8 byte item.
+    /// - More than one negative credit is found.
+    ///
+    pub fn verify_identity_negative_credit(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        verify_subset_of_proof: bool,
+    ) -> Result<(RootHash, Option<Credits>), Error> {
+        let path_query = Self::identity_negative_credit_query(&identity_id);
+        let (root_hash, mut proved_key_values) = if verify_subset_of_proof {
+            GroveDb::verify_subset_query_with_absence_proof(proof, &path_query)?
+        } else {
+            GroveDb::verify_query_with_absence_proof(proof, &path_query)?
+        };
+        if proved_key_values.len() == 1 {
+            let (path, key, maybe_element) = proved_key_values.remove(0);
+            if path != identity_path(identity_id.as_slice()) {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct path in the identity tree",
+                )));
+            }
+            if key != vec![IdentityTreeNegativeCredit as u8] {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct key for the negative credit",
+                )));
+            }
+
+            let negative_credit = maybe_element
+                .map(|element| {
+                    let item_bytes = element.into_item_bytes().map_err(Error::GroveDB)?;
+                    Ok::<Credits, Error>(Credits::from_be_bytes(item_bytes.try_into().map_err(
+                        |_| {
+                            Error::Proof(ProofError::IncorrectValueSize(
+                                "negative credit should be 8 bytes",
+                            ))
+                        },
+                    )?))
+                })
+                .transpose()?
+                .filter(|negative_credit| *negative_credit > 0);
+            Ok((root_hash, negative_credit))
+        } else {
+            Err(Error::Proof(ProofError::TooManyElements(
+                "expected one identity negative credit",
+            )))
+        }
+    }
+
+    /// Verifies the epoch an identity was created in.
+    ///
+    /// The block height and time an identity was created at are not stored by platform, only
+    /// the epoch is: the identity tree is inserted with storage flags whose base epoch is the
+    /// creation epoch, and it is kept when the flags are merged on later updates. Clients that
+    /// need a creation height or time can not get it from a proof.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of the identity tree.
+    /// - `identity_id`: A 32-byte array representing the identity ID.
+    /// - `verify_subset_of_proof`: A boolean indicating whether we are verifying a subset of a larger proof.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// an `Option<EpochIndex>`, the creation epoch of the identity if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof of authentication is not valid.
+    /// - The proved key value is not for the correct path or key in the identities tree.
+    /// - The identity tree holds no storage flags or they can not be deserialized.
+    /// - More than one identity tree is found.
+    ///
+    pub fn verify_identity_created_in_epoch(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        verify_subset_of_proof: bool,
+    ) -> Result<(RootHash, Option<EpochIndex>), Error> {
+        let path_query = Self::identity_tree_element_query(&identity_id);
+        let (root_hash, mut proved_key_values) = if verify_subset_of_proof {
+            GroveDb::verify_subset_query_with_absence_proof(proof, &path_query)?
+        } else {
+            GroveDb::verify_query_with_absence_proof(proof, &path_query)?
+        };
+        if proved_key_values.len() == 1 {
+            let (path, key, maybe_element) = proved_key_values.remove(0);
+            if path != path_query.path {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct path in the identities tree",
+                )));
+            }
+            if key != identity_id {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct key in the identities tree",
+                )));
+            }
+
+            let created_in_epoch = maybe_element
+                .map(|element| {
+                    let storage_flags = element
+                        .get_flags()
+                        .as_deref()
+                        .map(StorageFlags::from_slice)
+                        .transpose()?
+                        .flatten()
+                        .ok_or(Error::Proof(ProofError::CorruptedProof(
+                            "the identity tree should have storage flags",
+                        )))?;
+                    Ok::<EpochIndex, Error>(*storage_flags.base_epoch())
+                })
+                .transpose()?;
+            Ok((root_hash, created_in_epoch))
+        } else {
+            Err(Error::Proof(ProofError::TooManyElements(
+                "expected one identity tree",
+            )))
+        }
+    }
+
     /// Verifies the balances of multiple identities by their identity IDs.
     ///
     /// `is_proof_subset` is used to indicate if we want to verify a subset of a bigger proof.
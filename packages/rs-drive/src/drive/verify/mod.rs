@@ -1,11 +1,56 @@
+use crate::error::proof::ProofError;
+use crate::error::Error;
+
+/// Asset lock verification methods on proofs
+pub mod asset_lock;
 /// Contract verification methods on proofs
 pub mod contract;
+/// Documents created within a time range verification methods on proofs
+pub mod created_between;
 /// Document verification methods on proofs
 pub mod document;
+/// Document history verification methods on proofs
+pub mod document_history;
+/// DPNS name verification methods on proofs
+pub mod dpns;
+/// Verification methods on proofs that no document matches a query
+pub mod empty_query;
+/// Epoch verification methods on proofs
+pub mod epoch;
 /// Identity verification methods on proofs
 pub mod identity;
+/// Verification methods on proofs of documents of several document types
+pub mod multi_type_documents;
+/// Verification methods on proofs of the documents an identity owns
+pub mod owner_documents;
+/// Limits on how many proofs are verified at the same time
+pub mod pool;
+/// Prefix query verification methods on proofs
+pub mod prefix;
+/// Serializable wrappers for verification results
+pub mod result;
 /// Single Document verification methods on proofs
 pub mod single_document;
+/// System verification methods on proofs
+pub mod system;
+/// Unique index verification methods on proofs
+pub mod unique_index;
+/// Withdrawal verification methods on proofs
+pub mod withdrawal;
 
 /// Represents the root hash of the grovedb tree
 pub type RootHash = [u8; 32];
+
+/// Checks that the root hash computed from a proof matches the root hash the caller trusts.
+pub(crate) fn verify_expected_root_hash(
+    expected_root: RootHash,
+    root_hash: RootHash,
+) -> Result<(), Error> {
+    if root_hash != expected_root {
+        return Err(Error::Proof(ProofError::IncorrectRootHash {
+            expected: expected_root,
+            actual: root_hash,
+        }));
+    }
+    Ok(())
+}
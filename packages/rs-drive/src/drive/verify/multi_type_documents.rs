@@ -0,0 +1,205 @@
+use crate::drive::document::contract_document_type_path_vec;
+use crate::drive::verify::RootHash;
+use crate::drive::Drive;
+use crate::error::proof::ProofError;
+use crate::error::query::QuerySyntaxError;
+use crate::error::Error;
+use crate::query::SingleDocumentDriveQuery;
+use dpp::data_contract::document_type::DocumentType;
+use dpp::document::Document;
+use grovedb::{GroveDb, PathQuery};
+use std::collections::BTreeMap;
+
+impl Drive {
+    /// Verifies a proof of documents of several document types, as built by `prove_multiple`
+    /// from single document queries, and groups the proven documents by contract id and
+    /// document type name.
+    ///
+    /// Every document is deserialized against the document type declared for the primary key
+    /// tree it was proven in. The path queries are merged without a limit, as `prove_multiple`
+    /// does, so for a document type keeping history only the latest proven revision of each
+    /// document is returned.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `document_queries`: The single document queries the proof was built from.
+    /// - `document_types`: The contract id and document type of every queried document type.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and the proven documents by contract id and
+    /// document type name. Documents proven not to exist are not returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - No document is queried, or a query is on a document type that is not declared.
+    /// - The proof is corrupted or holds a document of a document type that is not declared.
+    /// - A document can not be deserialized against its document type or was not queried.
+    pub fn verify_multi_type_documents_proof(
+        proof: &[u8],
+        document_queries: &[SingleDocumentDriveQuery],
+        document_types: &[([u8; 32], &DocumentType)],
+    ) -> Result<(RootHash, BTreeMap<([u8; 32], String), Vec<Document>>), Error> {
+        if document_queries.is_empty() {
+            return Err(Error::Query(QuerySyntaxError::NoQueryItems(
+                "we are asking to verify no documents",
+            )));
+        }
+        // the primary key tree path of every declared document type
+        let primary_key_paths = document_types
+            .iter()
+            .map(|(contract_id, document_type)| {
+                let mut path = contract_document_type_path_vec(contract_id, &document_type.name);
+                path.push(vec![0]);
+                (path, (*contract_id, *document_type))
+            })
+            .collect::<Vec<_>>();
+        for query in document_queries {
+            if !document_types.iter().any(|(contract_id, document_type)| {
+                *contract_id == query.contract_id && document_type.name == query.document_type_name
+            }) {
+                return Err(Error::Query(QuerySyntaxError::InvalidParameter(format!(
+                    "document type {} is queried but not declared",
+                    query.document_type_name
+                ))));
+            }
+        }
+        // limited path queries can not be merged
+        let path_queries = if document_queries.len() == 1 {
+            vec![document_queries[0].construct_path_query()]
+        } else {
+            document_queries
+                .iter()
+                .map(|query| query.construct_path_query_without_limit())
+                .collect::<Vec<_>>()
+        };
+        let path_query = PathQuery::merge(path_queries.iter().collect()).map_err(Error::GroveDB)?;
+        let (root_hash, proved_key_values) = GroveDb::verify_query(proof, &path_query)?;
+
+        let mut documents = BTreeMap::<([u8; 32], String), Vec<Document>>::new();
+        for (path, _key, maybe_element) in proved_key_values {
+            let Some(element) = maybe_element else {
+                continue;
+            };
+            // documents of document types keeping history are proven under their own tree
+            let (contract_id, document_type) = primary_key_paths
+                .iter()
+                .find(|(primary_key_path, _)| path.starts_with(primary_key_path))
+                .map(|(_, declared)| *declared)
+                .ok_or(Error::Proof(ProofError::TooManyElements(
+                    "we got back a document of a document type that was not declared",
+                )))?;
+            let serialized_document = element.into_item_bytes().map_err(Error::GroveDB)?;
+            let document = Document::from_bytes(serialized_document.as_slice(), document_type)?;
+            if !document_queries.iter().any(|query| {
+                query.contract_id == contract_id
+                    && query.document_type_name == document_type.name
+                    && query.document_id == document.id.to_buffer()
+            }) {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we got back a document that was not queried",
+                )));
+            }
+            let documents_of_type = documents
+                .entry((contract_id, document_type.name.clone()))
+                .or_default();
+            // revisions of a document keeping history are proven from the latest one
+            if !documents_of_type
+                .iter()
+                .any(|proven_document| proven_document.id == document.id)
+            {
+                documents_of_type.push(document);
+            }
+        }
+        Ok((root_hash, documents))
+    }
+}
+
+#[cfg(feature = "full")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drive::document::tests::setup_dashpay;
+    use crate::drive::flags::StorageFlags;
+    use crate::drive::object_size_info::DocumentInfo::DocumentRefInfo;
+    use crate::drive::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+    use dpp::block::block_info::BlockInfo;
+    use dpp::data_contract::document_type::random_document::CreateRandomDocument;
+
+    #[test]
+    fn should_verify_documents_of_several_document_types() {
+        let (drive, dashpay) = setup_dashpay("multi_type_documents", false);
+
+        let mut document_queries = vec![];
+        let mut document_types = vec![];
+        for document_type_name in ["profile", "contactInfo"] {
+            let document_type = dashpay
+                .document_type_for_name(document_type_name)
+                .expect("expected to get document type");
+            let document = document_type.random_document(Some(3));
+            drive
+                .add_document_for_contract(
+                    DocumentAndContractInfo {
+                        owned_document_info: OwnedDocumentInfo {
+                            document_info: DocumentRefInfo((
+                                &document,
+                                StorageFlags::optional_default_as_cow(),
+                            )),
+                            owner_id: None,
+                        },
+                        contract: &dashpay,
+                        document_type,
+                    },
+                    false,
+                    BlockInfo::default(),
+                    true,
+                    None,
+                )
+                .expect("expected to insert a document successfully");
+            document_queries.push(SingleDocumentDriveQuery {
+                contract_id: dashpay.id.to_buffer(),
+                document_type_name: document_type_name.to_string(),
+                document_type_keeps_history: document_type.documents_keep_history,
+                document_id: document.id.to_buffer(),
+                block_time_ms: None,
+            });
+            document_types.push((dashpay.id.to_buffer(), document_type));
+        }
+
+        let proof = drive
+            .prove_multiple(&vec![], &[], &document_queries, None)
+            .expect("expected to prove the documents");
+
+        let (_, documents) = Drive::verify_multi_type_documents_proof(
+            proof.as_slice(),
+            &document_queries,
+            &document_types,
+        )
+        .expect("expected to verify the documents");
+
+        let contract_id = dashpay.id.to_buffer();
+        assert_eq!(documents.len(), 2);
+        assert_eq!(
+            documents
+                .get(&(contract_id, "profile".to_string()))
+                .map(Vec::len),
+            Some(1)
+        );
+        assert_eq!(
+            documents
+                .get(&(contract_id, "contactInfo".to_string()))
+                .map(Vec::len),
+            Some(1)
+        );
+
+        Drive::verify_multi_type_documents_proof(
+            proof.as_slice(),
+            &document_queries,
+            &document_types[..1],
+        )
+        .expect_err("expected an undeclared document type to be rejected");
+    }
+}
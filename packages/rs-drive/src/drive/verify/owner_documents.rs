@@ -0,0 +1,333 @@
+use crate::drive::document::contract_document_type_path_vec;
+use crate::drive::verify::RootHash;
+use crate::drive::Drive;
+use crate::error::proof::ProofError;
+use crate::error::query::QuerySyntaxError;
+use crate::error::Error;
+use crate::query::{DriveQuery, InternalClauses, WhereClause, WhereOperator};
+use dpp::document::Document;
+use dpp::identity::Identity;
+use dpp::platform_value::Value;
+use dpp::prelude::DataContract;
+use grovedb::{GroveDb, PathQuery, Query, SizedQuery};
+use std::collections::BTreeMap;
+
+/// The system property holding the owner of a document
+const OWNER_ID: &str = "$ownerId";
+
+impl Drive {
+    /// The query for the `$ownerId` index tree of an owner in a document type.
+    ///
+    /// The tree only exists while the owner has documents of the document type, as index trees
+    /// are deleted when they become empty.
+    pub fn owner_documents_index_query(
+        contract_id: [u8; 32],
+        document_type_name: &str,
+        owner_id: [u8; 32],
+    ) -> PathQuery {
+        let mut path = contract_document_type_path_vec(&contract_id, document_type_name);
+        path.push(OWNER_ID.as_bytes().to_vec());
+        let mut query = Query::new();
+        query.insert_key(owner_id.to_vec());
+        PathQuery::new(path, SizedQuery::new(query, Some(1), None))
+    }
+
+    /// Verifies whether an identity owns no documents of a document type.
+    ///
+    /// The document type must have an index starting with `$ownerId`.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `contract_id`: The id of the data contract of the document type.
+    /// - `document_type_name`: The name of the document type.
+    /// - `owner_id`: The id of the identity.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and `true` if the proof shows that the
+    /// identity owns no documents of the document type, `false` if it owns some.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the proof is corrupted or is not for the owner's index tree.
+    pub fn verify_identity_has_no_documents(
+        proof: &[u8],
+        contract_id: [u8; 32],
+        document_type_name: &str,
+        owner_id: [u8; 32],
+    ) -> Result<(RootHash, bool), Error> {
+        let path_query =
+            Self::owner_documents_index_query(contract_id, document_type_name, owner_id);
+        let (root_hash, mut proved_key_values) =
+            GroveDb::verify_query_with_absence_proof(proof, &path_query)?;
+        if proved_key_values.len() != 1 {
+            return Err(Error::Proof(ProofError::CorruptedProof(
+                "we should always get back one element for the owner index tree",
+            )));
+        }
+        let (path, key, maybe_element) = proved_key_values.remove(0);
+        if path != path_query.path || key != owner_id.to_vec() {
+            return Err(Error::Proof(ProofError::CorruptedProof(
+                "we did not get back an element for the owner index tree",
+            )));
+        }
+        Ok((root_hash, maybe_element.is_none()))
+    }
+
+    /// Builds the query for the documents of a document type owned by an identity, through an
+    /// index of the document type starting with `$ownerId`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the contract has no such document type, or if the document type
+    /// has no index starting with `$ownerId`.
+    pub fn owner_documents_query<'a>(
+        contract: &'a DataContract,
+        document_type_name: &str,
+        owner_id: [u8; 32],
+        limit: Option<u16>,
+    ) -> Result<DriveQuery<'a>, Error> {
+        let document_type = contract.document_type_for_name(document_type_name)?;
+        if !document_type.indices.iter().any(|index| {
+            index
+                .properties
+                .first()
+                .map_or(false, |property| property.name == OWNER_ID)
+        }) {
+            return Err(Error::Query(
+                QuerySyntaxError::WhereClauseOnNonIndexedProperty(
+                    "the document type has no index starting with $ownerId",
+                ),
+            ));
+        }
+        Ok(DriveQuery {
+            contract,
+            document_type,
+            internal_clauses: InternalClauses {
+                equal_clauses: BTreeMap::from([(
+                    OWNER_ID.to_string(),
+                    WhereClause {
+                        field: OWNER_ID.to_string(),
+                        operator: WhereOperator::Equal,
+                        value: Value::Identifier(owner_id),
+                    },
+                )]),
+                ..Default::default()
+            },
+            offset: None,
+            limit,
+            order_by: Default::default(),
+            start_at: None,
+            start_at_included: true,
+            block_time_ms: None,
+        })
+    }
+
+    /// The query for the full identity and all the documents of a document type it owns, so
+    /// that both are proven together.
+    ///
+    /// The documents part has no limit, as limited path queries can not be merged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the contract has no such document type, or if the document type
+    /// has no index starting with `$ownerId`.
+    pub fn identity_with_documents_query(
+        identity_id: [u8; 32],
+        contract: &DataContract,
+        document_type_name: &str,
+    ) -> Result<PathQuery, Error> {
+        let identity_query = Self::full_identity_query(&identity_id)?;
+        let documents_query =
+            Self::owner_documents_query(contract, document_type_name, identity_id, None)?
+                .construct_path_query(None)?;
+        PathQuery::merge(vec![&identity_query, &documents_query]).map_err(Error::GroveDB)
+    }
+
+    /// Verifies a proof of the full identity and of the documents of a document type it owns.
+    ///
+    /// Both parts are verified as subsets of the proof and must resolve to the same root hash.
+    /// All the documents the identity owns are proven, the limit is applied once they are
+    /// verified.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `identity_id`: The id of the identity.
+    /// - `contract`: The data contract of the document type.
+    /// - `document_type_name`: The name of the document type.
+    /// - `limit`: The maximum number of documents to return.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and the identity, if it exists, with the
+    /// documents it owns.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The document type does not exist or has no index starting with `$ownerId`.
+    /// - The proof does not prove the full identity, see `verify_full_identity_by_identity_id`.
+    /// - The proof is corrupted or a document can not be deserialized.
+    /// - Both parts of the proof do not resolve to the same root hash.
+    pub fn verify_identity_with_documents(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        contract: &DataContract,
+        document_type_name: &str,
+        limit: Option<u16>,
+    ) -> Result<(RootHash, (Option<Identity>, Vec<Document>)), Error> {
+        let (root_hash, identity) =
+            Self::verify_full_identity_by_identity_id(proof, true, identity_id)?;
+        let documents_query =
+            Self::owner_documents_query(contract, document_type_name, identity_id, None)?;
+        let (documents_root_hash, proved_key_values) =
+            GroveDb::verify_subset_query(proof, &documents_query.construct_path_query(None)?)?;
+        if documents_root_hash != root_hash {
+            return Err(Error::Proof(ProofError::CorruptedProof(
+                "the identity and its documents were not proven against the same root hash",
+            )));
+        }
+        let documents = proved_key_values
+            .into_iter()
+            .filter_map(|(_path, _key, element)| element)
+            .take(limit.map_or(usize::MAX, usize::from))
+            .map(|element| {
+                let serialized_document = element.into_item_bytes().map_err(Error::GroveDB)?;
+                Document::from_bytes(
+                    serialized_document.as_slice(),
+                    documents_query.document_type,
+                )
+                .map_err(Error::Protocol)
+            })
+            .collect::<Result<Vec<Document>, Error>>()?;
+        Ok((root_hash, (identity, documents)))
+    }
+}
+
+#[cfg(feature = "full")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drive::document::tests::setup_dashpay;
+    use crate::drive::flags::StorageFlags;
+    use crate::drive::object_size_info::DocumentInfo::DocumentRefInfo;
+    use crate::drive::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+    use dpp::block::block_info::BlockInfo;
+    use dpp::data_contract::document_type::random_document::CreateRandomDocument;
+
+    #[test]
+    fn should_prove_an_identity_has_no_documents() {
+        let (drive, dashpay) = setup_dashpay("owner_documents", false);
+
+        let document_type = dashpay
+            .document_type_for_name("profile")
+            .expect("expected to get document type");
+        let document = document_type.random_document(Some(5));
+        drive
+            .add_document_for_contract(
+                DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentRefInfo((
+                            &document,
+                            StorageFlags::optional_default_as_cow(),
+                        )),
+                        owner_id: None,
+                    },
+                    contract: &dashpay,
+                    document_type,
+                },
+                false,
+                BlockInfo::default(),
+                true,
+                None,
+            )
+            .expect("expected to insert a document successfully");
+
+        let owner_id = document.owner_id.to_buffer();
+        let proof = drive
+            .prove_identity_has_no_documents(dashpay.id.to_buffer(), "profile", owner_id, None)
+            .expect("expected to prove the owner documents");
+        let (_, has_no_documents) = Drive::verify_identity_has_no_documents(
+            proof.as_slice(),
+            dashpay.id.to_buffer(),
+            "profile",
+            owner_id,
+        )
+        .expect("expected to verify the owner documents");
+        assert!(!has_no_documents);
+
+        let proof = drive
+            .prove_identity_has_no_documents(dashpay.id.to_buffer(), "profile", [7; 32], None)
+            .expect("expected to prove the owner documents");
+        let (_, has_no_documents) = Drive::verify_identity_has_no_documents(
+            proof.as_slice(),
+            dashpay.id.to_buffer(),
+            "profile",
+            [7; 32],
+        )
+        .expect("expected to verify the owner documents");
+        assert!(has_no_documents);
+    }
+
+    #[test]
+    fn should_prove_an_identity_with_its_documents() {
+        let (drive, dashpay) = setup_dashpay("identity_with_documents", false);
+
+        let identity = Identity::random_identity(3, Some(14));
+        drive
+            .add_new_identity(identity.clone(), &BlockInfo::default(), true, None)
+            .expect("expected to add an identity");
+
+        let document_type = dashpay
+            .document_type_for_name("profile")
+            .expect("expected to get document type");
+        let mut document = document_type.random_document(Some(5));
+        document.owner_id = identity.id;
+        drive
+            .add_document_for_contract(
+                DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentRefInfo((
+                            &document,
+                            StorageFlags::optional_default_as_cow(),
+                        )),
+                        owner_id: None,
+                    },
+                    contract: &dashpay,
+                    document_type,
+                },
+                false,
+                BlockInfo::default(),
+                true,
+                None,
+            )
+            .expect("expected to insert a document successfully");
+
+        let identity_id = identity.id.to_buffer();
+        let proof = drive
+            .prove_identity_with_documents(identity_id, dashpay.id.to_buffer(), "profile", None)
+            .expect("expected to prove the identity with its documents");
+        let (_, (proved_identity, documents)) = Drive::verify_identity_with_documents(
+            proof.as_slice(),
+            identity_id,
+            &dashpay,
+            "profile",
+            Some(10),
+        )
+        .expect("expected to verify the identity with its documents");
+
+        let proved_identity = proved_identity.expect("expected the identity to be proven");
+        assert_eq!(proved_identity.id, identity.id);
+        assert_eq!(proved_identity.balance, identity.balance);
+        assert_eq!(
+            documents
+                .iter()
+                .map(|document| document.id)
+                .collect::<Vec<_>>(),
+            vec![document.id]
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use crate::error::Error;
+use std::sync::{Condvar, Mutex, MutexGuard};
+
+/// A point in time view of the verifications of a pool
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VerifierPoolMetrics {
+    /// The number of verifications running
+    pub in_flight: usize,
+    /// The number of verifications waiting for a slot
+    pub queue_depth: usize,
+    /// The highest number of verifications that waited for a slot at the same time
+    pub peak_queue_depth: usize,
+    /// The number of verifications that have completed, successfully or not
+    pub completed: u64,
+}
+
+/// Caps how many proofs are verified at the same time.
+///
+/// Verifying a proof holds the proof and the elements it proves in memory, so a service
+/// verifying proofs for many requests at once can run out of memory. Verifications run
+/// through the pool block the calling thread until a slot is free, and are otherwise run
+/// unchanged on that thread.
+#[derive(Debug)]
+pub struct VerifierPool {
+    max_concurrent_verifications: usize,
+    metrics: Mutex<VerifierPoolMetrics>,
+    slot_freed: Condvar,
+}
+
+/// Frees the slot of a verification when it completes, or if it panics
+struct VerifierPoolSlot<'a> {
+    pool: &'a VerifierPool,
+}
+
+impl Drop for VerifierPoolSlot<'_> {
+    fn drop(&mut self) {
+        let mut metrics = self.pool.lock_metrics();
+        metrics.in_flight -= 1;
+        metrics.completed += 1;
+        self.pool.slot_freed.notify_one();
+    }
+}
+
+impl VerifierPool {
+    /// Creates a pool running at most `max_concurrent_verifications` verifications at the same
+    /// time, at least one.
+    pub fn new(max_concurrent_verifications: usize) -> Self {
+        VerifierPool {
+            max_concurrent_verifications: max_concurrent_verifications.max(1),
+            metrics: Mutex::new(VerifierPoolMetrics::default()),
+            slot_freed: Condvar::new(),
+        }
+    }
+
+    /// The most verifications the pool runs at the same time
+    pub fn max_concurrent_verifications(&self) -> usize {
+        self.max_concurrent_verifications
+    }
+
+    /// The current metrics of the pool
+    pub fn metrics(&self) -> VerifierPoolMetrics {
+        *self.lock_metrics()
+    }
+
+    /// Runs a verification once fewer than the maximum number of verifications are running,
+    /// waiting for one to complete otherwise.
+    ///
+    /// ```ignore
+    /// let (root_hash, identity) =
+    ///     pool.verify(|| Drive::verify_full_identity_by_identity_id(proof, false, identity_id))?;
+    /// ```
+    pub fn verify<T>(&self, verification: impl FnOnce() -> Result<T, Error>) -> Result<T, Error> {
+        let _slot = self.acquire_slot();
+        verification()
+    }
+
+    fn acquire_slot(&self) -> VerifierPoolSlot {
+        let mut metrics = self.lock_metrics();
+        if metrics.in_flight >= self.max_concurrent_verifications {
+            metrics.queue_depth += 1;
+            metrics.peak_queue_depth = metrics.peak_queue_depth.max(metrics.queue_depth);
+            while metrics.in_flight >= self.max_concurrent_verifications {
+                metrics = self
+                    .slot_freed
+                    .wait(metrics)
+                    .unwrap_or_else(|poisoned| poisoned.into_inner());
+            }
+            metrics.queue_depth -= 1;
+        }
+        metrics.in_flight += 1;
+        VerifierPoolSlot { pool: self }
+    }
+
+    /// The metrics are only updated while the lock is held and are consistent even if a
+    /// verification panicked, so a poisoned lock is recovered
+    fn lock_metrics(&self) -> MutexGuard<VerifierPoolMetrics> {
+        self.metrics
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::proof::ProofError;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn should_cap_concurrent_verifications() {
+        let pool = Arc::new(VerifierPool::new(2));
+        let running = Arc::new(AtomicUsize::new(0));
+        let most_running = Arc::new(AtomicUsize::new(0));
+
+        let handles = (0..8)
+            .map(|i| {
+                let pool = pool.clone();
+                let running = running.clone();
+                let most_running = most_running.clone();
+                thread::spawn(move || {
+                    pool.verify(|| {
+                        let now_running = running.fetch_add(1, Ordering::SeqCst) + 1;
+                        most_running.fetch_max(now_running, Ordering::SeqCst);
+                        thread::sleep(Duration::from_millis(20));
+                        running.fetch_sub(1, Ordering::SeqCst);
+                        if i % 2 == 0 {
+                            Ok(i)
+                        } else {
+                            Err(Error::Proof(ProofError::CorruptedProof("corrupted")))
+                        }
+                    })
+                    .is_ok()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let verified = handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .expect("expected the verification not to panic")
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(verified.iter().filter(|verified| **verified).count(), 4);
+        assert!(most_running.load(Ordering::SeqCst) <= 2);
+
+        let metrics = pool.metrics();
+        assert_eq!(metrics.in_flight, 0);
+        assert_eq!(metrics.queue_depth, 0);
+        assert_eq!(metrics.completed, 8);
+        assert!(metrics.peak_queue_depth <= 6);
+    }
+}
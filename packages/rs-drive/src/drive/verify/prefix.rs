@@ -0,0 +1,265 @@
+use crate::drive::verify::RootHash;
+use crate::drive::Drive;
+use crate::error::proof::ProofError;
+use crate::error::query::QuerySyntaxError;
+use crate::error::Error;
+use crate::query::{DriveQuery, InternalClauses, OrderClause, WhereClause, WhereOperator};
+use dpp::document::Document;
+use dpp::platform_value::Value;
+use dpp::prelude::DataContract;
+use indexmap::IndexMap;
+
+impl Drive {
+    /// Builds the query for the documents of a document type whose `field` starts with
+    /// `prefix`, in ascending order of the field.
+    ///
+    /// # Parameters
+    ///
+    /// - `contract`: The data contract of the document type.
+    /// - `document_type_name`: The name of the document type.
+    /// - `field`: The string field the prefix applies to, it must start an index.
+    /// - `prefix`: The prefix the field must start with.
+    /// - `limit`: The maximum number of documents returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the contract has no such document type, or if the prefix is empty.
+    pub fn prefix_query<'a>(
+        contract: &'a DataContract,
+        document_type_name: &str,
+        field: &str,
+        prefix: &str,
+        limit: Option<u16>,
+    ) -> Result<DriveQuery<'a>, Error> {
+        let document_type = contract.document_type_for_name(document_type_name)?;
+        if prefix.is_empty() {
+            return Err(Error::Query(QuerySyntaxError::StartsWithIllegalString(
+                "starts with can not start with an empty string",
+            )));
+        }
+        Ok(DriveQuery {
+            contract,
+            document_type,
+            internal_clauses: InternalClauses {
+                range_clause: Some(WhereClause {
+                    field: field.to_string(),
+                    operator: WhereOperator::StartsWith,
+                    value: Value::Text(prefix.to_string()),
+                }),
+                ..Default::default()
+            },
+            offset: None,
+            limit,
+            order_by: IndexMap::from([(
+                field.to_string(),
+                OrderClause {
+                    field: field.to_string(),
+                    ascending: true,
+                },
+            )]),
+            start_at: None,
+            start_at_included: true,
+            block_time_ms: None,
+        })
+    }
+
+    /// Verifies the proof of the documents of a document type whose `field` starts with
+    /// `prefix`, and checks that every proven document does.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `contract`: The data contract of the document type.
+    /// - `document_type_name`: The name of the document type.
+    /// - `field`: The string field the prefix applies to.
+    /// - `prefix`: The prefix that was requested.
+    /// - `limit`: The maximum number of documents that were requested.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and the proven documents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the query can not be built, or if the proof does not verify, see
+    /// `DriveQuery::verify_prefix_proof`.
+    pub fn verify_prefix_query(
+        proof: &[u8],
+        contract: &DataContract,
+        document_type_name: &str,
+        field: &str,
+        prefix: &str,
+        limit: Option<u16>,
+    ) -> Result<(RootHash, Vec<Document>), Error> {
+        Self::prefix_query(contract, document_type_name, field, prefix, limit)?
+            .verify_prefix_proof(proof)
+    }
+}
+
+impl<'a> DriveQuery<'a> {
+    /// Verifies a proof for a query whose range clause is a `startsWith` clause, and checks
+    /// that the field of every proven document starts with the prefix.
+    ///
+    /// This also verifies prefix queries going through an index after equality clauses, like
+    /// DPNS label autocompletion under a parent domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - A byte slice representing the proof to be verified.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an `Error` variant if:
+    /// 1. The query has no `startsWith` range clause.
+    /// 2. The proof verification fails or a document can not be deserialized.
+    /// 3. A proven document's field does not start with the prefix.
+    pub fn verify_prefix_proof(&self, proof: &[u8]) -> Result<(RootHash, Vec<Document>), Error> {
+        let Some(WhereClause {
+            field,
+            operator: WhereOperator::StartsWith,
+            value,
+        }) = &self.internal_clauses.range_clause
+        else {
+            return Err(Error::Query(QuerySyntaxError::InvalidStartsWithClause(
+                "a prefix proof must be for a query with a starts with clause",
+            )));
+        };
+        let prefix = self.document_type.serialize_value_for_key(field, value)?;
+        let (root_hash, documents) = self.verify_proof(proof)?;
+        for document in documents.iter() {
+            let starts_with_prefix = document
+                .get_raw_for_document_type(field, self.document_type, None)?
+                .map_or(false, |raw_value| raw_value.starts_with(&prefix));
+            if !starts_with_prefix {
+                return Err(Error::Proof(ProofError::InvalidDocument {
+                    document_id: document.id.to_buffer(),
+                    field: field.clone(),
+                    reason: "the field does not start with the queried prefix",
+                }));
+            }
+        }
+        Ok((root_hash, documents))
+    }
+}
+
+#[cfg(feature = "full")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drive::flags::StorageFlags;
+    use crate::drive::object_size_info::DocumentInfo::DocumentRefInfo;
+    use crate::drive::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::platform_value::platform_value;
+    use dpp::prelude::Identifier;
+
+    #[test]
+    fn should_prove_documents_with_prefix() {
+        let drive = setup_drive_with_initial_state_structure();
+
+        let contract = DataContract::from_raw_object(platform_value!({
+            "protocolVersion": 1,
+            "$id": "BZUodcFoFL6KvnonehrnMVggTvCe8W5MiRnZuqLb6M54",
+            "$schema": "https://schema.dash.org/dpp-0-4-0/meta/data-contract",
+            "version": 1,
+            "ownerId": "GZVdTnLFAN2yE9rLeCHBDBCr7YQgmXJuoExkY347j7Z5",
+            "documents": {
+                "contact": {
+                    "type": "object",
+                    "indices": [
+                        {"name":"name", "properties": [{"name":"asc"}]},
+                    ],
+                    "properties":{
+                        "name": {
+                            "type": "string",
+                            "maxLength": 63,
+                        },
+                    },
+                    "required": ["name"],
+                    "additionalProperties": false,
+                },
+            },
+        }))
+        .expect("expected to create a contract");
+
+        drive
+            .apply_contract(&contract, BlockInfo::default(), true, None, None)
+            .expect("expected to apply the contract");
+
+        let document_type = contract
+            .document_type_for_name("contact")
+            .expect("expected to get the document type");
+
+        for (i, name) in ["alex", "alice", "bob", "alfred"].into_iter().enumerate() {
+            let document = Document {
+                id: Identifier::new([i as u8 + 1; 32]),
+                owner_id: contract.owner_id,
+                properties: [("name".to_string(), Value::Text(name.to_string()))].into(),
+                revision: None,
+                created_at: None,
+                updated_at: None,
+            };
+            drive
+                .add_document_for_contract(
+                    DocumentAndContractInfo {
+                        owned_document_info: OwnedDocumentInfo {
+                            document_info: DocumentRefInfo((
+                                &document,
+                                StorageFlags::optional_default_as_cow(),
+                            )),
+                            owner_id: None,
+                        },
+                        contract: &contract,
+                        document_type,
+                    },
+                    false,
+                    BlockInfo::default(),
+                    true,
+                    None,
+                )
+                .expect("expected to insert a document");
+        }
+
+        let proof = drive
+            .prove_documents_with_prefix(
+                contract.id.to_buffer(),
+                "contact",
+                "name",
+                "al",
+                Some(10),
+                None,
+            )
+            .expect("expected to prove the documents");
+
+        let (_, documents) = Drive::verify_prefix_query(
+            proof.as_slice(),
+            &contract,
+            "contact",
+            "name",
+            "al",
+            Some(10),
+        )
+        .expect("expected to verify the documents");
+
+        assert_eq!(
+            documents
+                .iter()
+                .map(|document| document.properties.get("name").cloned())
+                .collect::<Vec<_>>(),
+            vec![
+                Some(Value::Text("alex".to_string())),
+                Some(Value::Text("alfred".to_string())),
+                Some(Value::Text("alice".to_string())),
+            ]
+        );
+
+        let mut query = Drive::prefix_query(&contract, "contact", "name", "al", Some(10))
+            .expect("expected to build the query");
+        query.internal_clauses.range_clause = None;
+        assert!(matches!(
+            query.verify_prefix_proof(proof.as_slice()),
+            Err(Error::Query(QuerySyntaxError::InvalidStartsWithClause(_)))
+        ));
+    }
+}
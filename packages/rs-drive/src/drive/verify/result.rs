@@ -0,0 +1,97 @@
+use crate::drive::verify::RootHash;
+use dpp::document::Document;
+use dpp::prelude::{DataContract, Identity};
+use serde::{Deserialize, Serialize};
+
+/// A verification result along with the root hash it was verified against, serializable so
+/// that it can be exposed over JSON. The root hash is serialized as hex.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifiedResult<T> {
+    /// The root hash of GroveDB computed from the proof
+    #[serde(
+        serialize_with = "serialize_root_hash_as_hex",
+        deserialize_with = "deserialize_root_hash_from_hex"
+    )]
+    pub root_hash: RootHash,
+    /// The verified value
+    pub result: T,
+}
+
+/// A verified identity, `None` if the identity was proved absent
+pub type VerifiedIdentity = VerifiedResult<Option<Identity>>;
+
+/// A verified data contract, `None` if the contract was proved absent
+pub type VerifiedDataContract = VerifiedResult<Option<DataContract>>;
+
+/// Verified documents
+pub type VerifiedDocuments = VerifiedResult<Vec<Document>>;
+
+impl<T> From<(RootHash, T)> for VerifiedResult<T> {
+    fn from((root_hash, result): (RootHash, T)) -> Self {
+        VerifiedResult { root_hash, result }
+    }
+}
+
+fn serialize_root_hash_as_hex<S>(root_hash: &RootHash, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_str(hex::encode(root_hash).as_str())
+}
+
+fn deserialize_root_hash_from_hex<'de, D>(deserializer: D) -> Result<RootHash, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+
+    let s = String::deserialize(deserializer)?;
+    let bytes = hex::decode(s).map_err(Error::custom)?;
+    bytes
+        .try_into()
+        .map_err(|_| Error::custom("root hash should be 32 bytes"))
+}
+
+#[cfg(feature = "full")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drive::Drive;
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::block::block_info::BlockInfo;
+
+    #[test]
+    fn should_round_trip_a_verified_identity_through_json() {
+        let drive = setup_drive_with_initial_state_structure();
+        let identity = Identity::random_identity(3, Some(14));
+
+        drive
+            .add_new_identity(identity.clone(), &BlockInfo::default(), true, None)
+            .expect("expected to add an identity");
+
+        let proof = drive
+            .prove_full_identity(identity.id.to_buffer(), None)
+            .expect("should not error when proving an identity");
+
+        let verified_identity: VerifiedIdentity = Drive::verify_full_identity_by_identity_id(
+            proof.as_slice(),
+            false,
+            identity.id.to_buffer(),
+        )
+        .expect("expect that this be verified")
+        .into();
+
+        let json = serde_json::to_value(&verified_identity).expect("expected to serialize");
+
+        assert_eq!(
+            json["rootHash"],
+            serde_json::Value::String(hex::encode(verified_identity.root_hash))
+        );
+
+        let deserialized: VerifiedIdentity =
+            serde_json::from_value(json).expect("expected to deserialize");
+
+        assert_eq!(deserialized, verified_identity);
+    }
+}
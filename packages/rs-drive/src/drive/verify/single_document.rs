@@ -1,10 +1,12 @@
-use crate::drive::verify::RootHash;
+use crate::drive::verify::{verify_expected_root_hash, RootHash};
 
 use crate::error::proof::ProofError;
 use crate::error::Error;
 use crate::query::SingleDocumentDriveQuery;
 use dpp::data_contract::document_type::DocumentType;
+use dpp::document::document_transition::INITIAL_REVISION;
 use dpp::document::Document;
+use dpp::prelude::{Revision, TimestampMillis};
 
 use grovedb::GroveDb;
 
@@ -96,4 +98,300 @@ impl SingleDocumentDriveQuery {
                 Ok((root_hash, document))
             })?
     }
+
+    /// Verifies the proof of a single document query against a root hash the caller
+    /// already trusts, for example one taken from a verified block header.
+    ///
+    /// # Parameters
+    ///
+    /// - `is_subset`: A boolean indicating whether to verify a subset of a larger proof.
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `document_type`: The type of the document being verified.
+    /// - `expected_root`: The trusted root hash the proof must resolve to.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with an `Option<Document>` representing the deserialized
+    /// document if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is corrupted.
+    /// - The computed root hash does not match `expected_root`.
+    /// - The document serialization fails.
+    pub fn verify_proof_with_expected_root(
+        &self,
+        is_subset: bool,
+        proof: &[u8],
+        document_type: &DocumentType,
+        expected_root: RootHash,
+    ) -> Result<Option<Document>, Error> {
+        let (root_hash, document) = self.verify_proof(is_subset, proof, document_type)?;
+        verify_expected_root_hash(expected_root, root_hash)?;
+        Ok(document)
+    }
+
+    /// Verifies the proof of a single document query and returns the document together
+    /// with its revision, so a client can tell whether the document changed since it
+    /// last synced without comparing full document contents.
+    ///
+    /// Documents of immutable document types never carry a revision, as they can not be
+    /// updated; for those the initial revision is reported.
+    ///
+    /// # Parameters
+    ///
+    /// - `is_subset`: A boolean indicating whether to verify a subset of a larger proof.
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `document_type`: The type of the document being verified.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and `Option<(Document, Revision)>`,
+    /// holding the deserialized document and its revision if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is corrupted.
+    /// - The GroveDb query fails.
+    /// - The document serialization fails.
+    pub fn verify_proof_with_revision(
+        &self,
+        is_subset: bool,
+        proof: &[u8],
+        document_type: &DocumentType,
+    ) -> Result<(RootHash, Option<(Document, Revision)>), Error> {
+        let (root_hash, document) = self.verify_proof(is_subset, proof, document_type)?;
+        let document_with_revision = document.map(|document| {
+            let revision = document.revision.unwrap_or(INITIAL_REVISION);
+            (document, revision)
+        });
+        Ok((root_hash, document_with_revision))
+    }
+
+    /// Verifies the proof of a single document query and checks whether the document is
+    /// owned by the expected identity.
+    ///
+    /// # Parameters
+    ///
+    /// - `is_subset`: A boolean indicating whether to verify a subset of a larger proof.
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `document_type`: The type of the document being verified.
+    /// - `expected_owner`: The identity id the document is expected to be owned by.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and `Option<bool>`. The `Option<bool>` is
+    /// `Some(true)` if the document exists and is owned by `expected_owner`, `Some(false)` if
+    /// it exists and is owned by someone else, and `None` if the document does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is corrupted.
+    /// - The GroveDb query fails.
+    /// - The document serialization fails.
+    pub fn verify_document_owner(
+        &self,
+        is_subset: bool,
+        proof: &[u8],
+        document_type: &DocumentType,
+        expected_owner: [u8; 32],
+    ) -> Result<(RootHash, Option<bool>), Error> {
+        let (root_hash, document) = self.verify_proof(is_subset, proof, document_type)?;
+        let is_owner = document.map(|document| document.owner_id.to_buffer() == expected_owner);
+        Ok((root_hash, is_owner))
+    }
+
+    /// Verifies the proof of a single document query and returns the creation and last
+    /// update timestamps of the document, so a client can tell whether it was modified after
+    /// it was created.
+    ///
+    /// A timestamp is `None` when the document type does not require it.
+    ///
+    /// # Parameters
+    ///
+    /// - `is_subset`: A boolean indicating whether to verify a subset of a larger proof.
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `document_type`: The type of the document being verified.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and
+    /// `Option<(Option<TimestampMillis>, Option<TimestampMillis>)>`, holding the `$createdAt`
+    /// and `$updatedAt` timestamps if the document exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is corrupted.
+    /// - The GroveDb query fails.
+    /// - The document serialization fails.
+    pub fn verify_document_timestamps(
+        &self,
+        is_subset: bool,
+        proof: &[u8],
+        document_type: &DocumentType,
+    ) -> Result<
+        (
+            RootHash,
+            Option<(Option<TimestampMillis>, Option<TimestampMillis>)>,
+        ),
+        Error,
+    > {
+        let (root_hash, document) = self.verify_proof(is_subset, proof, document_type)?;
+        let timestamps = document.map(|document| (document.created_at, document.updated_at));
+        Ok((root_hash, timestamps))
+    }
+}
+
+#[cfg(feature = "full")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drive::document::tests::setup_dashpay;
+    use crate::drive::flags::StorageFlags;
+    use crate::drive::object_size_info::DocumentInfo::DocumentRefInfo;
+    use crate::drive::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+    use dpp::block::block_info::BlockInfo;
+    use dpp::data_contract::document_type::random_document::CreateRandomDocument;
+
+    #[test]
+    fn should_verify_document_timestamps() {
+        let (drive, dashpay) = setup_dashpay("document_timestamps", false);
+        let document_type = dashpay
+            .document_type_for_name("profile")
+            .expect("expected to get document type");
+
+        let document = document_type.random_document(Some(3));
+        drive
+            .add_document_for_contract(
+                DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentRefInfo((
+                            &document,
+                            StorageFlags::optional_default_as_cow(),
+                        )),
+                        owner_id: None,
+                    },
+                    contract: &dashpay,
+                    document_type,
+                },
+                false,
+                BlockInfo::default(),
+                true,
+                None,
+            )
+            .expect("expected to insert a document successfully");
+
+        let document_query = |document_id: [u8; 32]| SingleDocumentDriveQuery {
+            contract_id: dashpay.id.to_buffer(),
+            document_type_name: "profile".to_string(),
+            document_type_keeps_history: document_type.documents_keep_history,
+            document_id,
+            block_time_ms: None,
+        };
+
+        let query = document_query(document.id.to_buffer());
+        let proof = drive
+            .grove_get_proved_path_query(&query.construct_path_query(), false, None, &mut vec![])
+            .expect("expected to prove the document");
+        let (_, timestamps) = query
+            .verify_document_timestamps(false, proof.as_slice(), document_type)
+            .expect("expected to verify the document timestamps");
+
+        // the profile document type requires both timestamps
+        assert!(document.created_at.is_some());
+        assert!(document.updated_at.is_some());
+        assert_eq!(timestamps, Some((document.created_at, document.updated_at)));
+
+        let query = document_query([7; 32]);
+        let proof = drive
+            .grove_get_proved_path_query(&query.construct_path_query(), false, None, &mut vec![])
+            .expect("expected to prove the document absence");
+        let (_, timestamps) = query
+            .verify_document_timestamps(false, proof.as_slice(), document_type)
+            .expect("expected to verify the document absence");
+
+        assert_eq!(timestamps, None);
+    }
+
+    #[test]
+    fn should_verify_document_revision() {
+        let (drive, dashpay) = setup_dashpay("document_revision", false);
+        let document_type = dashpay
+            .document_type_for_name("profile")
+            .expect("expected to get document type");
+
+        let mut document = document_type.random_document(Some(3));
+        document.revision = Some(1);
+        drive
+            .add_document_for_contract(
+                DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentRefInfo((
+                            &document,
+                            StorageFlags::optional_default_as_cow(),
+                        )),
+                        owner_id: None,
+                    },
+                    contract: &dashpay,
+                    document_type,
+                },
+                false,
+                BlockInfo::default(),
+                true,
+                None,
+            )
+            .expect("expected to insert a document successfully");
+
+        let query = SingleDocumentDriveQuery {
+            contract_id: dashpay.id.to_buffer(),
+            document_type_name: "profile".to_string(),
+            document_type_keeps_history: document_type.documents_keep_history,
+            document_id: document.id.to_buffer(),
+            block_time_ms: None,
+        };
+
+        let proof = drive
+            .grove_get_proved_path_query(&query.construct_path_query(), false, None, &mut vec![])
+            .expect("expected to prove the document");
+        let (_, document_with_revision) = query
+            .verify_proof_with_revision(false, proof.as_slice(), document_type)
+            .expect("expected to verify the document revision");
+        let (_, synced_revision) =
+            document_with_revision.expect("expected the document to be proven");
+        assert_eq!(synced_revision, 1);
+
+        document.revision = Some(2);
+        drive
+            .update_document_for_contract(
+                &document,
+                &dashpay,
+                document_type,
+                Some(document.owner_id.to_buffer()),
+                BlockInfo::default(),
+                true,
+                StorageFlags::optional_default_as_cow(),
+                None,
+            )
+            .expect("expected to update the document");
+
+        let proof = drive
+            .grove_get_proved_path_query(&query.construct_path_query(), false, None, &mut vec![])
+            .expect("expected to prove the document");
+        let (_, document_with_revision) = query
+            .verify_proof_with_revision(false, proof.as_slice(), document_type)
+            .expect("expected to verify the document revision");
+        let (_, revision) = document_with_revision.expect("expected the document to be proven");
+
+        // the document changed since it was synced
+        assert_eq!(revision, 2);
+        assert_ne!(revision, synced_revision);
+    }
 }
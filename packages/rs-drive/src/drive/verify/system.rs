@@ -0,0 +1,165 @@
+use crate::drive::system::misc_path_vec;
+use crate::drive::system::misc_tree_constants::{
+    NEXT_PROTOCOL_VERSION_STORAGE_KEY, PROTOCOL_VERSION_STORAGE_KEY,
+};
+use crate::drive::verify::RootHash;
+use crate::drive::Drive;
+use crate::error::proof::ProofError;
+use crate::error::Error;
+use dpp::util::deserializer::ProtocolVersion;
+use grovedb::{GroveDb, PathQuery, Query, SizedQuery};
+use integer_encoding::VarInt;
+
+impl Drive {
+    /// The query for the current protocol version.
+    pub fn current_protocol_version_query() -> PathQuery {
+        Self::protocol_version_query(PROTOCOL_VERSION_STORAGE_KEY)
+    }
+
+    /// The query for the next protocol version.
+    pub fn next_protocol_version_query() -> PathQuery {
+        Self::protocol_version_query(NEXT_PROTOCOL_VERSION_STORAGE_KEY)
+    }
+
+    fn protocol_version_query(key: &[u8; 1]) -> PathQuery {
+        let mut query = Query::new();
+        query.insert_key(key.to_vec());
+        PathQuery::new(misc_path_vec(), SizedQuery::new(query, Some(1), None))
+    }
+
+    /// Verifies the current protocol version of the platform.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and the current protocol version, `None`
+    /// if no protocol version was stored yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is corrupted.
+    /// - The protocol version is not an item holding a varint encoded protocol version.
+    pub fn verify_current_protocol_version(
+        proof: &[u8],
+    ) -> Result<(RootHash, Option<ProtocolVersion>), Error> {
+        Self::verify_protocol_version(proof, PROTOCOL_VERSION_STORAGE_KEY)
+    }
+
+    /// Verifies the next protocol version of the platform, the version that passed the
+    /// upgrade threshold at the last epoch change where one did.
+    ///
+    /// The version is activated at the start of the epoch following that epoch change. The
+    /// activation is not stored with a block height, and the version stays stored once it is
+    /// active, so the proof does not tell whether the activation is still pending.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and the next protocol version, `None` if
+    /// no upgrade ever passed the threshold.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is corrupted.
+    /// - The protocol version is not an item holding a varint encoded protocol version.
+    pub fn verify_next_protocol_version(
+        proof: &[u8],
+    ) -> Result<(RootHash, Option<ProtocolVersion>), Error> {
+        Self::verify_protocol_version(proof, NEXT_PROTOCOL_VERSION_STORAGE_KEY)
+    }
+
+    fn verify_protocol_version(
+        proof: &[u8],
+        protocol_version_key: &[u8; 1],
+    ) -> Result<(RootHash, Option<ProtocolVersion>), Error> {
+        let path_query = Self::protocol_version_query(protocol_version_key);
+        let (root_hash, mut proved_key_values) =
+            GroveDb::verify_query_with_absence_proof(proof, &path_query)?;
+        if proved_key_values.len() != 1 {
+            return Err(Error::Proof(ProofError::CorruptedProof(
+                "we should always get back one element for the protocol version",
+            )));
+        }
+        let (path, key, maybe_element) = proved_key_values.remove(0);
+        if path != path_query.path || key != protocol_version_key.to_vec() {
+            return Err(Error::Proof(ProofError::CorruptedProof(
+                "we did not get back an element for the protocol version",
+            )));
+        }
+        let protocol_version = maybe_element
+            .map(|element| {
+                let bytes = element.into_item_bytes().map_err(Error::GroveDB)?;
+                let Some((protocol_version, _)) = ProtocolVersion::decode_var(&bytes) else {
+                    return Err(Error::Proof(ProofError::IncorrectValueSize(
+                        "protocol version should be a varint",
+                    )));
+                };
+                Ok(protocol_version)
+            })
+            .transpose()?;
+        Ok((root_hash, protocol_version))
+    }
+}
+
+#[cfg(feature = "full")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+
+    #[test]
+    fn should_prove_current_protocol_version() {
+        let drive = setup_drive_with_initial_state_structure();
+
+        let proof = drive
+            .prove_current_protocol_version(None)
+            .expect("expected to prove the protocol version");
+        let (_, protocol_version) = Drive::verify_current_protocol_version(proof.as_slice())
+            .expect("expected to verify the protocol version");
+        assert_eq!(protocol_version, None);
+
+        let proof = drive
+            .prove_next_protocol_version(None)
+            .expect("expected to prove the next protocol version");
+        let (_, next_protocol_version) = Drive::verify_next_protocol_version(proof.as_slice())
+            .expect("expected to verify the next protocol version");
+        assert_eq!(next_protocol_version, None);
+
+        drive
+            .change_to_new_version_and_clear_version_information(3, 4, None)
+            .expect("expected to change the protocol version");
+
+        let proof = drive
+            .prove_current_protocol_version(None)
+            .expect("expected to prove the protocol version");
+        let (root_hash, protocol_version) =
+            Drive::verify_current_protocol_version(proof.as_slice())
+                .expect("expected to verify the protocol version");
+        assert_eq!(protocol_version, Some(3));
+
+        let proof = drive
+            .prove_next_protocol_version(None)
+            .expect("expected to prove the next protocol version");
+        let (_, next_protocol_version) = Drive::verify_next_protocol_version(proof.as_slice())
+            .expect("expected to verify the next protocol version");
+        assert_eq!(next_protocol_version, Some(4));
+        assert_eq!(
+            root_hash,
+            drive
+                .grove
+                .root_hash(None)
+                .unwrap()
+                .expect("there is always a root hash")
+        );
+    }
+}
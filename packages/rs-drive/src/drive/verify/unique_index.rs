@@ -0,0 +1,369 @@
+use crate::drive::document::contract_document_type_path_vec;
+use crate::drive::verify::RootHash;
+use crate::drive::Drive;
+use crate::error::proof::ProofError;
+use crate::error::query::QuerySyntaxError;
+use crate::error::Error;
+use dpp::data_contract::document_type::{DocumentType, Index, IndexProperty};
+use dpp::document::Document;
+use dpp::platform_value::Value;
+use grovedb::{GroveDb, PathQuery, Query, SizedQuery};
+
+/// The outcome of verifying a value against a unique index
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UniqueProofResult {
+    /// No document holds the value
+    Absent,
+    /// The id of the only document holding the value
+    Present([u8; 32]),
+}
+
+impl Drive {
+    /// The unique index of a document type with the given name.
+    fn unique_index<'a>(
+        document_type: &'a DocumentType,
+        index_name: &str,
+    ) -> Result<&'a Index, Error> {
+        let index = document_type
+            .indices
+            .iter()
+            .find(|index| index.name == index_name)
+            .ok_or_else(|| {
+                Error::Query(QuerySyntaxError::InvalidParameter(format!(
+                    "document type {} has no index {}",
+                    document_type.name, index_name
+                )))
+            })?;
+        if !index.unique {
+            return Err(Error::Query(QuerySyntaxError::InvalidParameter(format!(
+                "index {} is not unique",
+                index_name
+            ))));
+        }
+        Ok(index)
+    }
+
+    /// The property of a unique index on a single property.
+    fn unique_index_property<'a>(
+        document_type: &'a DocumentType,
+        index_name: &str,
+    ) -> Result<&'a IndexProperty, Error> {
+        match Self::unique_index(document_type, index_name)?
+            .properties
+            .as_slice()
+        {
+            [property] => Ok(property),
+            _ => Err(Error::Query(QuerySyntaxError::InvalidParameter(format!(
+                "index {} must be on a single property",
+                index_name
+            )))),
+        }
+    }
+
+    /// The query for the document reference stored for a value of a single property unique
+    /// index.
+    ///
+    /// Unique indexes store the reference to the document holding a value under the key `0`
+    /// of the value's subtree.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the index does not exist on the document type, is not unique or
+    /// is on more than one property, or if the value can not be serialized for the property.
+    pub fn unique_index_value_query(
+        document_type: &DocumentType,
+        index_name: &str,
+        value: &Value,
+    ) -> Result<PathQuery, Error> {
+        let property = Self::unique_index_property(document_type, index_name)?;
+        let mut path = contract_document_type_path_vec(
+            document_type.data_contract_id.as_slice(),
+            document_type.name.as_str(),
+        );
+        path.push(property.name.as_bytes().to_vec());
+        path.push(document_type.serialize_value_for_key(property.name.as_str(), value)?);
+        let mut query = Query::new();
+        query.insert_key(vec![0]);
+        Ok(PathQuery::new(path, SizedQuery::new(query, Some(1), None)))
+    }
+
+    /// Verifies whether a value is held by a document for a unique index of a document type.
+    ///
+    /// The proof must be over the subtree of the value in the named unique index, so a
+    /// present result is tied to the single document the index references for the value.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `document_type`: The document type the index is defined on.
+    /// - `index_name`: The name of a unique index on a single property.
+    /// - `value`: The value of the indexed property.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and `UniqueProofResult`, either `Absent`
+    /// or `Present` with the id of the document holding the value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The index is not a unique index on a single property of the document type.
+    /// - The proof is corrupted or is not over the unique index path of the value.
+    /// - The referenced document can not be deserialized or does not hold the value.
+    pub fn verify_unique_index_covers_value(
+        proof: &[u8],
+        document_type: &DocumentType,
+        index_name: &str,
+        value: &Value,
+    ) -> Result<(RootHash, UniqueProofResult), Error> {
+        let path_query = Self::unique_index_value_query(document_type, index_name, value)?;
+        let (root_hash, mut proved_key_values) =
+            GroveDb::verify_query_with_absence_proof(proof, &path_query)?;
+        if proved_key_values.len() != 1 {
+            return Err(Error::Proof(ProofError::CorruptedProof(
+                "we should always get back one element for a unique index value",
+            )));
+        }
+        let (path, key, maybe_element) = proved_key_values.remove(0);
+        if path != path_query.path || key != vec![0] {
+            return Err(Error::Proof(ProofError::CorruptedProof(
+                "we did not get back an element for the correct unique index path",
+            )));
+        }
+        let Some(element) = maybe_element else {
+            return Ok((root_hash, UniqueProofResult::Absent));
+        };
+        let serialized_document = element.into_item_bytes().map_err(Error::GroveDB)?;
+        let document = Document::from_bytes(serialized_document.as_slice(), document_type)?;
+        // the index references the document, the document must hold the value it is indexed by
+        let property = Self::unique_index_property(document_type, index_name)?;
+        let document_value = document.get(property.name.as_str()).ok_or(Error::Proof(
+            ProofError::CorruptedProof(
+                "the referenced document does not hold the indexed property",
+            ),
+        ))?;
+        if document_type.serialize_value_for_key(property.name.as_str(), document_value)?
+            != document_type.serialize_value_for_key(property.name.as_str(), value)?
+        {
+            return Err(Error::Proof(ProofError::CorruptedProof(
+                "the referenced document does not hold the indexed value",
+            )));
+        }
+        Ok((
+            root_hash,
+            UniqueProofResult::Present(document.id.to_buffer()),
+        ))
+    }
+
+    /// The query for the document reference stored for the values of all the properties of a
+    /// unique index, for example the parent domain name and label of a DPNS domain.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the index does not exist on the document type or is not unique,
+    /// if there is not one value for each property of the index, or if a value can not be
+    /// serialized for its property.
+    pub fn composite_unique_key_query(
+        document_type: &DocumentType,
+        index_name: &str,
+        key_values: &[Value],
+    ) -> Result<PathQuery, Error> {
+        let index = Self::unique_index(document_type, index_name)?;
+        if index.properties.len() != key_values.len() {
+            return Err(Error::Query(QuerySyntaxError::InvalidParameter(format!(
+                "index {} has {} properties but {} key values were given",
+                index_name,
+                index.properties.len(),
+                key_values.len()
+            ))));
+        }
+        let mut path = contract_document_type_path_vec(
+            document_type.data_contract_id.as_slice(),
+            document_type.name.as_str(),
+        );
+        for (property, value) in index.properties.iter().zip(key_values) {
+            path.push(property.name.as_bytes().to_vec());
+            path.push(document_type.serialize_value_for_key(property.name.as_str(), value)?);
+        }
+        let mut query = Query::new();
+        query.insert_key(vec![0]);
+        Ok(PathQuery::new(path, SizedQuery::new(query, Some(1), None)))
+    }
+
+    /// Verifies the document holding the given values for all the properties of a unique
+    /// index of a document type.
+    ///
+    /// A unique index whose values are all null references its documents by id instead, so
+    /// such values can not be verified through this method.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `document_type`: The document type the index is defined on.
+    /// - `index_name`: The name of a unique index.
+    /// - `key_values`: The value of every property of the index, in the order of the index.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and the document holding the values, if
+    /// any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The index is not a unique index of the document type or the key values do not match
+    ///   its properties.
+    /// - The proof is corrupted or is not over the unique index path of the values.
+    /// - The referenced document can not be deserialized or does not hold the values.
+    pub fn verify_document_by_composite_unique_key(
+        proof: &[u8],
+        document_type: &DocumentType,
+        index_name: &str,
+        key_values: &[Value],
+    ) -> Result<(RootHash, Option<Document>), Error> {
+        let path_query = Self::composite_unique_key_query(document_type, index_name, key_values)?;
+        let (root_hash, mut proved_key_values) =
+            GroveDb::verify_query_with_absence_proof(proof, &path_query)?;
+        if proved_key_values.len() != 1 {
+            return Err(Error::Proof(ProofError::CorruptedProof(
+                "we should always get back one element for a unique index key",
+            )));
+        }
+        let (path, key, maybe_element) = proved_key_values.remove(0);
+        if path != path_query.path || key != vec![0] {
+            return Err(Error::Proof(ProofError::CorruptedProof(
+                "we did not get back an element for the correct unique index path",
+            )));
+        }
+        let Some(element) = maybe_element else {
+            return Ok((root_hash, None));
+        };
+        let serialized_document = element.into_item_bytes().map_err(Error::GroveDB)?;
+        let document = Document::from_bytes(serialized_document.as_slice(), document_type)?;
+        // the index references the document, the document must hold the values it is indexed by
+        let index = Self::unique_index(document_type, index_name)?;
+        for (property, value) in index.properties.iter().zip(key_values) {
+            let document_value =
+                document.get_raw_for_document_type(property.name.as_str(), document_type, None)?;
+            if document_value
+                != Some(document_type.serialize_value_for_key(property.name.as_str(), value)?)
+            {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "the referenced document does not hold the indexed values",
+                )));
+            }
+        }
+        Ok((root_hash, Some(document)))
+    }
+}
+
+#[cfg(feature = "full")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drive::flags::StorageFlags;
+    use crate::drive::object_size_info::DocumentInfo::DocumentRefInfo;
+    use crate::drive::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::platform_value::platform_value;
+    use dpp::prelude::{DataContract, Identifier};
+
+    #[test]
+    fn should_verify_a_value_against_a_unique_index() {
+        let drive = setup_drive_with_initial_state_structure();
+
+        let contract = DataContract::from_raw_object(platform_value!({
+            "protocolVersion": 1,
+            "$id": "BZUodcFoFL6KvnonehrnMVggTvCe8W5MiRnZuqLb6M54",
+            "$schema": "https://schema.dash.org/dpp-0-4-0/meta/data-contract",
+            "version": 1,
+            "ownerId": "GZVdTnLFAN2yE9rLeCHBDBCr7YQgmXJuoExkY347j7Z5",
+            "documents": {
+                "account": {
+                    "type": "object",
+                    "indices": [
+                        {"name":"handle", "properties": [{"handle":"asc"}], "unique": true},
+                    ],
+                    "properties":{
+                        "handle": {
+                            "type": "string",
+                            "maxLength": 63,
+                        },
+                    },
+                    "required": ["handle"],
+                    "additionalProperties": false,
+                },
+            },
+        }))
+        .expect("expected to create a contract");
+
+        drive
+            .apply_contract(&contract, BlockInfo::default(), true, None, None)
+            .expect("expected to apply the contract");
+
+        let document_type = contract
+            .document_type_for_name("account")
+            .expect("expected to get the document type");
+
+        let document = Document {
+            id: Identifier::new([1; 32]),
+            owner_id: contract.owner_id,
+            properties: [("handle".to_string(), Value::Text("alice".to_string()))].into(),
+            revision: None,
+            created_at: None,
+            updated_at: None,
+        };
+        drive
+            .add_document_for_contract(
+                DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentRefInfo((
+                            &document,
+                            StorageFlags::optional_default_as_cow(),
+                        )),
+                        owner_id: None,
+                    },
+                    contract: &contract,
+                    document_type,
+                },
+                false,
+                BlockInfo::default(),
+                true,
+                None,
+            )
+            .expect("expected to insert a document");
+
+        let prove_and_verify = |handle: &str| {
+            let value = Value::Text(handle.to_string());
+            let path_query = Drive::unique_index_value_query(document_type, "handle", &value)
+                .expect("expected to build the query");
+            let proof = drive
+                .grove_get_proved_path_query(&path_query, false, None, &mut vec![])
+                .expect("expected to prove the unique index value");
+            Drive::verify_unique_index_covers_value(
+                proof.as_slice(),
+                document_type,
+                "handle",
+                &value,
+            )
+            .expect("expected to verify the unique index value")
+        };
+
+        let (root_hash, result) = prove_and_verify("alice");
+        assert_eq!(
+            root_hash,
+            drive
+                .grove
+                .root_hash(None)
+                .unwrap()
+                .expect("expected to get the root hash")
+        );
+        assert_eq!(result, UniqueProofResult::Present([1; 32]));
+
+        let (_, result) = prove_and_verify("bob");
+        assert_eq!(result, UniqueProofResult::Absent);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::drive::verify::RootHash;
+use crate::drive::Drive;
+use crate::error::proof::ProofError;
+use crate::error::Error;
+use crate::query::SingleDocumentDriveQuery;
+use dpp::contracts::withdrawals_contract;
+use dpp::contracts::withdrawals_contract::WithdrawalStatus;
+use dpp::data_contract::document_type::DocumentType;
+use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
+use std::convert::TryFrom;
+
+impl Drive {
+    /// The query for a withdrawal document of the withdrawals system contract.
+    ///
+    /// # Parameters
+    ///
+    /// - `document_type`: The withdrawal document type of the withdrawals contract.
+    /// - `withdrawal_id`: The id of the withdrawal document.
+    pub fn withdrawal_document_query(
+        document_type: &DocumentType,
+        withdrawal_id: [u8; 32],
+    ) -> SingleDocumentDriveQuery {
+        SingleDocumentDriveQuery {
+            contract_id: withdrawals_contract::CONTRACT_ID.to_buffer(),
+            document_type_name: document_type.name.clone(),
+            document_type_keeps_history: document_type.documents_keep_history,
+            document_id: withdrawal_id,
+            block_time_ms: None,
+        }
+    }
+
+    /// Verifies the proof of a withdrawal document and returns the status of the withdrawal.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `withdrawal_id`: The id of the withdrawal document.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and `Option<WithdrawalStatus>`, `None` if
+    /// the withdrawal document is proven not to exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The withdrawals system contract can not be loaded.
+    /// - The proof is corrupted or the withdrawal document can not be deserialized.
+    /// - The withdrawal document holds no valid status.
+    pub fn verify_withdrawal_status(
+        proof: &[u8],
+        withdrawal_id: [u8; 32],
+    ) -> Result<(RootHash, Option<WithdrawalStatus>), Error> {
+        let contract = load_system_data_contract(SystemDataContract::Withdrawals)?;
+        let document_type =
+            contract.document_type_for_name(withdrawals_contract::document_types::WITHDRAWAL)?;
+        let (root_hash, document) = Self::withdrawal_document_query(document_type, withdrawal_id)
+            .verify_proof(false, proof, document_type)?;
+        let status = document
+            .map(|document| {
+                let invalid_status = |reason| {
+                    Error::Proof(ProofError::InvalidDocument {
+                        document_id: withdrawal_id,
+                        field: withdrawals_contract::property_names::STATUS.to_string(),
+                        reason,
+                    })
+                };
+                let status: u8 = document
+                    .get(withdrawals_contract::property_names::STATUS)
+                    .ok_or_else(|| invalid_status("the withdrawal has no status"))?
+                    .to_integer()
+                    .map_err(|_| invalid_status("the withdrawal status is not a byte"))?;
+                WithdrawalStatus::try_from(status)
+                    .map_err(|_| invalid_status("the withdrawal status is unknown"))
+            })
+            .transpose()?;
+        Ok((root_hash, status))
+    }
+}
+
+#[cfg(feature = "full")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::helpers::setup::{
+        setup_document, setup_drive_with_initial_state_structure, setup_system_data_contract,
+    };
+    use dpp::identity::core_script::CoreScript;
+    use dpp::identity::state_transition::identity_credit_withdrawal_transition::Pooling;
+    use dpp::platform_value::platform_value;
+    use dpp::prelude::Identifier;
+    use dpp::tests::fixtures::get_withdrawal_document_fixture;
+
+    #[test]
+    fn should_verify_withdrawal_status() {
+        let drive = setup_drive_with_initial_state_structure();
+
+        let data_contract = load_system_data_contract(SystemDataContract::Withdrawals)
+            .expect("to load system data contract");
+
+        setup_system_data_contract(&drive, &data_contract, None);
+
+        let document = get_withdrawal_document_fixture(
+            &data_contract,
+            Identifier::new([1u8; 32]),
+            platform_value!({
+                "amount": 1000u64,
+                "coreFeePerByte": 1u32,
+                "pooling": Pooling::Never as u8,
+                "outputScript": CoreScript::from_bytes((0..23).collect::<Vec<u8>>()),
+                "status": WithdrawalStatus::POOLED as u8,
+                "transactionIndex": 1u64,
+            }),
+            None,
+        )
+        .expect("expected withdrawal document");
+
+        let document_type = data_contract
+            .document_type_for_name(withdrawals_contract::document_types::WITHDRAWAL)
+            .expect("expected to get document type");
+
+        setup_document(&drive, &document, &data_contract, document_type, None);
+
+        let withdrawal_id = document.id.to_buffer();
+        let proof = drive
+            .grove_get_proved_path_query(
+                &Drive::withdrawal_document_query(document_type, withdrawal_id)
+                    .construct_path_query(),
+                false,
+                None,
+                &mut vec![],
+            )
+            .expect("expected to prove the withdrawal");
+
+        let (_, status) = Drive::verify_withdrawal_status(proof.as_slice(), withdrawal_id)
+            .expect("expected to verify the withdrawal status");
+        assert_eq!(status, Some(WithdrawalStatus::POOLED));
+
+        let unknown_withdrawal_id = [7; 32];
+        let proof = drive
+            .grove_get_proved_path_query(
+                &Drive::withdrawal_document_query(document_type, unknown_withdrawal_id)
+                    .construct_path_query(),
+                false,
+                None,
+                &mut vec![],
+            )
+            .expect("expected to prove the withdrawal absence");
+
+        let (_, status) = Drive::verify_withdrawal_status(proof.as_slice(), unknown_withdrawal_id)
+            .expect("expected to verify the withdrawal status");
+        assert_eq!(status, None);
+    }
+}
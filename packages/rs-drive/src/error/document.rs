@@ -13,4 +13,12 @@ pub enum DocumentError {
     /// Error
     #[error("contact with specified identifier is not found")]
     ContractNotFound,
+    /// Error
+    #[error("contract version {found} does not match the expected version {expected}")]
+    ContractVersionMismatch {
+        /// The version of the stored contract
+        found: u32,
+        /// The version that was expected
+        expected: u32,
+    },
 }
@@ -35,6 +35,26 @@ pub enum ProofError {
         /// The actual path
         actual: Path,
     },
+
+    /// The root hash computed from the proof does not match the expected one
+    #[error("incorrect root hash error: proof root hash does not match the expected root hash")]
+    IncorrectRootHash {
+        /// The expected root hash
+        expected: [u8; 32],
+        /// The root hash computed from the proof
+        actual: [u8; 32],
+    },
+
+    /// A proved document does not conform to its document type
+    #[error("invalid document error: field {field} of a proved document is invalid: {reason}")]
+    InvalidDocument {
+        /// The id of the offending document
+        document_id: [u8; 32],
+        /// The offending field
+        field: String,
+        /// Why the field is invalid
+        reason: &'static str,
+    },
 }
 
 fn get_error_code(error: &ProofError) -> u32 {
@@ -46,5 +66,7 @@ fn get_error_code(error: &ProofError) -> u32 {
         ProofError::IncompleteProof(_) => 6004,
         ProofError::IncorrectValueSize(_) => 6005,
         ProofError::IncorrectElementPath { .. } => 6006,
+        ProofError::IncorrectRootHash { .. } => 6007,
+        ProofError::InvalidDocument { .. } => 6008,
     }
 }
@@ -95,6 +95,9 @@ pub enum QuerySyntaxError {
     /// Query is too far from index error
     #[error("query is too far from index: {0}")]
     QueryTooFarFromIndex(&'static str),
+    /// Query is too complex error
+    #[error("query is too complex: {0}")]
+    QueryTooComplex(String),
     /// Query on document type with no indexes error
     #[error("query on document type with no indexes: {0}")]
     QueryOnDocumentTypeWithNoIndexes(&'static str),
@@ -112,6 +115,9 @@ pub enum QuerySyntaxError {
     /// Range operator does not have order by error
     #[error("range operator does not have order by error: {0}")]
     RangeOperatorDoesNotHaveOrderBy(&'static str),
+    /// Range clause is not on the index property right after the equality clauses error
+    #[error("misplaced range clause error: {0}")]
+    MisplacedRangeClause(String),
 
     /// Validation error
     #[error("validation error: {0}")]
@@ -127,4 +133,8 @@ pub enum QuerySyntaxError {
     /// Invalid identity prove request error
     #[error("invalid identity prove request error: {0}")]
     InvalidIdentityProveRequest(&'static str),
+
+    /// The query used for verification differs from the query that was sent
+    #[error("query mismatch error: {0}")]
+    QueryMismatch(String),
 }
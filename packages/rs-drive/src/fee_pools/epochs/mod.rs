@@ -32,5 +32,6 @@
 
 /// Epoch key constants module
 pub mod epoch_key_constants;
+#[cfg(feature = "full")]
 pub mod operations_factory;
 pub mod paths;
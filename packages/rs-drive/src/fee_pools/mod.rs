@@ -27,17 +27,27 @@
 // DEALINGS IN THE SOFTWARE.
 //
 
+#[cfg(feature = "full")]
 use crate::drive::batch::GroveDbOpBatch;
+#[cfg(feature = "full")]
 use crate::drive::fee_pools::pools_vec_path;
+#[cfg(feature = "full")]
 use crate::error::Error;
+#[cfg(feature = "full")]
 use crate::fee::credits::{Creditable, Credits};
+#[cfg(feature = "full")]
 use crate::fee::epoch::{EpochIndex, GENESIS_EPOCH_INDEX, PERPETUAL_STORAGE_EPOCHS};
+#[cfg(feature = "full")]
 use crate::fee_pools::epochs::operations_factory::EpochOperations;
+#[cfg(feature = "full")]
 use crate::fee_pools::epochs_root_tree_key_constants::{
     KEY_PENDING_EPOCH_REFUNDS, KEY_STORAGE_FEE_POOL, KEY_UNPAID_EPOCH_INDEX,
 };
+#[cfg(feature = "full")]
 use dpp::block::epoch::Epoch;
+#[cfg(feature = "full")]
 use grovedb::batch::GroveDbOp;
+#[cfg(feature = "full")]
 use grovedb::Element;
 
 /// Epochs module
@@ -45,6 +55,7 @@ pub mod epochs;
 /// Epochs root tree key constants module
 pub mod epochs_root_tree_key_constants;
 
+#[cfg(feature = "full")]
 /// Adds the operations to groveDB op batch to create the fee pool trees
 pub fn add_create_fee_pool_trees_operations(batch: &mut GroveDbOpBatch) -> Result<(), Error> {
     // Init storage credit pool
@@ -65,11 +76,13 @@ pub fn add_create_fee_pool_trees_operations(batch: &mut GroveDbOpBatch) -> Resul
     Ok(())
 }
 
+#[cfg(feature = "full")]
 /// Adds operations to batch to create pending pool updates tree
 pub fn add_create_pending_epoch_refunds_tree_operations(batch: &mut GroveDbOpBatch) {
     batch.add_insert_empty_sum_tree(pools_vec_path(), KEY_PENDING_EPOCH_REFUNDS.to_vec());
 }
 
+#[cfg(feature = "full")]
 /// Updates the storage fee distribution pool with a new storage fee
 pub fn update_storage_fee_distribution_pool_operation(
     storage_fee: Credits,
@@ -81,6 +94,7 @@ pub fn update_storage_fee_distribution_pool_operation(
     ))
 }
 
+#[cfg(feature = "full")]
 /// Updates the unpaid epoch index
 pub fn update_unpaid_epoch_index_operation(epoch_index: EpochIndex) -> GroveDbOp {
     GroveDbOp::insert_op(
@@ -92,6 +106,7 @@ pub fn update_unpaid_epoch_index_operation(epoch_index: EpochIndex) -> GroveDbOp
 
 // TODD: Find tests
 
+#[cfg(feature = "full")]
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -26,7 +26,7 @@ pub mod error;
 #[cfg(any(feature = "full", feature = "verify"))]
 pub mod fee;
 /// Fee pools module
-#[cfg(feature = "full")]
+#[cfg(any(feature = "full", feature = "verify"))]
 pub mod fee_pools;
 /// Query module
 #[cfg(any(feature = "full", feature = "verify"))]
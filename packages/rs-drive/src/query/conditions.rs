@@ -45,6 +45,7 @@ use crate::error::Error;
 use dpp::data_contract::document_type::DocumentType;
 use dpp::document::Document;
 use dpp::platform_value::Value;
+use serde::{Deserialize, Serialize};
 
 /// Converts SQL values to CBOR.
 fn sql_value_to_platform_value(sql_value: ast::Value) -> Option<Value> {
@@ -71,7 +72,7 @@ fn sql_value_to_platform_value(sql_value: ast::Value) -> Option<Value> {
 }
 
 /// Where operator arguments
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum WhereOperator {
     /// Equal
     Equal,
@@ -199,16 +200,44 @@ impl WhereOperator {
 }
 
 /// Where clause struct
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct WhereClause {
     /// Field
     pub field: String,
     /// Operator
     pub operator: WhereOperator,
-    /// Value
+    /// Value, serialized as the hex of its bincode encoding so that it keeps its exact variant
+    #[serde(
+        serialize_with = "serialize_value_losslessly",
+        deserialize_with = "deserialize_value_losslessly"
+    )]
     pub value: Value,
 }
 
+fn serialize_value_losslessly<S>(value: &Value, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use serde::ser::Error;
+
+    let encoded_value =
+        bincode::encode_to_vec(value, bincode::config::standard()).map_err(Error::custom)?;
+    serializer.serialize_str(hex::encode(encoded_value).as_str())
+}
+
+fn deserialize_value_losslessly<'de, D>(deserializer: D) -> Result<Value, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+
+    let s = String::deserialize(deserializer)?;
+    let encoded_value = hex::decode(s).map_err(Error::custom)?;
+    bincode::decode_from_slice(encoded_value.as_slice(), bincode::config::standard())
+        .map(|(value, _)| value)
+        .map_err(Error::custom)
+}
+
 impl<'a> WhereClause {
     /// Returns true if the `WhereClause` is an identifier
     pub fn is_identifier(&self) -> bool {
@@ -1,2 +1,4 @@
 /// Max index difference constant
 pub(crate) const MAX_INDEX_DIFFERENCE: u16 = 2;
+pub(crate) const RANGE_COMPLEXITY_FACTOR: u32 = 2;
+pub(crate) const NO_EQUALITY_COMPLEXITY_FACTOR: u32 = 4;
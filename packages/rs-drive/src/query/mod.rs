@@ -104,10 +104,12 @@ use dpp::platform_value::platform_value;
 use dpp::platform_value::Value;
 
 use crate::common::encode::encode_u64;
-use crate::drive::config::DriveConfig;
+use crate::drive::config::{DriveConfig, DEFAULT_DEFAULT_QUERY_LIMIT};
 use crate::error::Error::GroveDB;
 #[cfg(any(feature = "full", feature = "verify"))]
 use dpp::ProtocolError;
+#[cfg(any(feature = "full", feature = "verify"))]
+use serde::{Deserialize, Serialize};
 
 #[cfg(any(feature = "full", feature = "verify"))]
 pub mod conditions;
@@ -116,16 +118,24 @@ mod defaults;
 #[cfg(any(feature = "full", feature = "verify"))]
 pub mod ordering;
 #[cfg(any(feature = "full", feature = "verify"))]
+mod replayable_drive_query;
+#[cfg(any(feature = "full", feature = "verify"))]
 mod single_document_drive_query;
 #[cfg(feature = "full")]
 mod test_index;
 
+#[cfg(any(feature = "full", feature = "verify"))]
+pub use replayable_drive_query::ReplayableDriveQuery;
 #[cfg(any(feature = "full", feature = "verify"))]
 pub use single_document_drive_query::SingleDocumentDriveQuery;
 
+/// The default maximum complexity score a query may have before it is rejected locally
+#[cfg(any(feature = "full", feature = "verify"))]
+pub const DEFAULT_MAX_QUERY_COMPLEXITY: u32 = 1600;
+
 #[cfg(any(feature = "full", feature = "verify"))]
 /// Internal clauses struct
-#[derive(Clone, Debug, PartialEq, Default)]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
 pub struct InternalClauses {
     /// Primary key in clause
     pub primary_key_in_clause: Option<WhereClause>,
@@ -176,7 +186,7 @@ impl InternalClauses {
 
     #[cfg(any(feature = "full", feature = "verify"))]
     /// Extracts the `WhereClause`s and returns them as type `InternalClauses`.
-    fn extract_from_clauses(all_where_clauses: Vec<WhereClause>) -> Result<Self, Error> {
+    pub(crate) fn extract_from_clauses(all_where_clauses: Vec<WhereClause>) -> Result<Self, Error> {
         let primary_key_equal_clauses_array = all_where_clauses
             .iter()
             .filter_map(|where_clause| match where_clause.operator {
@@ -471,7 +481,7 @@ impl<'a> DriveQuery<'a> {
             )));
         }
 
-        Ok(DriveQuery {
+        let query = DriveQuery {
             contract,
             document_type,
             internal_clauses,
@@ -481,7 +491,9 @@ impl<'a> DriveQuery<'a> {
             start_at,
             start_at_included,
             block_time_ms,
-        })
+        };
+        query.validate_clause_order()?;
+        Ok(query)
     }
 
     #[cfg(any(feature = "full", feature = "verify"))]
@@ -552,7 +564,7 @@ impl<'a> DriveQuery<'a> {
             .map(|order_clause| Ok((order_clause.field.clone(), order_clause.to_owned())))
             .collect::<Result<IndexMap<String, OrderClause>, Error>>()?;
 
-        Ok(DriveQuery {
+        let query = DriveQuery {
             contract,
             document_type,
             internal_clauses,
@@ -562,7 +574,9 @@ impl<'a> DriveQuery<'a> {
             start_at,
             start_at_included,
             block_time_ms,
-        })
+        };
+        query.validate_clause_order()?;
+        Ok(query)
     }
 
     #[cfg(any(feature = "full", feature = "verify"))]
@@ -698,7 +712,7 @@ impl<'a> DriveQuery<'a> {
             })
             .transpose()?;
 
-        Ok(DriveQuery {
+        let query = DriveQuery {
             contract,
             document_type,
             internal_clauses,
@@ -708,7 +722,9 @@ impl<'a> DriveQuery<'a> {
             start_at,
             start_at_included,
             block_time_ms: None,
-        })
+        };
+        query.validate_clause_order()?;
+        Ok(query)
     }
 
     #[cfg(any(feature = "full", feature = "verify"))]
@@ -1054,6 +1070,132 @@ impl<'a> DriveQuery<'a> {
         Ok(index)
     }
 
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Checks that the where clauses follow the equality-then-range order of the best index
+    /// for the query. Queries without a range clause, for primary keys or matching no index
+    /// are left to be validated when they are executed.
+    pub fn validate_clause_order(&self) -> Result<(), Error> {
+        if self.internal_clauses.range_clause.is_none()
+            || self.internal_clauses.is_for_primary_key()
+        {
+            return Ok(());
+        }
+        match self.find_best_index() {
+            Ok(index) => self.validate_clause_order_for_index(index),
+            Err(_) => Ok(()),
+        }
+    }
+
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Checks that the where clauses follow the equality-then-range order of an index.
+    ///
+    /// The leading properties of the index must all have equality (or `in`) clauses, and a
+    /// range clause can only be on the property right after them, as the index tree is walked
+    /// down with the equality values before the range is applied.
+    pub fn validate_clause_order_for_index(&self, index: &Index) -> Result<(), Error> {
+        let range_clause = match &self.internal_clauses.range_clause {
+            None => return Ok(()),
+            Some(range_clause) => range_clause,
+        };
+        let mut properties_after_equalities = index.properties.iter().skip_while(|property| {
+            self.internal_clauses
+                .equal_clauses
+                .contains_key(property.name.as_str())
+                || self
+                    .internal_clauses
+                    .in_clause
+                    .as_ref()
+                    .map_or(false, |in_clause| in_clause.field == property.name)
+        });
+        match properties_after_equalities.next() {
+            Some(property) if property.name == range_clause.field => Ok(()),
+            Some(property) => Err(Error::Query(QuerySyntaxError::MisplacedRangeClause(
+                format!(
+                    "range clause on {} must instead be on {}, the first property of index {} without an equality clause",
+                    range_clause.field, property.name, index.name
+                ),
+            ))),
+            None => Err(Error::Query(QuerySyntaxError::MisplacedRangeClause(
+                format!(
+                    "range clause on {} can not be used as all properties of index {} have equality clauses",
+                    range_clause.field, index.name
+                ),
+            ))),
+        }
+    }
+
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Estimates how expensive the query would be for the platform to execute.
+    ///
+    /// The score starts from the limit and grows with the number of values of an `in`
+    /// clause, with a range clause and when no equality clause narrows down the index.
+    /// Queries for primary keys, or fully matching a unique index with equality clauses,
+    /// are scored by the number of documents they can return.
+    pub fn complexity_score(&self) -> u32 {
+        let limit = self.limit.unwrap_or(DEFAULT_DEFAULT_QUERY_LIMIT) as u32;
+
+        if self.internal_clauses.primary_key_equal_clause.is_some() {
+            return 1;
+        }
+
+        if let Some(primary_key_in_clause) = &self.internal_clauses.primary_key_in_clause {
+            return primary_key_in_clause
+                .in_values()
+                .map(|values| values.len() as u32)
+                .unwrap_or(limit)
+                .min(limit);
+        }
+
+        if self.internal_clauses.in_clause.is_none() && self.internal_clauses.range_clause.is_none()
+        {
+            if let Ok(index) = self.find_best_index() {
+                let fully_matches_unique_index = index.unique
+                    && index.properties.iter().all(|index_property| {
+                        self.internal_clauses
+                            .equal_clauses
+                            .contains_key(&index_property.name)
+                    });
+                if fully_matches_unique_index {
+                    return 1;
+                }
+            }
+        }
+
+        let mut score = limit.max(1);
+
+        if let Some(in_clause) = &self.internal_clauses.in_clause {
+            let in_values_count = in_clause
+                .in_values()
+                .map(|values| values.len() as u32)
+                .unwrap_or(1);
+            score = score.saturating_mul(in_values_count.max(1));
+        }
+
+        if self.internal_clauses.range_clause.is_some() {
+            score = score.saturating_mul(defaults::RANGE_COMPLEXITY_FACTOR);
+        }
+
+        if self.internal_clauses.equal_clauses.is_empty() {
+            score = score.saturating_mul(defaults::NO_EQUALITY_COMPLEXITY_FACTOR);
+        }
+
+        score
+    }
+
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Rejects the query locally if its complexity score is above `max_complexity`,
+    /// otherwise returns the score.
+    pub fn validate_complexity(&self, max_complexity: u32) -> Result<u32, Error> {
+        let score = self.complexity_score();
+        if score > max_complexity {
+            return Err(Error::Query(QuerySyntaxError::QueryTooComplex(format!(
+                "query complexity score {} is above the maximum of {}, consider lowering the limit or adding equality clauses",
+                score, max_complexity
+            ))));
+        }
+        Ok(score)
+    }
+
     #[cfg(any(feature = "full", feature = "verify"))]
     /// Returns a `QueryItem` given a start key and query direction.
     fn query_item_for_starts_at_key(starts_at_key: Vec<u8>, left_to_right: bool) -> QueryItem {
@@ -1288,6 +1430,7 @@ impl<'a> DriveQuery<'a> {
         starts_at_document: Option<(Document, bool)>,
     ) -> Result<PathQuery, Error> {
         let index = self.find_best_index()?;
+        self.validate_clause_order_for_index(index)?;
         let ordered_clauses: Vec<&WhereClause> = index
             .properties
             .iter()
@@ -1668,7 +1811,9 @@ mod tests {
     use crate::contract::Contract;
     use crate::drive::flags::StorageFlags;
     use crate::drive::Drive;
-    use crate::query::DriveQuery;
+    use crate::error::query::QuerySyntaxError;
+    use crate::error::Error;
+    use crate::query::{DriveQuery, DEFAULT_MAX_QUERY_COMPLEXITY};
     use dpp::data_contract::document_type::DocumentType;
     use dpp::data_contract::extra::common::json_document_to_contract;
 
@@ -1720,6 +1865,64 @@ mod tests {
         (drive, contract)
     }
 
+    #[test]
+    fn test_query_complexity_score() {
+        let contract = json_document_to_contract(
+            "tests/supporting_files/contract/family/family-contract.json",
+        )
+        .expect("expected to get contract");
+        let document_type = contract
+            .document_type_for_name("person")
+            .expect("expected to get document type");
+
+        let query_for = |query_value: serde_json::Value| {
+            let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+                .expect("expected to serialize to cbor");
+            DriveQuery::from_cbor(
+                where_cbor.as_slice(),
+                &contract,
+                document_type,
+                &DriveConfig::default(),
+            )
+            .expect("query should be valid")
+        };
+
+        let equal_query = query_for(json!({
+            "where": [
+                ["firstName", "==", "Samuel"],
+            ],
+            "limit": 10,
+        }));
+        assert_eq!(equal_query.complexity_score(), 10);
+
+        let range_query = query_for(json!({
+            "where": [
+                ["firstName", ">", "Samuel"],
+            ],
+            "limit": 10,
+            "orderBy": [
+                ["firstName", "asc"],
+            ]
+        }));
+        assert_eq!(range_query.complexity_score(), 80);
+
+        let unbounded_query = query_for(json!({
+            "where": [],
+            "limit": 100,
+        }));
+        assert_eq!(unbounded_query.complexity_score(), 400);
+        assert!(matches!(
+            unbounded_query.validate_complexity(100),
+            Err(Error::Query(QuerySyntaxError::QueryTooComplex(_)))
+        ));
+        assert_eq!(
+            equal_query
+                .validate_complexity(DEFAULT_MAX_QUERY_COMPLEXITY)
+                .expect("expected query to be simple enough"),
+            10
+        );
+    }
+
     #[test]
     fn test_invalid_query_ranges_different_fields() {
         let query_value = json!({
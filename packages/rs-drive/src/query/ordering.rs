@@ -32,9 +32,10 @@
 
 use dpp::platform_value::Value;
 use grovedb::Error;
+use serde::{Deserialize, Serialize};
 
 /// Order clause struct
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct OrderClause {
     /// Field
     pub field: String,
@@ -0,0 +1,227 @@
+use crate::contract::Contract;
+use crate::error::query::QuerySyntaxError;
+use crate::error::Error;
+use crate::query::ordering::OrderClause;
+use crate::query::{DriveQuery, InternalClauses};
+use dpp::prelude::Identifier;
+use serde::{Deserialize, Serialize};
+
+/// A drive query without the contract it borrows, serializable so that a query can be logged
+/// and later rebuilt against the same contract to be replayed verbatim.
+///
+/// Where clause values keep their exact variant through serialization, so the replayed query
+/// constructs the same path query as the logged one and verifies the same proofs.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayableDriveQuery {
+    /// The id of the contract the query is on
+    pub contract_id: Identifier,
+    /// The name of the document type the query is on
+    pub document_type_name: String,
+    /// Internal clauses
+    pub internal_clauses: InternalClauses,
+    /// Offset
+    pub offset: Option<u16>,
+    /// Limit
+    pub limit: Option<u16>,
+    /// Order by clauses, in order
+    pub order_by: Vec<OrderClause>,
+    /// Start at
+    pub start_at: Option<[u8; 32]>,
+    /// Start at included
+    pub start_at_included: bool,
+    /// Block time
+    pub block_time_ms: Option<u64>,
+}
+
+impl<'a> From<&DriveQuery<'a>> for ReplayableDriveQuery {
+    fn from(query: &DriveQuery<'a>) -> Self {
+        ReplayableDriveQuery {
+            contract_id: query.contract.id,
+            document_type_name: query.document_type.name.clone(),
+            internal_clauses: query.internal_clauses.clone(),
+            offset: query.offset,
+            limit: query.limit,
+            order_by: query.order_by.values().cloned().collect(),
+            start_at: query.start_at,
+            start_at_included: query.start_at_included,
+            block_time_ms: query.block_time_ms,
+        }
+    }
+}
+
+impl ReplayableDriveQuery {
+    /// Rebuilds the drive query against the contract it was made on
+    pub fn into_drive_query(self, contract: &Contract) -> Result<DriveQuery, Error> {
+        if contract.id != self.contract_id {
+            return Err(Error::Query(QuerySyntaxError::InvalidContractId(
+                "the query was made on another contract",
+            )));
+        }
+        let document_type = contract
+            .document_types
+            .get(self.document_type_name.as_str())
+            .ok_or(Error::Query(QuerySyntaxError::DocumentTypeNotFound(
+                "document type not found in contract",
+            )))?;
+        Ok(DriveQuery {
+            contract,
+            document_type,
+            internal_clauses: self.internal_clauses,
+            offset: self.offset,
+            limit: self.limit,
+            order_by: self
+                .order_by
+                .into_iter()
+                .map(|order_clause| (order_clause.field.clone(), order_clause))
+                .collect(),
+            start_at: self.start_at,
+            start_at_included: self.start_at_included,
+            block_time_ms: self.block_time_ms,
+        })
+    }
+}
+
+impl<'a> DriveQuery<'a> {
+    /// Checks that this query, used to verify a proof, is the query that was sent to get the
+    /// proof.
+    ///
+    /// A proof only verifies against the exact query it was made for, so a query drifting
+    /// between the request and the verification fails as a corrupted or incomplete proof. This
+    /// names the parts of the query that differ instead. Both queries are compared in their
+    /// `ReplayableDriveQuery` form, which is also what a client can echo back to show the query
+    /// it will verify with.
+    pub fn assert_matches(&self, other: &DriveQuery) -> Result<(), Error> {
+        let verifying = ReplayableDriveQuery::from(self);
+        let sent = ReplayableDriveQuery::from(other);
+        let differing_parts = [
+            ("contract id", verifying.contract_id != sent.contract_id),
+            (
+                "document type",
+                verifying.document_type_name != sent.document_type_name,
+            ),
+            (
+                "where clauses",
+                verifying.internal_clauses != sent.internal_clauses,
+            ),
+            ("offset", verifying.offset != sent.offset),
+            ("limit", verifying.limit != sent.limit),
+            ("order by", verifying.order_by != sent.order_by),
+            ("start at", verifying.start_at != sent.start_at),
+            (
+                "start at included",
+                verifying.start_at_included != sent.start_at_included,
+            ),
+            ("block time", verifying.block_time_ms != sent.block_time_ms),
+        ]
+        .into_iter()
+        .filter_map(|(part, differs)| differs.then_some(part))
+        .collect::<Vec<_>>();
+        if differing_parts.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Query(QuerySyntaxError::QueryMismatch(format!(
+                "the verification query differs from the sent query in its {}",
+                differing_parts.join(", ")
+            ))))
+        }
+    }
+}
+
+#[cfg(feature = "full")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drive::config::DriveConfig;
+    use dpp::data_contract::extra::common::json_document_to_contract;
+    use dpp::util::cbor_serializer;
+    use serde_json::json;
+
+    #[test]
+    fn should_replay_a_query_from_json() {
+        let contract = json_document_to_contract(
+            "tests/supporting_files/contract/family/family-contract.json",
+        )
+        .expect("expected to get contract");
+        let document_type = contract
+            .document_type_for_name("person")
+            .expect("expected to get document type");
+
+        let query_value = json!({
+            "where": [
+                ["firstName", "==", "Samuel"],
+                ["middleName", ">", "B"],
+            ],
+            "limit": 10,
+            "orderBy": [
+                ["middleName", "desc"],
+            ],
+        });
+        let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+            .expect("expected to serialize to cbor");
+        let query = DriveQuery::from_cbor(
+            where_cbor.as_slice(),
+            &contract,
+            document_type,
+            &DriveConfig::default(),
+        )
+        .expect("expected a valid query");
+
+        let logged_query = serde_json::to_string(&ReplayableDriveQuery::from(&query))
+            .expect("expected to serialize the query");
+
+        let replayed_query = serde_json::from_str::<ReplayableDriveQuery>(&logged_query)
+            .expect("expected to deserialize the query")
+            .into_drive_query(&contract)
+            .expect("expected to rebuild the query");
+
+        assert_eq!(replayed_query, query);
+    }
+
+    #[test]
+    fn should_name_the_parts_of_a_mismatched_query() {
+        let contract = json_document_to_contract(
+            "tests/supporting_files/contract/family/family-contract.json",
+        )
+        .expect("expected to get contract");
+        let document_type = contract
+            .document_type_for_name("person")
+            .expect("expected to get document type");
+
+        let query_value = json!({
+            "where": [
+                ["firstName", "==", "Samuel"],
+                ["middleName", ">", "B"],
+            ],
+            "limit": 10,
+            "orderBy": [
+                ["middleName", "desc"],
+            ],
+        });
+        let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+            .expect("expected to serialize to cbor");
+        let sent_query = DriveQuery::from_cbor(
+            where_cbor.as_slice(),
+            &contract,
+            document_type,
+            &DriveConfig::default(),
+        )
+        .expect("expected a valid query");
+
+        sent_query
+            .assert_matches(&sent_query.clone())
+            .expect("expected the same query to match");
+
+        let mut verification_query = sent_query.clone();
+        verification_query.limit = Some(5);
+        verification_query.start_at_included = false;
+
+        match verification_query.assert_matches(&sent_query) {
+            Err(Error::Query(QuerySyntaxError::QueryMismatch(message))) => assert_eq!(
+                message,
+                "the verification query differs from the sent query in its limit, start at included"
+            ),
+            result => panic!("expected a query mismatch, got {:?}", result),
+        }
+    }
+}
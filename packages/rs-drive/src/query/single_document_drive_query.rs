@@ -22,6 +22,16 @@ pub struct SingleDocumentDriveQuery {
 impl SingleDocumentDriveQuery {
     /// Operations to construct a path query.
     pub fn construct_path_query(&self) -> PathQuery {
+        self.construct_path_query_with_limit(Some(1))
+    }
+
+    /// Operations to construct a path query without a limit.
+    /// Not having a limit is needed if you want to merge path queries
+    pub fn construct_path_query_without_limit(&self) -> PathQuery {
+        self.construct_path_query_with_limit(None)
+    }
+
+    fn construct_path_query_with_limit(&self, limit: Option<u16>) -> PathQuery {
         // First we should get the overall document_type_path
         let mut path =
             contract_document_type_path(&self.contract_id, self.document_type_name.as_str())
@@ -46,7 +56,7 @@ impl SingleDocumentDriveQuery {
             }
         }
 
-        PathQuery::new(path, SizedQuery::new(query, Some(1), None))
+        PathQuery::new(path, SizedQuery::new(query, limit, None))
     }
 }
 
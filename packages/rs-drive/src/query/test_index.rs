@@ -139,4 +139,60 @@ mod tests {
             matches!(error, Error::Query(QuerySyntaxError::WhereClauseOnNonIndexedProperty(message)) if message == "query must be for valid indexes")
         )
     }
+
+    #[test]
+    fn test_range_clause_after_equality_clauses() {
+        let document_type = construct_indexed_document_type();
+        let contract = Contract::default();
+
+        let query_value = json!({
+            "where": [
+                ["b", "==", "2"],
+                ["a", ">", "1"],
+            ],
+            "orderBy": [
+                ["a", "asc"],
+            ]
+        });
+        let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+            .expect("expected to serialize to cbor");
+        let query = DriveQuery::from_cbor(
+            where_cbor.as_slice(),
+            &contract,
+            &document_type,
+            &DriveConfig::default(),
+        )
+        .expect("query should be valid");
+        let index = query.find_best_index().expect("expected to find index");
+        assert_eq!(index, document_type.indices.get(2).unwrap());
+    }
+
+    #[test]
+    fn test_misplaced_range_clause_error() {
+        let document_type = construct_indexed_document_type();
+        let contract = Contract::default();
+
+        // index d is on b, a, d so the range on d skips a
+        let query_value = json!({
+            "where": [
+                ["b", "==", "2"],
+                ["d", ">", "1"],
+            ],
+            "orderBy": [
+                ["d", "asc"],
+            ]
+        });
+        let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+            .expect("expected to serialize to cbor");
+        let error = DriveQuery::from_cbor(
+            where_cbor.as_slice(),
+            &contract,
+            &document_type,
+            &DriveConfig::default(),
+        )
+        .expect_err("expected the range clause to be misplaced");
+        assert!(
+            matches!(error, Error::Query(QuerySyntaxError::MisplacedRangeClause(message)) if message.starts_with("range clause on d must instead be on a"))
+        )
+    }
 }
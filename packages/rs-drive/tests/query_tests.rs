@@ -46,6 +46,8 @@ use std::option::Option::None;
 #[cfg(feature = "full")]
 use std::sync::Arc;
 
+#[cfg(feature = "full")]
+use dpp::data_contract::document_type::{DocumentFieldType, DocumentType};
 #[cfg(feature = "full")]
 use dpp::data_contract::DataContractFactory;
 #[cfg(feature = "full")]
@@ -74,8 +76,12 @@ use drive::drive::flags::StorageFlags;
 #[cfg(feature = "full")]
 use drive::drive::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
 #[cfg(feature = "full")]
+use drive::drive::verify::unique_index::UniqueProofResult;
+#[cfg(feature = "full")]
 use drive::drive::Drive;
 #[cfg(feature = "full")]
+use drive::error::proof::ProofError;
+#[cfg(feature = "full")]
 use drive::error::{query::QuerySyntaxError, Error};
 #[cfg(feature = "full")]
 use drive::query::DriveQuery;
@@ -88,6 +94,8 @@ use dpp::data_contract::validation::data_contract_validator::DataContractValidat
 #[cfg(feature = "full")]
 use dpp::document::Document;
 #[cfg(feature = "full")]
+use dpp::platform_value::string_encoding::Encoding;
+#[cfg(feature = "full")]
 use dpp::platform_value::Value;
 use dpp::platform_value::{platform_value, Bytes32, Identifier};
 
@@ -414,6 +422,35 @@ pub fn setup_family_tests_only_first_name_index(count: u32, seed: u64) -> (Drive
     (drive, contract)
 }
 
+#[cfg(feature = "full")]
+/// Builds the query for at most 5 people with a first name after `first_name`, in first name
+/// order, on the given person document type of the family contract.
+pub fn family_first_name_after_query<'a>(
+    drive: &Drive,
+    contract: &'a Contract,
+    person_document_type: &'a DocumentType,
+    first_name: &str,
+) -> DriveQuery<'a> {
+    let query_value = json!({
+        "where": [
+            ["firstName", ">", first_name]
+        ],
+        "limit": 5,
+        "orderBy": [
+            ["firstName", "asc"]
+        ]
+    });
+    let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+        .expect("expected to serialize to cbor");
+    DriveQuery::from_cbor(
+        where_cbor.as_slice(),
+        contract,
+        person_document_type,
+        &drive.config,
+    )
+    .expect("query should be built")
+}
+
 #[cfg(feature = "full")]
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -2377,6 +2414,286 @@ fn test_family_person_update() {
     assert_eq!(documents.len(), 1);
 }
 
+#[cfg(feature = "full")]
+#[test]
+fn test_family_query_verification_with_expected_root() {
+    let (drive, contract) = setup_family_tests(10, 73509);
+
+    let root_hash = drive
+        .grove
+        .root_hash(None)
+        .unwrap()
+        .expect("there is always a root hash");
+
+    let person_document_type = contract
+        .document_types
+        .get("person")
+        .expect("contract should have a person document type");
+    let query = family_first_name_after_query(&drive, &contract, person_document_type, "Chris");
+
+    let (proof, _fee) = query
+        .clone()
+        .execute_with_proof(&drive, None, None)
+        .expect("expected proof to be generated");
+
+    let documents = query
+        .verify_proof_with_expected_root(&proof, root_hash)
+        .expect("expected to verify proof against the trusted root hash");
+
+    assert_eq!(documents.len(), 5);
+
+    let mut wrong_root_hash = root_hash;
+    wrong_root_hash[0] = wrong_root_hash[0].wrapping_add(1);
+
+    let result = query.verify_proof_with_expected_root(&proof, wrong_root_hash);
+
+    assert!(matches!(
+        result,
+        Err(Error::Proof(ProofError::IncorrectRootHash { .. }))
+    ));
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_family_query_verification_validated() {
+    let (drive, contract) = setup_family_tests(10, 73509);
+
+    let person_document_type = contract
+        .document_types
+        .get("person")
+        .expect("contract should have a person document type");
+    let query = family_first_name_after_query(&drive, &contract, person_document_type, "Chris");
+
+    let (proof, _fee) = query
+        .clone()
+        .execute_with_proof(&drive, None, None)
+        .expect("expected proof to be generated");
+
+    let (_, documents) = query
+        .verify_proof_validated(&proof)
+        .expect("expected proved documents to conform to the document type");
+
+    assert_eq!(documents.len(), 5);
+
+    // a stricter document type makes the proved first names too long
+    let mut strict_person_document_type = person_document_type.clone();
+    strict_person_document_type
+        .properties
+        .get_mut("firstName")
+        .expect("expected a first name property")
+        .document_type = DocumentFieldType::String(None, Some(1));
+    let strict_query =
+        family_first_name_after_query(&drive, &contract, &strict_person_document_type, "Chris");
+
+    let result = strict_query.verify_proof_validated(&proof);
+
+    assert!(matches!(
+        result,
+        Err(Error::Proof(ProofError::InvalidDocument { field, .. })) if field == "firstName"
+    ));
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_family_query_verification_with_key_range() {
+    let (drive, contract) = setup_family_tests(10, 73509);
+
+    let person_document_type = contract
+        .document_types
+        .get("person")
+        .expect("contract should have a person document type");
+    let query = family_first_name_after_query(&drive, &contract, person_document_type, "Chris");
+
+    let (proof, _fee) = query
+        .clone()
+        .execute_with_proof(&drive, None, None)
+        .expect("expected proof to be generated");
+
+    let (_, documents, key_range) = query
+        .verify_proof_with_key_range(&proof)
+        .expect("expected to verify the proof");
+
+    assert_eq!(documents.len(), 5);
+    assert!(key_range.lowest <= key_range.highest);
+
+    // the first names are index values, so the covered range starts and ends at the first
+    // names of the first and last proved documents
+    let first_name = |document: &Document| {
+        document
+            .properties
+            .get("firstName")
+            .and_then(|first_name| first_name.as_text())
+            .expect("expected a first name")
+            .as_bytes()
+            .to_vec()
+    };
+    assert!(key_range.lowest.0.contains(&first_name(&documents[0])));
+    assert!(key_range.highest.0.contains(&first_name(&documents[4])));
+    assert!(key_range.limit_reached);
+
+    // with a higher limit every document of the range is proved
+    let mut unlimited_query = query.clone();
+    unlimited_query.limit = Some(100);
+
+    let (proof, _fee) = unlimited_query
+        .clone()
+        .execute_with_proof(&drive, None, None)
+        .expect("expected proof to be generated");
+
+    let (_, documents, key_range) = unlimited_query
+        .verify_proof_with_key_range(&proof)
+        .expect("expected to verify the proof");
+
+    assert!(documents.len() >= 5 && documents.len() < 100);
+    assert!(!key_range.limit_reached);
+
+    // without proved documents the queried bounds are returned, the upper one is unbounded
+    let empty_query = family_first_name_after_query(&drive, &contract, person_document_type, "Zzz");
+
+    let (proof, _fee) = empty_query
+        .clone()
+        .execute_with_proof(&drive, None, None)
+        .expect("expected proof to be generated");
+
+    let (_, documents, key_range) = empty_query
+        .verify_proof_with_key_range(&proof)
+        .expect("expected to verify the proof");
+
+    assert!(documents.is_empty());
+    assert_eq!(key_range.lowest.0, key_range.highest.0);
+    assert!(!key_range.lowest.1.is_empty());
+    assert!(key_range.highest.1.is_empty());
+    assert!(!key_range.limit_reached);
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_family_query_verification_limited() {
+    let (drive, contract) = setup_family_tests(10, 73509);
+
+    let person_document_type = contract
+        .document_types
+        .get("person")
+        .expect("contract should have a person document type");
+    let query = family_first_name_after_query(&drive, &contract, person_document_type, "Chris");
+
+    let (proof, _fee) = query
+        .clone()
+        .execute_with_proof(&drive, None, None)
+        .expect("expected proof to be generated");
+
+    let (_, all_documents) = query
+        .verify_proof(&proof)
+        .expect("expected to verify the proof");
+
+    let (_, documents, exceeded_max_documents) = query
+        .verify_proof_limited(&proof, 3)
+        .expect("expected to verify the proof");
+
+    assert_eq!(documents, all_documents[..3]);
+    assert!(exceeded_max_documents);
+
+    let (_, documents, exceeded_max_documents) = query
+        .verify_proof_limited(&proof, 5)
+        .expect("expected to verify the proof");
+
+    assert_eq!(documents, all_documents);
+    assert!(!exceeded_max_documents);
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_family_query_verification_by_id() {
+    let (drive, contract) = setup_family_tests(10, 73509);
+
+    let person_document_type = contract
+        .document_types
+        .get("person")
+        .expect("contract should have a person document type");
+    let query = family_first_name_after_query(&drive, &contract, person_document_type, "Chris");
+
+    let (proof, _fee) = query
+        .clone()
+        .execute_with_proof(&drive, None, None)
+        .expect("expected proof to be generated");
+
+    let (_, documents) = query
+        .verify_proof(&proof)
+        .expect("expected to verify the proof");
+
+    let (_, documents_by_id) = query
+        .verify_proof_map(&proof)
+        .expect("expected to verify the proof");
+
+    assert_eq!(documents_by_id.len(), 5);
+    for document in documents {
+        assert_eq!(documents_by_id.get(&document.id), Some(&document));
+    }
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_family_paging_in_id_order() {
+    let (drive, contract) = setup_family_tests(10, 73509);
+
+    let person_document_type = contract
+        .document_types
+        .get("person")
+        .expect("contract should have a person document type");
+
+    let page_query = |start_after: Option<Identifier>| {
+        let mut query_value = json!({
+            "limit": 3,
+            "orderBy": [
+                ["$id", "asc"]
+            ]
+        });
+        if let Some(start_after) = start_after {
+            query_value["startAfter"] = json!(start_after.to_string(Encoding::Base58));
+        }
+        let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+            .expect("expected to serialize to cbor");
+        DriveQuery::from_cbor(
+            where_cbor.as_slice(),
+            &contract,
+            person_document_type,
+            &drive.config,
+        )
+        .expect("query should be built")
+    };
+
+    let mut ids: Vec<Identifier> = vec![];
+    loop {
+        let query = page_query(ids.last().copied());
+        assert!(query.is_for_primary_key());
+
+        let (results, _, _) = query
+            .execute_raw_results_no_proof(&drive, None, None)
+            .expect("query should be executed");
+
+        let (_, proof_results, _) = query
+            .execute_with_proof_only_get_elements(&drive, None, None)
+            .expect("we should be able to a proof");
+        assert_eq!(results, proof_results);
+
+        if results.is_empty() {
+            break;
+        }
+        ids.extend(results.iter().map(|result| {
+            Document::from_bytes(result.as_slice(), person_document_type)
+                .expect("we should be able to deserialize the document")
+                .id
+        }));
+    }
+
+    let mut sorted_ids = ids.clone();
+    sorted_ids.sort();
+    sorted_ids.dedup();
+
+    assert_eq!(ids.len(), 10);
+    assert_eq!(ids, sorted_ids);
+}
+
 #[cfg(feature = "full")]
 #[test]
 fn test_family_starts_at_queries() {
@@ -3615,6 +3932,257 @@ fn test_dpns_insertion_with_aliases() {
     assert_eq!(result.0, proof_results);
 }
 
+#[cfg(feature = "full")]
+#[test]
+fn test_dpns_username_verification() {
+    let (drive, contract) =
+        setup_dpns_test_with_data("tests/supporting_files/contract/dpns/domains.json");
+
+    // an alias resolves to its alias identity record
+    let query = Drive::dpns_domain_query(&contract, "rt-taya-58833-alias", "dash")
+        .expect("query should be built");
+
+    let (proof, _fee) = query
+        .execute_with_proof(&drive, None, None)
+        .expect("expected proof to be generated");
+
+    let (root_hash, identity_id) =
+        Drive::verify_dpns_username_with_contract(&proof, &contract, "rt-taya-58833-alias", "dash")
+            .expect("expected to verify the proof");
+
+    assert_eq!(
+        drive
+            .grove
+            .root_hash(None)
+            .unwrap()
+            .expect("should get root hash"),
+        root_hash
+    );
+    assert_eq!(
+        identity_id,
+        Some(
+            Identifier::from_string(
+                "GaJYCnEBmzQupp7HQh1cW2j4pzjsJKbD1nbhX2gXvhM2",
+                Encoding::Base58
+            )
+            .expect("expected a valid identifier")
+        )
+    );
+
+    // a unique name resolves to its unique identity record
+    let query = Drive::dpns_domain_query(&contract, "hashengineering", "dash")
+        .expect("query should be built");
+
+    let (proof, _fee) = query
+        .execute_with_proof(&drive, None, None)
+        .expect("expected proof to be generated");
+
+    let (_, identity_id) =
+        Drive::verify_dpns_username_with_contract(&proof, &contract, "hashengineering", "dash")
+            .expect("expected to verify the proof");
+
+    assert_eq!(
+        identity_id,
+        Some(
+            Identifier::from_string(
+                "7XKBbdV8ssVEq6kAKkHp6kBjPGg1x6GBqRRXkcnRZeca",
+                Encoding::Base58
+            )
+            .expect("expected a valid identifier")
+        )
+    );
+
+    // an unregistered name is proven absent
+    let query =
+        Drive::dpns_domain_query(&contract, "unregistered", "dash").expect("query should be built");
+
+    let (proof, _fee) = query
+        .execute_with_proof(&drive, None, None)
+        .expect("expected proof to be generated");
+
+    let (_, identity_id) =
+        Drive::verify_dpns_username_with_contract(&proof, &contract, "unregistered", "dash")
+            .expect("expected to verify the proof");
+
+    assert_eq!(identity_id, None);
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_dpns_unique_index_verification() {
+    let (drive, contract) =
+        setup_dpns_test_with_data("tests/supporting_files/contract/dpns/domains.json");
+
+    let domain_document_type = contract
+        .document_type_for_name("domain")
+        .expect("contract should have a domain document type");
+
+    let identity_id = Value::Identifier(
+        Identifier::from_string(
+            "7XKBbdV8ssVEq6kAKkHp6kBjPGg1x6GBqRRXkcnRZeca",
+            Encoding::Base58,
+        )
+        .expect("expected a valid identifier")
+        .to_buffer(),
+    );
+
+    let path_query =
+        Drive::unique_index_value_query(domain_document_type, "dashIdentityId", &identity_id)
+            .expect("expected to build the query");
+
+    let proof = drive
+        .grove
+        .get_proved_path_query(&path_query, false, None)
+        .unwrap()
+        .expect("expected proof to be generated");
+
+    let (root_hash, result) = Drive::verify_unique_index_covers_value(
+        &proof,
+        domain_document_type,
+        "dashIdentityId",
+        &identity_id,
+    )
+    .expect("expected to verify the proof");
+
+    assert_eq!(
+        drive
+            .grove
+            .root_hash(None)
+            .unwrap()
+            .expect("should get root hash"),
+        root_hash
+    );
+    assert_eq!(
+        result,
+        UniqueProofResult::Present(
+            Identifier::from_string(
+                "2R9tFPCwDSMMYwbfB4A48XSoq2U5X3nNgwF52r4qihDs",
+                Encoding::Base58
+            )
+            .expect("expected a valid identifier")
+            .to_buffer()
+        )
+    );
+
+    // a value no document holds is proven absent
+    let unused_identity_id = Value::Identifier([7u8; 32]);
+
+    let path_query = Drive::unique_index_value_query(
+        domain_document_type,
+        "dashIdentityId",
+        &unused_identity_id,
+    )
+    .expect("expected to build the query");
+
+    let proof = drive
+        .grove
+        .get_proved_path_query(&path_query, false, None)
+        .unwrap()
+        .expect("expected proof to be generated");
+
+    let (_, result) = Drive::verify_unique_index_covers_value(
+        &proof,
+        domain_document_type,
+        "dashIdentityId",
+        &unused_identity_id,
+    )
+    .expect("expected to verify the proof");
+
+    assert_eq!(result, UniqueProofResult::Absent);
+
+    // the alias index is not unique
+    Drive::unique_index_value_query(domain_document_type, "dashAlias", &identity_id)
+        .expect_err("expected the index not to be unique");
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_dpns_composite_unique_key_verification() {
+    let (drive, contract) =
+        setup_dpns_test_with_data("tests/supporting_files/contract/dpns/domains.json");
+
+    let domain_document_type = contract
+        .document_type_for_name("domain")
+        .expect("contract should have a domain document type");
+
+    let key_values = |label: &str| {
+        vec![
+            Value::Text("dash".to_string()),
+            Value::Text(label.to_string()),
+        ]
+    };
+
+    let path_query = Drive::composite_unique_key_query(
+        domain_document_type,
+        "parentNameAndLabel",
+        &key_values("hashengineering"),
+    )
+    .expect("expected to build the query");
+
+    let proof = drive
+        .grove
+        .get_proved_path_query(&path_query, false, None)
+        .unwrap()
+        .expect("expected proof to be generated");
+
+    let (root_hash, document) = Drive::verify_document_by_composite_unique_key(
+        &proof,
+        domain_document_type,
+        "parentNameAndLabel",
+        &key_values("hashengineering"),
+    )
+    .expect("expected to verify the proof");
+
+    assert_eq!(
+        drive
+            .grove
+            .root_hash(None)
+            .unwrap()
+            .expect("should get root hash"),
+        root_hash
+    );
+    assert_eq!(
+        document.expect("expected the domain to be present").id,
+        Identifier::from_string(
+            "2R9tFPCwDSMMYwbfB4A48XSoq2U5X3nNgwF52r4qihDs",
+            Encoding::Base58
+        )
+        .expect("expected a valid identifier")
+    );
+
+    // a label no domain holds is proven absent
+    let path_query = Drive::composite_unique_key_query(
+        domain_document_type,
+        "parentNameAndLabel",
+        &key_values("unregistered"),
+    )
+    .expect("expected to build the query");
+
+    let proof = drive
+        .grove
+        .get_proved_path_query(&path_query, false, None)
+        .unwrap()
+        .expect("expected proof to be generated");
+
+    let (_, document) = Drive::verify_document_by_composite_unique_key(
+        &proof,
+        domain_document_type,
+        "parentNameAndLabel",
+        &key_values("unregistered"),
+    )
+    .expect("expected to verify the proof");
+
+    assert!(document.is_none());
+
+    // every property of the index needs a value
+    Drive::composite_unique_key_query(
+        domain_document_type,
+        "parentNameAndLabel",
+        &[Value::Text("dash".to_string())],
+    )
+    .expect_err("expected a value for every property of the index");
+}
+
 #[cfg(feature = "full")]
 #[test]
 fn test_dpns_query_start_at() {